@@ -0,0 +1,18 @@
+//! Binary that generates TypeScript definitions of the SSE event payloads and WebSocket messages.
+//!
+//! The declarations are derived from the same `ToSchema` types as the OpenAPI document, so the
+//! frontends can stay in sync with the backend's DTOs.
+//!
+//! # Usage
+//!
+//! ```bash
+//! cargo run --bin ts-defs-generator --features ts-defs > neon-beat-events.d.ts
+//! ```
+
+fn main() {
+    #[cfg(feature = "ts-defs")]
+    print!(
+        "{}",
+        neon_beat_back::services::typescript::render_definitions()
+    );
+}
@@ -1,13 +1,20 @@
 //! Application-level configuration loading, including the runtime colors set and buzzer patterns.
 
-use std::{env, fs, io::ErrorKind, path::PathBuf};
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
+use utoipa::ToSchema;
 
 use crate::{
     dto::{
         common::TeamColorDto,
+        validation::wrap_hue,
         ws::{BuzzerPattern, BuzzerPatternDetails},
     },
     state::game::TeamColor,
@@ -18,11 +25,37 @@ const DEFAULT_CONFIG_PATH: &str = "config/app.json";
 /// Environment variable that overrides [`DEFAULT_CONFIG_PATH`].
 const CONFIG_PATH_ENV: &str = "NEON_BEAT_BACK_CONFIG_PATH";
 /// Fallback color returned when the colors set is exhausted.
-const DEFAULT_COLOR: TeamColor = TeamColor {
+pub(crate) const DEFAULT_COLOR: TeamColor = TeamColor {
     h: 0.0,
     s: 0.0,
     v: 1.0,
 };
+/// Naming template applied to teams created without an explicit name.
+const DEFAULT_TEAM_NAME_TEMPLATE: &str = "Team {n}";
+/// Default minimum delay (milliseconds) between two song advances.
+const DEFAULT_MIN_SONG_GAP_MS: u64 = 500;
+/// Default maximum size (bytes) of a message received on the buzzer WebSocket.
+const DEFAULT_WS_MAX_MESSAGE_BYTES: usize = 1024;
+/// Default time (milliseconds) a disconnected buzzer still counts as present for its team.
+const DEFAULT_BUZZER_RECONNECT_GRACE_MS: u64 = 3000;
+/// Default minimum delay (milliseconds) between two saves of the same document.
+const DEFAULT_PERSIST_COOLDOWN_MS: u64 = 200;
+/// Default time (milliseconds) a state machine transition may take before it is aborted.
+const DEFAULT_TRANSITION_TIMEOUT_MS: u64 = 5_000;
+/// Default maximum number of simultaneous admin SSE connections.
+const DEFAULT_ADMIN_MAX_SUBSCRIBERS: usize = 1;
+/// Default period (seconds) of the keepalive comments sent on idle SSE streams.
+const DEFAULT_SSE_KEEPALIVE_SECS: u64 = 15;
+/// Default time (seconds) a buzzer has to identify itself after opening its WebSocket.
+const DEFAULT_BUZZER_IDENT_TIMEOUT_SECS: u64 = 10;
+/// Default maximum length (characters) of a team name.
+const DEFAULT_MAX_TEAM_NAME_LEN: usize = 32;
+/// Default maximum number of buzzers whose last pattern is remembered.
+const DEFAULT_BUZZER_PATTERN_CACHE_SIZE: usize = 256;
+/// Default maximum number of game and team writes issued to the store at the same time.
+const DEFAULT_STORAGE_MAX_CONCURRENT_WRITES: usize = 4;
+/// LED brightness of patterns that do not set one.
+const DEFAULT_BRIGHTNESS: f32 = 1.0;
 /// Fallback color for patterns.
 const DEFAULT_COLOR_DTO: TeamColorDto = TeamColorDto {
     h: 0.0,
@@ -31,7 +64,7 @@ const DEFAULT_COLOR_DTO: TeamColorDto = TeamColorDto {
 };
 
 /// Resolve the configuration path taking the environment override into account.
-fn resolve_config_path() -> PathBuf {
+pub fn resolve_config_path() -> PathBuf {
     env::var_os(CONFIG_PATH_ENV)
         .map(PathBuf::from)
         .filter(|path| !path.as_os_str().is_empty())
@@ -43,6 +76,143 @@ fn resolve_config_path() -> PathBuf {
 pub struct AppConfig {
     colors: Vec<TeamColor>,
     patterns: PatternSet,
+    song_analytics: bool,
+    min_color_distance: Option<f32>,
+    color_assignment: ColorAssignment,
+    default_team_names: TeamNames,
+    pause_freezes_timers: bool,
+    event_recording: Option<EventRecordingConfig>,
+    one_buzz_per_song: bool,
+    min_song_gap_ms: u64,
+    ws_max_message_bytes: usize,
+    answer_countdown: Option<AnswerCountdownConfig>,
+    confirm_pairing_reassignment: bool,
+    buzzer_reconnect_grace_ms: u64,
+    low_battery_threshold: Option<u8>,
+    autosave_interval_ms: Option<u64>,
+    max_score_delta: Option<u32>,
+    persist_cooldown_ms: u64,
+    buzzer_welcome: bool,
+    keep_game_after_end: bool,
+    admin_max_subscribers: usize,
+    sse_keepalive_secs: u64,
+    applause_ms: Option<u64>,
+    log_answers: bool,
+    spectators_debounce_ms: Option<u64>,
+    non_answering_pattern: NonAnsweringPattern,
+    max_team_name_len: usize,
+    team_name_charset: TeamNameCharset,
+    leaderboard_key: LeaderboardKey,
+    buzz_cue: bool,
+    bonus_scoring: BonusScoring,
+    degraded_read_fallback: bool,
+    buzzer_pattern_cache_size: usize,
+    storage_max_concurrent_writes: usize,
+    buzzer_ident_timeout_secs: u64,
+    buzzer_ping_interval_secs: Option<u64>,
+    transition_timeout_ms: Option<u64>,
+}
+
+/// Countdown given to a team to answer after buzzing.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnswerCountdownConfig {
+    /// Time (milliseconds) the buzzing team has to answer.
+    pub duration_ms: u64,
+    /// Action taken when the countdown expires before the GM resolves the buzz.
+    #[serde(default)]
+    pub on_expiry: CountdownExpiry,
+}
+
+/// Strategy picking the color of a team created without one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorAssignment {
+    /// First colors set entry not used by another team.
+    #[default]
+    FirstUnused,
+    /// Colors set entry at the team's position, so the Nth team always gets the same color.
+    ByIndex,
+}
+
+/// Action taken when an answer countdown expires.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CountdownExpiry {
+    /// Resume playing the current song.
+    #[default]
+    Resume,
+    /// Reveal the answer of the current song.
+    Reveal,
+}
+
+/// Pattern shown by the buzzers of the teams not answering while another team answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NonAnsweringPattern {
+    /// The `waiting` pattern (off by default), dimming the other teams.
+    #[default]
+    Waiting,
+    /// The `standby` pattern in the team color, so every team stays visible.
+    Standby,
+}
+
+/// Characters accepted in team names, on top of the control characters always rejected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamNameCharset {
+    /// Any printable character, accents and emoji included.
+    #[default]
+    Unicode,
+    /// Printable ASCII only, for displays without extended glyphs.
+    Ascii,
+}
+
+impl TeamNameCharset {
+    /// Whether `c` may appear in a team name.
+    pub fn allows(self, c: char) -> bool {
+        match self {
+            Self::Unicode => !c.is_control(),
+            Self::Ascii => c.is_ascii() && !c.is_ascii_control(),
+        }
+    }
+}
+
+/// How the teams of different games are matched when aggregating the leaderboard.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardKey {
+    /// Team name, ignoring case and surrounding or repeated whitespace.
+    #[default]
+    Name,
+    /// First buzzer paired with the team, for venues handing the same buzzer to a team every
+    /// game; teams without a buzzer fall back to their name.
+    Buzzer,
+}
+
+/// When the bonus fields found for a song are scored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BonusScoring {
+    /// A correct answer awards the found point and bonus fields together.
+    #[default]
+    WithAnswer,
+    /// A correct answer only awards the point fields; bonus fields are awarded one by one with
+    /// `POST /admin/game/bonus/award`.
+    Separate,
+}
+
+/// Settings of the optional SSE event recorder.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventRecordingConfig {
+    /// NDJSON file receiving one line per broadcast event.
+    pub path: PathBuf,
+    /// Size (bytes) past which the file is rotated to `<path>.1`.
+    #[serde(default = "default_event_recording_max_bytes")]
+    pub max_bytes: u64,
+}
+
+fn default_event_recording_max_bytes() -> u64 {
+    10 * 1024 * 1024
 }
 
 impl AppConfig {
@@ -50,9 +220,8 @@ impl AppConfig {
     pub fn load() -> Self {
         let path = resolve_config_path();
         match fs::read_to_string(&path) {
-            Ok(contents) => match serde_json::from_str::<RawConfig>(&contents) {
-                Ok(raw) => {
-                    let app_config: Self = raw.into();
+            Ok(contents) => match Self::parse(&contents) {
+                Ok(app_config) => {
                     info!(path = %path.display(), "loaded runtime configuration");
                     app_config
                 }
@@ -83,18 +252,65 @@ impl AppConfig {
         }
     }
 
+    /// Read the configuration file at `path` again for a hot reload.
+    ///
+    /// Unlike [`AppConfig::load`], a missing, unreadable or invalid file is reported as an error
+    /// so the caller can keep its current configuration instead of reverting to the defaults.
+    pub fn reload(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read `{}`: {err}", path.display()))?;
+        Self::parse(&contents).map_err(|err| format!("failed to parse `{}`: {err}", path.display()))
+    }
+
+    fn parse(contents: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str::<RawConfig>(contents).map(Into::into)
+    }
+
     /// Return the first color from the colors set that is not already listed in `used`.
     ///
     /// When every colors set entry is already taken we wrap around to `DEFAULT_COLOR` so callers
     /// always receive a value.
+    ///
+    /// When a minimum color distance is configured, candidates closer than that OKLab distance to
+    /// any used color are skipped first; if no candidate is distinct enough, the first unused
+    /// color is returned instead.
     pub fn first_unused_color(&self, used: &[TeamColor]) -> TeamColor {
-        self.colors
+        let mut unused = self
+            .colors
             .iter()
-            .find(|candidate| used.iter().all(|existing| existing != *candidate))
+            .filter(|candidate| used.iter().all(|existing| existing != *candidate));
+
+        let distinct = self.min_color_distance.and_then(|threshold| {
+            unused.clone().find(|candidate| {
+                used.iter()
+                    .all(|existing| existing.oklab_distance(candidate) >= threshold)
+            })
+        });
+
+        distinct
+            .or_else(|| unused.next())
             .cloned()
             .unwrap_or(DEFAULT_COLOR)
     }
 
+    /// Pick the color of the team at position `index` following the configured
+    /// [`ColorAssignment`], given the colors of the other teams.
+    pub fn auto_color(&self, index: usize, used: &[TeamColor]) -> TeamColor {
+        match self.color_assignment {
+            ColorAssignment::FirstUnused => self.first_unused_color(used),
+            ColorAssignment::ByIndex if self.colors.is_empty() => DEFAULT_COLOR,
+            ColorAssignment::ByIndex => self.colors[index % self.colors.len()].clone(),
+        }
+    }
+
+    /// Preset sent to the buzzers of a team that is not answering while another team answers.
+    pub fn non_answering_preset(&self, color: TeamColor) -> BuzzerPatternPreset {
+        match self.non_answering_pattern {
+            NonAnsweringPattern::Waiting => BuzzerPatternPreset::Waiting,
+            NonAnsweringPattern::Standby => BuzzerPatternPreset::Standby(color),
+        }
+    }
+
     /// Retrieve the buzzer pattern preset for the requested state.
     ///
     /// For presets carrying a `TeamColorDto`, that color is used unless the configuration specifies
@@ -102,6 +318,246 @@ impl AppConfig {
     pub fn buzzer_pattern(&self, preset: BuzzerPatternPreset) -> BuzzerPattern {
         self.patterns.pattern(preset)
     }
+
+    /// Colors set used for automatic team color assignment, in order.
+    pub fn colors(&self) -> &[TeamColor] {
+        &self.colors
+    }
+
+    /// Minimum OKLab distance preferred between automatically assigned colors, if any.
+    pub fn min_color_distance(&self) -> Option<f32> {
+        self.min_color_distance
+    }
+
+    /// Strategy picking the color of a team created without one.
+    pub fn color_assignment(&self) -> ColorAssignment {
+        self.color_assignment
+    }
+
+    /// Source of names for teams created without an explicit name.
+    pub fn default_team_names(&self) -> &TeamNames {
+        &self.default_team_names
+    }
+
+    /// Pattern shown on the buzzers of the teams not answering while another team answers.
+    pub fn non_answering_pattern(&self) -> NonAnsweringPattern {
+        self.non_answering_pattern
+    }
+
+    /// Whether per-song timing analytics should be persisted when a song is resolved.
+    pub fn song_analytics_enabled(&self) -> bool {
+        self.song_analytics
+    }
+
+    /// Whether a manual pause freezes the game timers until the game is resumed.
+    pub fn pause_freezes_timers(&self) -> bool {
+        self.pause_freezes_timers
+    }
+
+    /// Settings of the SSE event recorder, when enabled.
+    pub fn event_recording(&self) -> Option<&EventRecordingConfig> {
+        self.event_recording.as_ref()
+    }
+
+    /// Whether each team may only buzz once per song.
+    pub fn one_buzz_per_song(&self) -> bool {
+        self.one_buzz_per_song
+    }
+
+    /// Minimum delay between two successive song advances.
+    pub fn min_song_gap(&self) -> Duration {
+        Duration::from_millis(self.min_song_gap_ms)
+    }
+
+    /// Answer countdown started when a team buzzes, if enabled.
+    pub fn answer_countdown(&self) -> Option<&AnswerCountdownConfig> {
+        self.answer_countdown.as_ref()
+    }
+
+    /// Whether pairing a buzzer already paired with another team requires the GM's confirmation.
+    pub fn confirm_pairing_reassignment(&self) -> bool {
+        self.confirm_pairing_reassignment
+    }
+
+    /// Time during which a buzzer that just disconnected still counts as present for the
+    /// pairing and start checks, so a momentary drop does not block the game.
+    pub fn buzzer_reconnect_grace(&self) -> Duration {
+        Duration::from_millis(self.buzzer_reconnect_grace_ms)
+    }
+
+    /// Battery level (percent) below which the GM is warned about a buzzer, if enabled.
+    pub fn low_battery_threshold(&self) -> Option<u8> {
+        self.low_battery_threshold
+    }
+
+    /// Period of the background autosave of the current game, if enabled.
+    pub fn autosave_interval(&self) -> Option<Duration> {
+        self.autosave_interval_ms
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis)
+    }
+
+    /// Largest score change a single adjustment may apply without being forced, if capped.
+    pub fn max_score_delta(&self) -> Option<u32> {
+        self.max_score_delta
+    }
+
+    /// Minimum delay between two saves of the same game or team document.
+    pub fn persist_cooldown(&self) -> Duration {
+        Duration::from_millis(self.persist_cooldown_ms)
+    }
+
+    /// Whether identified buzzers are greeted with a `welcome` message before their first pattern.
+    pub fn buzzer_welcome(&self) -> bool {
+        self.buzzer_welcome
+    }
+
+    /// Whether the game stays loaded in memory after it ends, ready to be played again.
+    pub fn keep_game_after_end(&self) -> bool {
+        self.keep_game_after_end
+    }
+
+    /// Maximum number of simultaneous admin SSE connections (at least one).
+    pub fn admin_max_subscribers(&self) -> usize {
+        self.admin_max_subscribers.max(1)
+    }
+
+    /// Period of the keepalive comments sent on SSE streams (at least one second).
+    pub fn sse_keepalive(&self) -> Duration {
+        Duration::from_secs(self.sse_keepalive_secs.max(1))
+    }
+
+    /// How long the buzzers of a team whose answer is validated correct flash the `correct`
+    /// pattern, if enabled.
+    pub fn applause_duration(&self) -> Option<Duration> {
+        self.applause_ms
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis)
+    }
+
+    /// Whether debug logs may include full playlists, answers included.
+    pub fn log_answers(&self) -> bool {
+        self.log_answers
+    }
+
+    /// Maximum length (characters) of a team name, once trimmed.
+    pub fn max_team_name_len(&self) -> usize {
+        self.max_team_name_len
+    }
+
+    /// Characters accepted in team names.
+    pub fn team_name_charset(&self) -> TeamNameCharset {
+        self.team_name_charset
+    }
+
+    /// How teams are matched across games in the leaderboard.
+    pub fn leaderboard_key(&self) -> LeaderboardKey {
+        self.leaderboard_key
+    }
+
+    /// Whether an accepted buzz is announced by a `buzz.cue` event ahead of the phase change.
+    pub fn buzz_cue(&self) -> bool {
+        self.buzz_cue
+    }
+
+    /// When the bonus fields found for a song are scored.
+    pub fn bonus_scoring(&self) -> BonusScoring {
+        self.bonus_scoring
+    }
+
+    /// Whether reads of the active game are served from memory while the storage is unavailable.
+    pub fn degraded_read_fallback(&self) -> bool {
+        self.degraded_read_fallback
+    }
+
+    /// Maximum number of buzzers whose last pattern is remembered for reconnection.
+    pub fn buzzer_pattern_cache_size(&self) -> usize {
+        self.buzzer_pattern_cache_size
+    }
+
+    /// Maximum number of game and team writes issued to the store at the same time.
+    pub fn storage_max_concurrent_writes(&self) -> usize {
+        self.storage_max_concurrent_writes
+    }
+
+    /// Time a buzzer has to send its identification after opening `/ws` (at least one second).
+    pub fn buzzer_ident_timeout(&self) -> Duration {
+        Duration::from_secs(self.buzzer_ident_timeout_secs.max(1))
+    }
+
+    /// Period of the pings sent to identified buzzers, if enabled. A buzzer that has not answered
+    /// a ping by the next one is disconnected.
+    pub fn buzzer_ping_interval(&self) -> Option<Duration> {
+        self.buzzer_ping_interval_secs
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// Time a state machine transition may take before it is aborted, or `None` when disabled.
+    pub fn transition_timeout(&self) -> Option<Duration> {
+        self.transition_timeout_ms
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis)
+    }
+
+    /// Delay gathering spectator count changes into one `spectators` broadcast, if enabled.
+    pub fn spectators_debounce(&self) -> Option<Duration> {
+        self.spectators_debounce_ms.map(Duration::from_millis)
+    }
+
+    /// Maximum size in bytes of a frame or message received from a buzzer WebSocket.
+    pub fn ws_max_message_bytes(&self) -> usize {
+        self.ws_max_message_bytes
+    }
+
+    /// Name given to the `index`-th (zero-based) team created without an explicit name.
+    ///
+    /// A template substitutes `{n}` with the team's position; a list of names is cycled, and a
+    /// template without `{n}` is used as is. Names for which `taken` returns `true` get a numeric
+    /// suffix (or the next `{n}`) until unique.
+    pub fn default_team_name(&self, index: usize, taken: impl Fn(&str) -> bool) -> String {
+        let base = match &self.default_team_names {
+            TeamNames::Template(template) if template.contains("{n}") => {
+                return (index + 1..)
+                    .map(|n| template.replace("{n}", &n.to_string()))
+                    .find(|name| !taken(name))
+                    .unwrap_or_default();
+            }
+            TeamNames::Template(name) => name,
+            TeamNames::List(names) => &names[index % names.len()],
+        };
+        std::iter::once(base.clone())
+            .chain((2..).map(|counter| format!("{base} {counter}")))
+            .find(|name| !taken(name))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+/// Source of names for teams created without an explicit name.
+pub enum TeamNames {
+    /// Template where `{n}` is replaced by the team's position.
+    Template(String),
+    /// Fixed names cycled in order.
+    List(Vec<String>),
+}
+
+impl TeamNames {
+    /// Fall back to the built-in template when the configured value cannot produce names.
+    fn or_default(self) -> Self {
+        match self {
+            TeamNames::Template(template) if template.trim().is_empty() => Self::default(),
+            TeamNames::List(names) if names.is_empty() => Self::default(),
+            other => other,
+        }
+    }
+}
+
+impl Default for TeamNames {
+    fn default() -> Self {
+        TeamNames::Template(DEFAULT_TEAM_NAME_TEMPLATE.to_string())
+    }
 }
 
 impl Default for AppConfig {
@@ -109,6 +565,41 @@ impl Default for AppConfig {
         Self {
             colors: default_colors(),
             patterns: default_patterns(),
+            song_analytics: false,
+            min_color_distance: None,
+            color_assignment: ColorAssignment::default(),
+            default_team_names: TeamNames::default(),
+            pause_freezes_timers: true,
+            event_recording: None,
+            one_buzz_per_song: false,
+            min_song_gap_ms: DEFAULT_MIN_SONG_GAP_MS,
+            ws_max_message_bytes: DEFAULT_WS_MAX_MESSAGE_BYTES,
+            answer_countdown: None,
+            confirm_pairing_reassignment: false,
+            buzzer_reconnect_grace_ms: DEFAULT_BUZZER_RECONNECT_GRACE_MS,
+            low_battery_threshold: None,
+            autosave_interval_ms: None,
+            max_score_delta: None,
+            persist_cooldown_ms: DEFAULT_PERSIST_COOLDOWN_MS,
+            buzzer_welcome: true,
+            keep_game_after_end: false,
+            admin_max_subscribers: DEFAULT_ADMIN_MAX_SUBSCRIBERS,
+            sse_keepalive_secs: DEFAULT_SSE_KEEPALIVE_SECS,
+            applause_ms: None,
+            log_answers: false,
+            spectators_debounce_ms: None,
+            non_answering_pattern: NonAnsweringPattern::default(),
+            max_team_name_len: DEFAULT_MAX_TEAM_NAME_LEN,
+            team_name_charset: TeamNameCharset::default(),
+            leaderboard_key: LeaderboardKey::default(),
+            buzz_cue: true,
+            bonus_scoring: BonusScoring::default(),
+            degraded_read_fallback: true,
+            buzzer_pattern_cache_size: DEFAULT_BUZZER_PATTERN_CACHE_SIZE,
+            storage_max_concurrent_writes: DEFAULT_STORAGE_MAX_CONCURRENT_WRITES,
+            buzzer_ident_timeout_secs: DEFAULT_BUZZER_IDENT_TIMEOUT_SECS,
+            buzzer_ping_interval_secs: None,
+            transition_timeout_ms: Some(DEFAULT_TRANSITION_TIMEOUT_MS),
         }
     }
 }
@@ -120,6 +611,146 @@ struct RawConfig {
     colors: Vec<RawColor>,
     #[serde(default)]
     patterns: Option<RawPatternSet>,
+    #[serde(default)]
+    song_analytics: bool,
+    #[serde(default)]
+    min_color_distance: Option<f32>,
+    #[serde(default)]
+    color_assignment: ColorAssignment,
+    #[serde(default)]
+    default_team_names: TeamNames,
+    #[serde(default = "default_pause_freezes_timers")]
+    pause_freezes_timers: bool,
+    #[serde(default)]
+    event_recording: Option<EventRecordingConfig>,
+    #[serde(default)]
+    one_buzz_per_song: bool,
+    #[serde(default = "default_min_song_gap_ms")]
+    min_song_gap_ms: u64,
+    #[serde(default = "default_ws_max_message_bytes")]
+    ws_max_message_bytes: usize,
+    #[serde(default)]
+    answer_countdown: Option<AnswerCountdownConfig>,
+    #[serde(default)]
+    confirm_pairing_reassignment: bool,
+    #[serde(default = "default_buzzer_reconnect_grace_ms")]
+    buzzer_reconnect_grace_ms: u64,
+    #[serde(default)]
+    low_battery_threshold: Option<u8>,
+    #[serde(default)]
+    autosave_interval_ms: Option<u64>,
+    #[serde(default)]
+    max_score_delta: Option<u32>,
+    #[serde(default = "default_persist_cooldown_ms")]
+    persist_cooldown_ms: u64,
+    #[serde(default = "default_buzzer_welcome")]
+    buzzer_welcome: bool,
+    #[serde(default)]
+    keep_game_after_end: bool,
+    #[serde(default = "default_admin_max_subscribers")]
+    admin_max_subscribers: usize,
+    #[serde(default = "default_sse_keepalive_secs")]
+    sse_keepalive_secs: u64,
+    #[serde(default)]
+    applause_ms: Option<u64>,
+    #[serde(default)]
+    log_answers: bool,
+    #[serde(default)]
+    spectators_debounce_ms: Option<u64>,
+    #[serde(default)]
+    non_answering_pattern: NonAnsweringPattern,
+    #[serde(default = "default_max_team_name_len")]
+    max_team_name_len: usize,
+    #[serde(default)]
+    team_name_charset: TeamNameCharset,
+    #[serde(default)]
+    leaderboard_key: LeaderboardKey,
+    #[serde(default = "default_buzz_cue")]
+    buzz_cue: bool,
+    #[serde(default)]
+    bonus_scoring: BonusScoring,
+    #[serde(default = "default_degraded_read_fallback")]
+    degraded_read_fallback: bool,
+    #[serde(default = "default_buzzer_pattern_cache_size")]
+    buzzer_pattern_cache_size: usize,
+    #[serde(default = "default_storage_max_concurrent_writes")]
+    storage_max_concurrent_writes: usize,
+    #[serde(default = "default_buzzer_ident_timeout_secs")]
+    buzzer_ident_timeout_secs: u64,
+    #[serde(default)]
+    buzzer_ping_interval_secs: Option<u64>,
+    #[serde(default = "default_transition_timeout_ms")]
+    transition_timeout_ms: Option<u64>,
+}
+
+fn default_pause_freezes_timers() -> bool {
+    true
+}
+
+fn default_min_song_gap_ms() -> u64 {
+    DEFAULT_MIN_SONG_GAP_MS
+}
+
+fn default_ws_max_message_bytes() -> usize {
+    DEFAULT_WS_MAX_MESSAGE_BYTES
+}
+
+fn default_buzzer_reconnect_grace_ms() -> u64 {
+    DEFAULT_BUZZER_RECONNECT_GRACE_MS
+}
+
+fn default_persist_cooldown_ms() -> u64 {
+    DEFAULT_PERSIST_COOLDOWN_MS
+}
+
+fn default_buzzer_welcome() -> bool {
+    true
+}
+
+fn default_buzz_cue() -> bool {
+    true
+}
+
+fn default_degraded_read_fallback() -> bool {
+    true
+}
+
+fn default_buzzer_pattern_cache_size() -> usize {
+    DEFAULT_BUZZER_PATTERN_CACHE_SIZE
+}
+
+fn default_storage_max_concurrent_writes() -> usize {
+    DEFAULT_STORAGE_MAX_CONCURRENT_WRITES
+}
+
+fn default_buzzer_ident_timeout_secs() -> u64 {
+    DEFAULT_BUZZER_IDENT_TIMEOUT_SECS
+}
+
+fn default_transition_timeout_ms() -> Option<u64> {
+    Some(DEFAULT_TRANSITION_TIMEOUT_MS)
+}
+
+fn default_admin_max_subscribers() -> usize {
+    DEFAULT_ADMIN_MAX_SUBSCRIBERS
+}
+
+fn default_sse_keepalive_secs() -> u64 {
+    DEFAULT_SSE_KEEPALIVE_SECS
+}
+
+fn default_max_team_name_len() -> usize {
+    DEFAULT_MAX_TEAM_NAME_LEN
+}
+
+#[cfg(test)]
+impl AppConfig {
+    /// Build a configuration from the JSON accepted in the configuration file.
+    pub(crate) fn from_json(json: &str) -> Self {
+        serde_json::from_str::<RawConfig>(json)
+            .expect("invalid test configuration")
+            .into()
+    }
 }
 
 impl From<RawConfig> for AppConfig {
@@ -133,7 +764,45 @@ impl From<RawConfig> for AppConfig {
             .patterns
             .map(override_default_patterns)
             .unwrap_or_else(default_patterns);
-        Self { colors, patterns }
+        Self {
+            colors,
+            patterns,
+            song_analytics: value.song_analytics,
+            min_color_distance: value.min_color_distance,
+            color_assignment: value.color_assignment,
+            default_team_names: value.default_team_names.or_default(),
+            pause_freezes_timers: value.pause_freezes_timers,
+            event_recording: value.event_recording,
+            one_buzz_per_song: value.one_buzz_per_song,
+            min_song_gap_ms: value.min_song_gap_ms,
+            ws_max_message_bytes: value.ws_max_message_bytes,
+            answer_countdown: value.answer_countdown,
+            confirm_pairing_reassignment: value.confirm_pairing_reassignment,
+            buzzer_reconnect_grace_ms: value.buzzer_reconnect_grace_ms,
+            low_battery_threshold: value.low_battery_threshold,
+            autosave_interval_ms: value.autosave_interval_ms,
+            max_score_delta: value.max_score_delta,
+            persist_cooldown_ms: value.persist_cooldown_ms,
+            buzzer_welcome: value.buzzer_welcome,
+            keep_game_after_end: value.keep_game_after_end,
+            admin_max_subscribers: value.admin_max_subscribers,
+            sse_keepalive_secs: value.sse_keepalive_secs,
+            applause_ms: value.applause_ms,
+            log_answers: value.log_answers,
+            spectators_debounce_ms: value.spectators_debounce_ms,
+            non_answering_pattern: value.non_answering_pattern,
+            max_team_name_len: value.max_team_name_len,
+            team_name_charset: value.team_name_charset,
+            leaderboard_key: value.leaderboard_key,
+            buzz_cue: value.buzz_cue,
+            bonus_scoring: value.bonus_scoring,
+            degraded_read_fallback: value.degraded_read_fallback,
+            buzzer_pattern_cache_size: value.buzzer_pattern_cache_size,
+            storage_max_concurrent_writes: value.storage_max_concurrent_writes,
+            buzzer_ident_timeout_secs: value.buzzer_ident_timeout_secs,
+            buzzer_ping_interval_secs: value.buzzer_ping_interval_secs,
+            transition_timeout_ms: value.transition_timeout_ms,
+        }
     }
 }
 
@@ -146,11 +815,34 @@ struct RawColor {
 }
 
 impl From<RawColor> for TeamColor {
+    /// Bring a configured color into range: the hue is wrapped into `[0, 360)` and saturation
+    /// and value are clamped to `[0, 1]`, with a warning for every adjusted component.
     fn from(value: RawColor) -> Self {
+        let component = |name: &str, configured: f32, applied: f32| {
+            if configured != applied {
+                warn!(
+                    component = name,
+                    configured, applied, "color component out of range"
+                );
+            }
+            applied
+        };
+        let unit = |component: f32| {
+            if component.is_nan() {
+                0.0
+            } else {
+                component.clamp(0.0, 1.0)
+            }
+        };
+        let hue = if value.hue.is_finite() {
+            wrap_hue(value.hue)
+        } else {
+            0.0
+        };
         Self {
-            h: value.hue,
-            s: value.saturation,
-            v: value.value,
+            h: component("hue", value.hue, hue),
+            s: component("saturation", value.saturation, unit(value.saturation)),
+            v: component("value", value.value, unit(value.value)),
         }
     }
 }
@@ -168,6 +860,10 @@ struct RawPatternSet {
     answering: Option<RawPatternTemplate>,
     #[serde(default)]
     waiting: Option<RawPatternTemplate>,
+    #[serde(default)]
+    correct: Option<RawPatternTemplate>,
+    #[serde(default)]
+    identify: Option<RawPatternTemplate>,
 }
 
 impl RawPatternSet {
@@ -188,6 +884,12 @@ impl RawPatternSet {
         if let Some(pattern) = self.waiting {
             defaults.waiting = pattern.into_template(&defaults.waiting);
         }
+        if let Some(pattern) = self.correct {
+            defaults.correct = pattern.into_template(&defaults.correct);
+        }
+        if let Some(pattern) = self.identify {
+            defaults.identify = pattern.into_template(&defaults.identify);
+        }
         defaults
     }
 }
@@ -217,7 +919,7 @@ impl RawPatternTemplate {
             .or(default.static_color);
 
         PatternTemplate {
-            kind: self.kind,
+            kind: self.kind.with_clamped_brightness(),
             static_color,
         }
     }
@@ -232,20 +934,55 @@ enum RawPatternKind {
     Off,
 }
 
+impl RawPatternKind {
+    /// Bring the configured brightness back into `0.0..=1.0`.
+    fn with_clamped_brightness(self) -> Self {
+        match self {
+            Self::Blink(details) => Self::Blink(details.with_clamped_brightness()),
+            Self::Wave(details) => Self::Wave(details.with_clamped_brightness()),
+            Self::Off => Self::Off,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 /// Timing metadata attached to blink/wave patterns.
 struct RawPatternDetails {
     duration_ms: usize,
     period_ms: usize,
     dc: f32,
+    #[serde(default = "default_brightness")]
+    brightness: f32,
+}
+
+fn default_brightness() -> f32 {
+    DEFAULT_BRIGHTNESS
 }
 
 impl RawPatternDetails {
+    fn with_clamped_brightness(mut self) -> Self {
+        let brightness = if self.brightness.is_nan() {
+            DEFAULT_BRIGHTNESS
+        } else {
+            self.brightness.clamp(0.0, 1.0)
+        };
+        if brightness != self.brightness {
+            warn!(
+                configured = self.brightness,
+                applied = brightness,
+                "pattern brightness out of range"
+            );
+            self.brightness = brightness;
+        }
+        self
+    }
+
     fn to_buzzer_pattern_details(&self, color: TeamColorDto) -> BuzzerPatternDetails {
         BuzzerPatternDetails {
             duration_ms: self.duration_ms,
             period_ms: self.period_ms,
             dc: self.dc,
+            brightness: self.brightness,
             color,
         }
     }
@@ -271,6 +1008,7 @@ impl PatternTemplate {
                 duration_ms,
                 period_ms,
                 dc,
+                brightness: DEFAULT_BRIGHTNESS,
             }),
             static_color,
         }
@@ -288,6 +1026,7 @@ impl PatternTemplate {
                 duration_ms,
                 period_ms,
                 dc,
+                brightness: DEFAULT_BRIGHTNESS,
             }),
             static_color,
         }
@@ -335,6 +1074,10 @@ pub struct PatternSet {
     answering: PatternTemplate,
     /// Pattern applied to teams that are temporarily waiting.
     waiting: PatternTemplate,
+    /// Pattern briefly flashed on a team whose answer was validated correct.
+    correct: PatternTemplate,
+    /// Pattern briefly flashed on a buzzer the GM asked to identify.
+    identify: PatternTemplate,
 }
 
 impl PatternSet {
@@ -346,6 +1089,8 @@ impl PatternSet {
             BuzzerPatternPreset::Playing(color) => self.playing.pattern(Some(color)),
             BuzzerPatternPreset::Answering(color) => self.answering.pattern(Some(color)),
             BuzzerPatternPreset::Waiting => self.waiting.pattern(None),
+            BuzzerPatternPreset::Correct(color) => self.correct.pattern(Some(color)),
+            BuzzerPatternPreset::Identify => self.identify.pattern(None),
         }
     }
 }
@@ -364,6 +1109,10 @@ pub enum BuzzerPatternPreset {
     Answering(TeamColor),
     /// Pattern for teams temporarily waiting (no color information required).
     Waiting,
+    /// Celebratory pattern briefly shown when a team's answer is validated correct.
+    Correct(TeamColor),
+    /// Pattern briefly shown on a buzzer the GM asked to identify (no color information required).
+    Identify,
 }
 
 /// Built-in colors set shipped with the binary.
@@ -488,5 +1237,160 @@ fn default_patterns() -> PatternSet {
         playing: PatternTemplate::wave(0, 3_000, 0.5, None),
         answering: PatternTemplate::blink(0, 500, 0.5, None),
         waiting: PatternTemplate::off(),
+        correct: PatternTemplate::blink(0, 150, 0.5, None),
+        identify: PatternTemplate::blink(0, 100, 0.5, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::admin::ConfigResponse;
+
+    fn color(h: f32) -> TeamColor {
+        TeamColor { h, s: 1.0, v: 1.0 }
+    }
+
+    fn config_with(colors: Vec<TeamColor>, min_color_distance: Option<f32>) -> AppConfig {
+        AppConfig {
+            colors,
+            min_color_distance,
+            ..AppConfig::default()
+        }
+    }
+
+    #[test]
+    fn contrast_enforcement_skips_near_duplicates() {
+        let palette = vec![color(0.0), color(4.0), color(120.0)];
+        let used = [color(0.0)];
+
+        let relaxed = config_with(palette.clone(), None);
+        assert_eq!(relaxed.first_unused_color(&used), color(4.0));
+
+        let strict = config_with(palette, Some(0.1));
+        assert_eq!(strict.first_unused_color(&used), color(120.0));
+    }
+
+    #[test]
+    fn contrast_enforcement_falls_back_to_first_unused() {
+        let strict = config_with(vec![color(0.0), color(4.0)], Some(0.1));
+        assert_eq!(strict.first_unused_color(&[color(0.0)]), color(4.0));
+    }
+
+    fn taken_in(names: &[String]) -> impl Fn(&str) -> bool + '_ {
+        move |candidate| names.iter().any(|name| name == candidate)
+    }
+
+    #[test]
+    fn templated_team_names_are_unique() {
+        let config = AppConfig::default();
+        let mut names: Vec<String> = Vec::new();
+        for index in 0..3 {
+            let name = config.default_team_name(index, taken_in(&names));
+            names.push(name);
+        }
+        assert_eq!(names, ["Team 1", "Team 2", "Team 3"]);
+
+        // A manually named "Team 2" pushes the next auto-created team further along.
+        let names = vec!["Team 2".to_string()];
+        assert_eq!(config.default_team_name(1, taken_in(&names)), "Team 3");
+    }
+
+    #[test]
+    fn listed_team_names_cycle_with_counter() {
+        let config = AppConfig {
+            default_team_names: TeamNames::List(vec!["Owls".into(), "Foxes".into()]),
+            ..AppConfig::default()
+        };
+        let mut names: Vec<String> = Vec::new();
+        for index in 0..5 {
+            let name = config.default_team_name(index, taken_in(&names));
+            names.push(name);
+        }
+        assert_eq!(names, ["Owls", "Foxes", "Owls 2", "Foxes 2", "Owls 3"]);
+    }
+
+    #[test]
+    fn template_without_placeholder_gets_a_counter() {
+        let config = AppConfig {
+            default_team_names: TeamNames::Template("Quiz Team".into()),
+            ..AppConfig::default()
+        };
+        let mut names: Vec<String> = Vec::new();
+        for index in 0..3 {
+            let name = config.default_team_name(index, taken_in(&names));
+            names.push(name);
+        }
+        assert_eq!(names, ["Quiz Team", "Quiz Team 2", "Quiz Team 3"]);
+    }
+
+    #[test]
+    fn config_view_reflects_loaded_values() {
+        let before = serde_json::to_value(ConfigResponse::from(&AppConfig::default())).unwrap();
+        assert_eq!(before["one_buzz_per_song"], false);
+        assert_eq!(before["default_team_names"], "Team {n}");
+        assert!(before["event_recording"].is_null());
+        assert_eq!(before["transition_timeout_ms"], 5_000);
+
+        let raw: RawConfig = serde_json::from_str(
+            r#"{
+                "colors": [{"hue": 120.0, "saturation": 0.5, "value": 1.0}],
+                "patterns": {"standby": {"type": "off"}},
+                "one_buzz_per_song": true,
+                "default_team_names": ["Owls", "Foxes"],
+                "event_recording": {"path": "events.ndjson"},
+                "transition_timeout_ms": null
+            }"#,
+        )
+        .unwrap();
+        let after = serde_json::to_value(ConfigResponse::from(&AppConfig::from(raw))).unwrap();
+        assert_eq!(
+            after["colors"],
+            serde_json::json!([{"h": 120.0, "s": 0.5, "v": 1.0}])
+        );
+        assert_eq!(after["patterns"]["standby"]["type"], "off");
+        assert_eq!(after["one_buzz_per_song"], true);
+        assert_eq!(
+            after["default_team_names"],
+            serde_json::json!(["Owls", "Foxes"])
+        );
+        assert_eq!(after["event_recording"]["path"], "events.ndjson");
+        assert_eq!(after["event_recording"]["max_bytes"], 10 * 1024 * 1024);
+        assert!(after["transition_timeout_ms"].is_null());
+    }
+
+    #[test]
+    fn configured_colors_are_brought_into_range() {
+        let config = AppConfig::from_json(
+            r#"{"colors": [{"hue": -60.0, "saturation": 1.5, "value": 2.0}]}"#,
+        );
+        assert_eq!(
+            config.colors,
+            [TeamColor {
+                h: 300.0,
+                s: 1.0,
+                v: 1.0
+            }]
+        );
+    }
+
+    #[test]
+    fn pattern_brightness_defaults_to_full_and_is_clamped() {
+        let config = AppConfig::from_json(
+            r#"{"patterns": {
+                "standby": {"type": "wave", "duration_ms": 0, "period_ms": 5000, "dc": 0.2, "brightness": 0.3},
+                "playing": {"type": "wave", "duration_ms": 0, "period_ms": 3000, "dc": 0.5, "brightness": 4.0},
+                "answering": {"type": "blink", "duration_ms": 0, "period_ms": 500, "dc": 0.5}
+            }}"#,
+        );
+        let brightness = |preset| match config.buzzer_pattern(preset) {
+            BuzzerPattern::Blink(details) | BuzzerPattern::Wave(details) => details.brightness,
+            BuzzerPattern::Off => panic!("unexpected off pattern"),
+        };
+
+        assert_eq!(brightness(BuzzerPatternPreset::Standby(color(0.0))), 0.3);
+        assert_eq!(brightness(BuzzerPatternPreset::Playing(color(0.0))), 1.0);
+        assert_eq!(brightness(BuzzerPatternPreset::Answering(color(0.0))), 1.0);
+        assert_eq!(brightness(BuzzerPatternPreset::WaitingForPairing), 1.0);
     }
 }
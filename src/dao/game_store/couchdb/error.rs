@@ -48,6 +48,9 @@ pub enum CouchDaoError {
     /// CouchDB returned an unexpected status code for a document endpoint.
     #[error("unexpected CouchDB response status {status} for `{path}`")]
     RequestStatus { path: String, status: StatusCode },
+    /// A write kept hitting revision conflicts after refreshing the revision on every attempt.
+    #[error("CouchDB document `{path}` still conflicts after {attempts} attempts")]
+    ConflictRetriesExhausted { path: String, attempts: usize },
     /// Response payload could not be parsed into JSON.
     #[error("failed to decode CouchDB response for `{path}`")]
     DecodeResponse {
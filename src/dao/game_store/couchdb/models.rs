@@ -6,12 +6,16 @@ use uuid::Uuid;
 
 use crate::dao::{
     game_store::couchdb::error::CouchDaoError,
-    models::{GameEntity, PlaylistEntity, SongEntity, TeamColorEntity, TeamEntity},
+    models::{
+        FinishReasonEntity, GameEntity, PlaylistAnalyticsEntity, PlaylistEntity,
+        SongAnalyticsEntity, SongEntity, TeamColorEntity, TeamEntity,
+    },
 };
 
 pub const GAME_PREFIX: &str = "game::";
 pub const PLAYLIST_PREFIX: &str = "playlist::";
 pub const TEAM_PREFIX: &str = "team::";
+pub const ANALYTICS_PREFIX: &str = "analytics::";
 pub const END_SUFFIX: &str = "\u{ffff}";
 
 #[derive(Debug, Deserialize)]
@@ -21,7 +25,6 @@ pub struct AllDocsResponse {
 
 #[derive(Debug, Deserialize)]
 pub struct AllDocsRow {
-    pub id: String,
     #[serde(default)]
     pub doc: Option<Value>,
 }
@@ -46,6 +49,12 @@ pub struct GameBody {
     pub playlist_song_order: Vec<u32>,
     pub current_song_index: Option<usize>,
     pub current_song_found: bool,
+    #[serde(default)]
+    pub enforce_required_fields: bool,
+    #[serde(default)]
+    pub finished: bool,
+    #[serde(default)]
+    pub finish_reason: Option<FinishReasonEntity>,
 }
 
 impl From<(GameEntity, Option<String>)> for CouchGameDocument {
@@ -63,6 +72,9 @@ impl From<(GameEntity, Option<String>)> for CouchGameDocument {
                 playlist_song_order: game.playlist_song_order,
                 current_song_index: game.current_song_index,
                 current_song_found: game.current_song_found,
+                enforce_required_fields: game.enforce_required_fields,
+                finished: game.finished,
+                finish_reason: game.finish_reason,
             },
         }
     }
@@ -134,6 +146,9 @@ impl CouchGameDocument {
             playlist_song_order: self.game.playlist_song_order,
             current_song_index: self.game.current_song_index,
             current_song_found: self.game.current_song_found,
+            enforce_required_fields: self.game.enforce_required_fields,
+            finished: self.game.finished,
+            finish_reason: self.game.finish_reason,
         })
     }
 }
@@ -152,6 +167,8 @@ pub struct CouchPlaylistDocument {
 pub struct PlaylistBody {
     pub name: String,
     pub songs: Vec<SongEntity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub song_order: Vec<u32>,
 }
 
 impl From<(PlaylistEntity, Option<String>)> for CouchPlaylistDocument {
@@ -162,6 +179,7 @@ impl From<(PlaylistEntity, Option<String>)> for CouchPlaylistDocument {
             playlist: PlaylistBody {
                 name: value.name,
                 songs: value.songs,
+                song_order: value.song_order,
             },
         }
     }
@@ -175,6 +193,37 @@ impl TryFrom<CouchPlaylistDocument> for PlaylistEntity {
             id: extract_uuid(&doc.id)?,
             name: doc.playlist.name,
             songs: doc.playlist.songs,
+            song_order: doc.playlist.song_order,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CouchAnalyticsDocument {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(rename = "_rev", skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    pub songs: Vec<SongAnalyticsEntity>,
+}
+
+impl From<(PlaylistAnalyticsEntity, Option<String>)> for CouchAnalyticsDocument {
+    fn from((value, rev): (PlaylistAnalyticsEntity, Option<String>)) -> Self {
+        Self {
+            id: analytics_doc_id(value.playlist_id),
+            rev,
+            songs: value.songs,
+        }
+    }
+}
+
+impl TryFrom<CouchAnalyticsDocument> for PlaylistAnalyticsEntity {
+    type Error = CouchDaoError;
+
+    fn try_from(doc: CouchAnalyticsDocument) -> Result<Self, Self::Error> {
+        Ok(Self {
+            playlist_id: extract_uuid(&doc.id)?,
+            songs: doc.songs,
         })
     }
 }
@@ -187,6 +236,10 @@ pub fn playlist_doc_id(id: Uuid) -> String {
     format!("{}{}", PLAYLIST_PREFIX, id)
 }
 
+pub fn analytics_doc_id(id: Uuid) -> String {
+    format!("{}{}", ANALYTICS_PREFIX, id)
+}
+
 pub fn extract_uuid(doc_id: &str) -> Result<Uuid, CouchDaoError> {
     let (_, id) = doc_id
         .split_once("::")
@@ -216,6 +269,8 @@ pub struct TeamBody {
     pub game_id: Uuid,
     pub team_id: Uuid,
     pub name: String,
+    #[serde(default)]
+    pub buzzer_ids: Vec<String>,
     pub score: i32,
     pub color: TeamColorEntity,
     pub updated_at: SystemTime,
@@ -230,6 +285,7 @@ impl From<(Uuid, TeamEntity, Option<String>)> for CouchTeamDocument {
                 game_id,
                 team_id: team.id,
                 name: team.name,
+                buzzer_ids: team.buzzer_ids,
                 score: team.score,
                 color: team.color,
                 updated_at: team.updated_at,
@@ -243,6 +299,7 @@ impl From<CouchTeamDocument> for TeamEntity {
         TeamEntity {
             id: doc.team.team_id,
             name: doc.team.name,
+            buzzer_ids: doc.team.buzzer_ids,
             score: doc.team.score,
             color: doc.team.color,
             updated_at: doc.team.updated_at,
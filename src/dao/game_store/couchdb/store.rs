@@ -8,7 +8,7 @@ use uuid::Uuid;
 
 use crate::dao::{
     game_store::GameStore,
-    models::{GameEntity, GameListItemEntity, PlaylistEntity, TeamEntity},
+    models::{GameEntity, GameListItemEntity, PlaylistAnalyticsEntity, PlaylistEntity, TeamEntity},
     storage::{StorageError, StorageResult},
 };
 
@@ -16,9 +16,9 @@ use super::{
     config::CouchConfig,
     error::{CouchDaoError, CouchResult},
     models::{
-        AllDocsResponse, CouchGameDocument, CouchPlaylistDocument, CouchTeamDocument, END_SUFFIX,
-        GAME_PREFIX, PLAYLIST_PREFIX, TEAM_PREFIX, extract_uuid, game_doc_id, playlist_doc_id,
-        team_doc_id,
+        AllDocsResponse, CouchAnalyticsDocument, CouchGameDocument, CouchPlaylistDocument,
+        CouchTeamDocument, END_SUFFIX, GAME_PREFIX, PLAYLIST_PREFIX, TEAM_PREFIX, analytics_doc_id,
+        extract_uuid, game_doc_id, playlist_doc_id, team_doc_id,
     },
 };
 
@@ -31,48 +31,53 @@ pub struct CouchGameStore {
     auth: Option<(Arc<str>, Arc<str>)>,
 }
 
-impl CouchGameStore {
-    /// Execute an operation with optimistic retry on CouchDB conflict (409).
-    ///
-    /// Uses exponential backoff to handle concurrent write conflicts.
-    /// This pattern is appropriate for operations where:
-    /// - Conflicts are expected during normal operation
-    /// - The operation can be safely retried with fresh data
-    /// - The semantic intent remains valid despite conflicts
-    async fn retry_on_conflict<F, Fut, T>(&self, operation: F) -> CouchResult<T>
-    where
-        F: Fn() -> Fut,
-        Fut: std::future::Future<Output = CouchResult<T>>,
-    {
-        const MAX_ATTEMPTS: usize = 5;
-
-        for attempt in 1..=MAX_ATTEMPTS {
-            match operation().await {
-                Ok(result) => return Ok(result),
-                Err(e) => match &e {
-                    CouchDaoError::RequestStatus { status, .. }
-                        if *status == StatusCode::CONFLICT =>
-                    {
-                        if attempt >= MAX_ATTEMPTS {
-                            return Err(e);
-                        }
-                        // Exponential backoff: 50ms, 100ms, 200ms, 400ms
-                        let backoff = std::time::Duration::from_millis(50 * (1 << (attempt - 1)));
-                        tokio::time::sleep(backoff).await;
-                    }
-                    _ => return Err(e),
-                },
+/// Number of attempts of a write that keeps hitting revision conflicts.
+const MAX_CONFLICT_ATTEMPTS: usize = 5;
+
+/// Execute an operation with optimistic retry on CouchDB conflict (409).
+///
+/// Uses exponential backoff to handle concurrent write conflicts.
+/// This pattern is appropriate for operations where:
+/// - Conflicts are expected during normal operation
+/// - The operation can be safely retried with fresh data (each attempt must fetch the latest
+///   `_rev` again)
+/// - The semantic intent remains valid despite conflicts
+///
+/// Once every attempt conflicted, fails with [`CouchDaoError::ConflictRetriesExhausted`].
+async fn retry_on_conflict<F, Fut, T>(operation: F) -> CouchResult<T>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = CouchResult<T>>,
+{
+    for attempt in 1..=MAX_CONFLICT_ATTEMPTS {
+        match operation().await {
+            Ok(result) => return Ok(result),
+            Err(CouchDaoError::RequestStatus { path, status })
+                if status == StatusCode::CONFLICT =>
+            {
+                if attempt >= MAX_CONFLICT_ATTEMPTS {
+                    return Err(CouchDaoError::ConflictRetriesExhausted {
+                        path,
+                        attempts: attempt,
+                    });
+                }
+                // Exponential backoff: 50ms, 100ms, 200ms, 400ms
+                let backoff = std::time::Duration::from_millis(50 * (1 << (attempt - 1)));
+                tokio::time::sleep(backoff).await;
             }
+            Err(e) => return Err(e),
         }
-        unreachable!()
     }
+    unreachable!()
+}
 
+impl CouchGameStore {
     /// Save a team document with optimistic retry on conflict.
     async fn save_team_document(&self, game_id: Uuid, team: &TeamEntity) -> CouchResult<()> {
         let doc_id = team_doc_id(game_id, team.id);
         let team = team.clone();
 
-        self.retry_on_conflict(|| async {
+        retry_on_conflict(|| async {
             let rev = self
                 .get_document::<CouchTeamDocument>(&doc_id)
                 .await?
@@ -98,10 +103,10 @@ impl CouchGameStore {
     /// Delete a single team document.
     async fn delete_team_document(&self, game_id: Uuid, team_id: Uuid) -> CouchResult<()> {
         let doc_id = team_doc_id(game_id, team_id);
-        if let Some(doc) = self.get_document::<CouchTeamDocument>(&doc_id).await? {
-            if let Some(rev) = doc.rev {
-                self.delete_document(&doc_id, &rev).await?;
-            }
+        if let Some(doc) = self.get_document::<CouchTeamDocument>(&doc_id).await?
+            && let Some(rev) = doc.rev
+        {
+            self.delete_document(&doc_id, &rev).await?;
         }
         Ok(())
     }
@@ -393,7 +398,7 @@ impl CouchGameStore {
     async fn save_game_document(&self, game: GameEntity) -> CouchResult<()> {
         let doc_id = game_doc_id(game.id);
 
-        self.retry_on_conflict(|| async {
+        retry_on_conflict(|| async {
             let rev = self
                 .get_document::<CouchGameDocument>(&doc_id)
                 .await?
@@ -458,17 +463,16 @@ impl GameStore for CouchGameStore {
         Box::pin(async move {
             let doc_id = playlist_doc_id(playlist.id);
 
-            store
-                .retry_on_conflict(|| async {
-                    let rev = store
-                        .get_document::<CouchPlaylistDocument>(&doc_id)
-                        .await?
-                        .and_then(|doc| doc.rev);
-                    let doc: CouchPlaylistDocument = (playlist.clone(), rev).into();
-                    store.put_document(&doc_id, &doc).await
-                })
-                .await
-                .map_err(Into::into)
+            retry_on_conflict(|| async {
+                let rev = store
+                    .get_document::<CouchPlaylistDocument>(&doc_id)
+                    .await?
+                    .and_then(|doc| doc.rev);
+                let doc: CouchPlaylistDocument = (playlist.clone(), rev).into();
+                store.put_document(&doc_id, &doc).await
+            })
+            .await
+            .map_err(Into::into)
         })
     }
 
@@ -506,6 +510,43 @@ impl GameStore for CouchGameStore {
         })
     }
 
+    /// Load the analytics document of a playlist from CouchDB.
+    fn find_playlist_analytics(
+        &self,
+        playlist_id: Uuid,
+    ) -> BoxFuture<'static, StorageResult<Option<PlaylistAnalyticsEntity>>> {
+        let store = self.clone();
+        Box::pin(async move {
+            let doc_id = analytics_doc_id(playlist_id);
+            let maybe_doc = store
+                .get_document::<CouchAnalyticsDocument>(&doc_id)
+                .await?;
+            Ok(maybe_doc.map(TryInto::try_into).transpose()?)
+        })
+    }
+
+    /// Persist a [`PlaylistAnalyticsEntity`] into CouchDB with optimistic retry on conflict.
+    fn save_playlist_analytics(
+        &self,
+        analytics: PlaylistAnalyticsEntity,
+    ) -> BoxFuture<'static, StorageResult<()>> {
+        let store = self.clone();
+        Box::pin(async move {
+            let doc_id = analytics_doc_id(analytics.playlist_id);
+
+            retry_on_conflict(|| async {
+                let rev = store
+                    .get_document::<CouchAnalyticsDocument>(&doc_id)
+                    .await?
+                    .and_then(|doc| doc.rev);
+                let doc: CouchAnalyticsDocument = (analytics.clone(), rev).into();
+                store.put_document(&doc_id, &doc).await
+            })
+            .await
+            .map_err(Into::into)
+        })
+    }
+
     /// Produce a list of known games comprising identifiers and titles.
     fn list_games(&self) -> BoxFuture<'static, StorageResult<Vec<GameListItemEntity>>> {
         let store = self.clone();
@@ -581,6 +622,28 @@ impl GameStore for CouchGameStore {
         })
     }
 
+    /// Delete a playlist document, retrying when its revision changed meanwhile.
+    fn delete_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
+        let store = self.clone();
+        Box::pin(async move {
+            let doc_id = playlist_doc_id(id);
+
+            retry_on_conflict(|| async {
+                let Some(doc) = store.get_document::<CouchPlaylistDocument>(&doc_id).await? else {
+                    return Ok(false);
+                };
+                let rev = doc.rev.ok_or_else(|| CouchDaoError::DeserializeValue {
+                    path: doc_id.clone(),
+                    source: JsonError::io(io::Error::other("missing _rev for CouchDB document")),
+                })?;
+                store.delete_document(&doc_id, &rev).await?;
+                Ok(true)
+            })
+            .await
+            .map_err(Into::into)
+        })
+    }
+
     fn delete_game(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
         let store = self.clone();
         Box::pin(async move {
@@ -606,6 +669,10 @@ impl GameStore for CouchGameStore {
         })
     }
 
+    fn kind(&self) -> &'static str {
+        "couch"
+    }
+
     /// Ping the remote database to ensure the connection is healthy.
     fn health_check(&self) -> BoxFuture<'static, StorageResult<()>> {
         let store = self.clone();
@@ -642,3 +709,64 @@ impl GameStore for CouchGameStore {
         Box::pin(async move { store.ensure_database().await.map_err(Into::into) })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Mimic a CouchDB PUT: the write only succeeds with the document's current revision.
+    fn put(current_rev: &AtomicUsize, rev: usize) -> CouchResult<()> {
+        if current_rev.load(Ordering::SeqCst) == rev {
+            current_rev.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        } else {
+            Err(CouchDaoError::RequestStatus {
+                path: "team::1".into(),
+                status: StatusCode::CONFLICT,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn stale_revision_is_refetched_and_the_write_retried() {
+        let current_rev = AtomicUsize::new(1);
+        let attempts = AtomicUsize::new(0);
+
+        retry_on_conflict(|| async {
+            let rev = current_rev.load(Ordering::SeqCst);
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                // Another writer saves the document between our GET and PUT.
+                current_rev.fetch_add(1, Ordering::SeqCst);
+            }
+            put(&current_rev, rev)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(current_rev.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn persistent_conflicts_surface_once_retries_are_exhausted() {
+        let current_rev = AtomicUsize::new(1);
+
+        let err = retry_on_conflict(|| async {
+            let rev = current_rev.load(Ordering::SeqCst);
+            current_rev.fetch_add(1, Ordering::SeqCst);
+            put(&current_rev, rev)
+        })
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            CouchDaoError::ConflictRetriesExhausted {
+                attempts: MAX_CONFLICT_ATTEMPTS,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,242 @@
+//! Process-local [`GameStore`] keeping every document in memory, for integration tests and
+//! local demos that should not depend on a running database.
+//!
+//! Documents are laid out like in the database backends: the game document only references its
+//! teams, which are stored separately and reassembled in the game's team order on read. Nothing
+//! survives a restart.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use futures::{
+    FutureExt,
+    future::{self, BoxFuture},
+};
+use uuid::Uuid;
+
+use super::GameStore;
+use crate::dao::{
+    models::{GameEntity, GameListItemEntity, PlaylistAnalyticsEntity, PlaylistEntity, TeamEntity},
+    storage::StorageResult,
+};
+
+/// In-memory storage backend; clones share the same collections.
+#[derive(Clone, Default)]
+pub struct InMemoryGameStore {
+    inner: Arc<Collections>,
+}
+
+#[derive(Default)]
+struct Collections {
+    /// Game documents; their `teams` only matter for the team ids and order.
+    games: DashMap<Uuid, GameEntity>,
+    /// Team documents keyed by `(game_id, team_id)`.
+    teams: DashMap<(Uuid, Uuid), TeamEntity>,
+    playlists: DashMap<Uuid, PlaylistEntity>,
+    playlist_analytics: DashMap<Uuid, PlaylistAnalyticsEntity>,
+}
+
+impl InMemoryGameStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assemble a game document with its current team documents.
+    fn assemble_game(&self, mut game: GameEntity) -> GameEntity {
+        game.teams = game
+            .teams
+            .iter()
+            .filter_map(|team| {
+                self.inner
+                    .teams
+                    .get(&(game.id, team.id))
+                    .map(|entry| entry.value().clone())
+            })
+            .collect();
+        game
+    }
+}
+
+impl GameStore for InMemoryGameStore {
+    fn save_game(&self, game: GameEntity) -> BoxFuture<'static, StorageResult<()>> {
+        for team in &game.teams {
+            self.inner.teams.insert((game.id, team.id), team.clone());
+        }
+        self.inner.games.insert(game.id, game);
+        future::ok(()).boxed()
+    }
+
+    fn save_game_without_teams(&self, game: GameEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.inner.games.insert(game.id, game);
+        future::ok(()).boxed()
+    }
+
+    fn save_playlist(&self, playlist: PlaylistEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.inner.playlists.insert(playlist.id, playlist);
+        future::ok(()).boxed()
+    }
+
+    fn find_game(&self, id: Uuid) -> BoxFuture<'static, StorageResult<Option<GameEntity>>> {
+        let game = self
+            .inner
+            .games
+            .get(&id)
+            .map(|entry| entry.value().clone())
+            .map(|game| self.assemble_game(game));
+        future::ok(game).boxed()
+    }
+
+    fn find_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<Option<PlaylistEntity>>> {
+        let playlist = self
+            .inner
+            .playlists
+            .get(&id)
+            .map(|entry| entry.value().clone());
+        future::ok(playlist).boxed()
+    }
+
+    fn list_games(&self) -> BoxFuture<'static, StorageResult<Vec<GameListItemEntity>>> {
+        let games = self
+            .inner
+            .games
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|game| self.assemble_game(game).into())
+            .collect();
+        future::ok(games).boxed()
+    }
+
+    fn list_playlists(&self) -> BoxFuture<'static, StorageResult<Vec<(Uuid, String)>>> {
+        let playlists = self
+            .inner
+            .playlists
+            .iter()
+            .map(|entry| (entry.id, entry.name.clone()))
+            .collect();
+        future::ok(playlists).boxed()
+    }
+
+    fn delete_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
+        let deleted = self.inner.playlists.remove(&id).is_some();
+        future::ok(deleted).boxed()
+    }
+
+    fn delete_game(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
+        let deleted = self.inner.games.remove(&id).is_some();
+        self.inner.teams.retain(|(game_id, _), _| *game_id != id);
+        future::ok(deleted).boxed()
+    }
+
+    fn save_team(&self, game_id: Uuid, team: TeamEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.inner.teams.insert((game_id, team.id), team);
+        future::ok(()).boxed()
+    }
+
+    fn delete_team(&self, game_id: Uuid, team_id: Uuid) -> BoxFuture<'static, StorageResult<()>> {
+        self.inner.teams.remove(&(game_id, team_id));
+        future::ok(()).boxed()
+    }
+
+    fn find_playlist_analytics(
+        &self,
+        playlist_id: Uuid,
+    ) -> BoxFuture<'static, StorageResult<Option<PlaylistAnalyticsEntity>>> {
+        let analytics = self
+            .inner
+            .playlist_analytics
+            .get(&playlist_id)
+            .map(|entry| entry.value().clone());
+        future::ok(analytics).boxed()
+    }
+
+    fn save_playlist_analytics(
+        &self,
+        analytics: PlaylistAnalyticsEntity,
+    ) -> BoxFuture<'static, StorageResult<()>> {
+        self.inner
+            .playlist_analytics
+            .insert(analytics.playlist_id, analytics);
+        future::ok(()).boxed()
+    }
+
+    fn kind(&self) -> &'static str {
+        "memory"
+    }
+
+    fn health_check(&self) -> BoxFuture<'static, StorageResult<()>> {
+        future::ok(()).boxed()
+    }
+
+    fn try_reconnect(&self) -> BoxFuture<'static, StorageResult<()>> {
+        future::ok(()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::dao::models::TeamColorEntity;
+
+    fn team(name: &str, score: i32) -> TeamEntity {
+        TeamEntity {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            buzzer_ids: Vec::new(),
+            score,
+            color: TeamColorEntity {
+                h: 0.0,
+                s: 1.0,
+                v: 1.0,
+            },
+            updated_at: SystemTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn games_are_reassembled_from_their_team_documents() {
+        let store = InMemoryGameStore::new();
+        let now = SystemTime::now();
+        let mut game = GameEntity {
+            id: Uuid::new_v4(),
+            name: "demo".into(),
+            created_at: now,
+            updated_at: now,
+            teams: vec![team("Blue", 0), team("Red", 0)],
+            playlist_id: Uuid::new_v4(),
+            playlist_song_order: vec![1, 2],
+            current_song_index: Some(0),
+            current_song_found: false,
+            enforce_required_fields: false,
+            finished: false,
+            finish_reason: None,
+        };
+        store.save_game(game.clone()).await.unwrap();
+
+        let mut red = game.teams[1].clone();
+        red.score = 5;
+        store.save_team(game.id, red).await.unwrap();
+        game.name = "renamed".into();
+        game.teams.swap(0, 1);
+        store.save_game_without_teams(game.clone()).await.unwrap();
+
+        let found = store.find_game(game.id).await.unwrap().unwrap();
+        assert_eq!(found.name, "renamed");
+        let teams: Vec<_> = found
+            .teams
+            .iter()
+            .map(|team| (team.name.as_str(), team.score))
+            .collect();
+        assert_eq!(teams, [("Red", 5), ("Blue", 0)]);
+        assert_eq!(store.list_games().await.unwrap()[0].teams.len(), 2);
+
+        assert!(store.delete_game(game.id).await.unwrap());
+        assert!(store.find_game(game.id).await.unwrap().is_none());
+        assert!(store.inner.teams.is_empty());
+        assert!(!store.delete_game(game.id).await.unwrap());
+    }
+}
@@ -1,11 +1,19 @@
 /// CouchDB game store implementation.
 #[cfg(feature = "couch-store")]
 pub mod couchdb;
+/// In-memory game store implementation, for tests and demos.
+#[cfg(feature = "memory-store")]
+pub mod memory;
 /// MongoDB game store implementation.
 #[cfg(feature = "mongo-store")]
 pub mod mongodb;
+/// Store doubles for unit tests.
+#[cfg(test)]
+pub mod testing;
 
-use crate::dao::models::{GameEntity, GameListItemEntity, PlaylistEntity, TeamEntity};
+use crate::dao::models::{
+    GameEntity, GameListItemEntity, PlaylistAnalyticsEntity, PlaylistEntity, TeamEntity,
+};
 use crate::dao::storage::StorageResult;
 use futures::future::BoxFuture;
 use uuid::Uuid;
@@ -26,12 +34,26 @@ pub trait GameStore: Send + Sync {
     fn list_games(&self) -> BoxFuture<'static, StorageResult<Vec<GameListItemEntity>>>;
     /// List all playlists with ID and name pairs.
     fn list_playlists(&self) -> BoxFuture<'static, StorageResult<Vec<(Uuid, String)>>>;
+    /// Delete a playlist entity, returning whether it existed.
+    fn delete_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>>;
     /// Delete a game entity and all its associated team documents.
     fn delete_game(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>>;
     /// Save a single team document for a game.
     fn save_team(&self, game_id: Uuid, team: TeamEntity) -> BoxFuture<'static, StorageResult<()>>;
     /// Delete a single team document from a game.
     fn delete_team(&self, game_id: Uuid, team_id: Uuid) -> BoxFuture<'static, StorageResult<()>>;
+    /// Find the analytics accumulated for a playlist, if any were recorded.
+    fn find_playlist_analytics(
+        &self,
+        playlist_id: Uuid,
+    ) -> BoxFuture<'static, StorageResult<Option<PlaylistAnalyticsEntity>>>;
+    /// Save the analytics document of a playlist, replacing any previous version.
+    fn save_playlist_analytics(
+        &self,
+        analytics: PlaylistAnalyticsEntity,
+    ) -> BoxFuture<'static, StorageResult<()>>;
+    /// Short name of the backend kind (`mongo`, `couch`, `memory`), reported by the health routes.
+    fn kind(&self) -> &'static str;
     /// Verify storage backend is reachable and operational.
     fn health_check(&self) -> BoxFuture<'static, StorageResult<()>>;
     /// Attempt to reconnect to the storage backend after a disconnection.
@@ -1,4 +1,6 @@
-use mongodb::options::ClientOptions;
+use std::time::Duration;
+
+use mongodb::options::{Acknowledgment, ClientOptions, WriteConcern};
 
 use super::error::{MongoDaoError, MongoResult};
 
@@ -30,11 +32,157 @@ impl MongoConfig {
     }
 
     /// Build a configuration by reading MongoDB connection details from environment variables.
+    ///
+    /// The optional `MONGO_MAX_POOL_SIZE`, `MONGO_MIN_POOL_SIZE`, `MONGO_WRITE_CONCERN` and
+    /// `MONGO_WRITE_TIMEOUT_MS` override the matching options of the URI; the driver defaults
+    /// apply when neither sets them.
     pub async fn from_env() -> MongoResult<Self> {
         let uri = std::env::var("MONGO_URI")
             .map_err(|_| MongoDaoError::MissingEnvVar { var: "MONGO_URI" })?;
         let db = std::env::var("MONGO_DB")
             .map_err(|_| MongoDaoError::MissingEnvVar { var: "MONGO_DB" })?;
-        Self::from_uri(&uri, Some(&db)).await
+        let mut config = Self::from_uri(&uri, Some(&db)).await?;
+        apply_overrides(&mut config.options, |var| std::env::var(var).ok())?;
+        Ok(config)
+    }
+}
+
+/// Apply the pool and write concern overrides found by `lookup` to `options`, rejecting values
+/// MongoDB would not accept.
+fn apply_overrides(
+    options: &mut ClientOptions,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> MongoResult<()> {
+    let read = |var: &'static str| {
+        lookup(var)
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+            .map(|value| (var, value))
+    };
+    let invalid = |var, value: String, reason| MongoDaoError::InvalidEnvVar { var, value, reason };
+
+    if let Some((var, value)) = read("MONGO_MAX_POOL_SIZE") {
+        match value.parse::<u32>() {
+            Ok(size) if size > 0 => options.max_pool_size = Some(size),
+            _ => return Err(invalid(var, value, "expected a positive integer")),
+        }
+    }
+    if let Some((var, value)) = read("MONGO_MIN_POOL_SIZE") {
+        let Ok(size) = value.parse::<u32>() else {
+            return Err(invalid(var, value, "expected a non-negative integer"));
+        };
+        if options.max_pool_size.is_some_and(|max| size > max) {
+            return Err(invalid(var, value, "exceeds the maximum pool size"));
+        }
+        options.min_pool_size = Some(size);
+    }
+
+    let acknowledgment = match read("MONGO_WRITE_CONCERN") {
+        None => None,
+        Some((_, value)) if value.eq_ignore_ascii_case("majority") => {
+            Some(Acknowledgment::Majority)
+        }
+        Some((var, value)) => match value.parse::<u32>() {
+            Ok(nodes) if nodes > 0 => Some(Acknowledgment::Nodes(nodes)),
+            _ => {
+                return Err(invalid(
+                    var,
+                    value,
+                    "expected `majority` or a positive number of nodes",
+                ));
+            }
+        },
+    };
+    let w_timeout = match read("MONGO_WRITE_TIMEOUT_MS") {
+        None => None,
+        Some((var, value)) => match value.parse::<u64>() {
+            Ok(ms) if ms > 0 => Some(Duration::from_millis(ms)),
+            _ => {
+                return Err(invalid(
+                    var,
+                    value,
+                    "expected a positive number of milliseconds",
+                ));
+            }
+        },
+    };
+    if acknowledgment.is_some() || w_timeout.is_some() {
+        let concern = options
+            .write_concern
+            .get_or_insert_with(WriteConcern::default);
+        if acknowledgment.is_some() {
+            concern.w = acknowledgment;
+        }
+        if w_timeout.is_some() {
+            concern.w_timeout = w_timeout;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn apply(vars: &[(&str, &str)]) -> MongoResult<ClientOptions> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(var, value)| (var.to_string(), value.to_string()))
+            .collect();
+        let mut options = ClientOptions::default();
+        apply_overrides(&mut options, |var| vars.get(var).cloned())?;
+        Ok(options)
+    }
+
+    #[test]
+    fn unset_overrides_keep_the_driver_defaults() {
+        let options = apply(&[]).unwrap();
+        assert_eq!(options.max_pool_size, None);
+        assert_eq!(options.min_pool_size, None);
+        assert!(options.write_concern.is_none());
+    }
+
+    #[test]
+    fn pool_and_write_concern_overrides_are_applied() {
+        let options = apply(&[
+            ("MONGO_MAX_POOL_SIZE", "50"),
+            ("MONGO_MIN_POOL_SIZE", "5"),
+            ("MONGO_WRITE_CONCERN", "majority"),
+            ("MONGO_WRITE_TIMEOUT_MS", "2000"),
+        ])
+        .unwrap();
+        assert_eq!(options.max_pool_size, Some(50));
+        assert_eq!(options.min_pool_size, Some(5));
+        let concern = options.write_concern.unwrap();
+        assert_eq!(concern.w, Some(Acknowledgment::Majority));
+        assert_eq!(concern.w_timeout, Some(Duration::from_secs(2)));
+
+        let nodes = apply(&[("MONGO_WRITE_CONCERN", "2")]).unwrap();
+        assert_eq!(
+            nodes.write_concern.unwrap().w,
+            Some(Acknowledgment::Nodes(2))
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        for vars in [
+            [("MONGO_MAX_POOL_SIZE", "0")],
+            [("MONGO_MIN_POOL_SIZE", "-1")],
+            [("MONGO_WRITE_CONCERN", "0")],
+            [("MONGO_WRITE_CONCERN", "everyone")],
+            [("MONGO_WRITE_TIMEOUT_MS", "soon")],
+        ] {
+            assert!(
+                matches!(apply(&vars), Err(MongoDaoError::InvalidEnvVar { .. })),
+                "{vars:?} was accepted"
+            );
+        }
+        assert!(matches!(
+            apply(&[("MONGO_MAX_POOL_SIZE", "4"), ("MONGO_MIN_POOL_SIZE", "8")]),
+            Err(MongoDaoError::InvalidEnvVar { .. })
+        ));
     }
 }
@@ -47,6 +47,12 @@ pub enum MongoDaoError {
         #[source]
         source: MongoError,
     },
+    #[error("failed to delete playlist `{id}`")]
+    DeletePlaylist {
+        id: Uuid,
+        #[source]
+        source: MongoError,
+    },
     #[error("failed to delete game `{id}`")]
     DeleteGame {
         id: Uuid,
@@ -65,6 +71,18 @@ pub enum MongoDaoError {
         #[source]
         source: MongoError,
     },
+    #[error("failed to load analytics for playlist `{id}`")]
+    LoadPlaylistAnalytics {
+        id: Uuid,
+        #[source]
+        source: MongoError,
+    },
+    #[error("failed to save analytics for playlist `{id}`")]
+    SavePlaylistAnalytics {
+        id: Uuid,
+        #[source]
+        source: MongoError,
+    },
     #[error("failed to list games")]
     ListGames {
         #[source]
@@ -77,4 +95,10 @@ pub enum MongoDaoError {
     },
     #[error("missing MongoDB environment variable `{var}`")]
     MissingEnvVar { var: &'static str },
+    #[error("invalid MongoDB environment variable `{var}` = `{value}`: {reason}")]
+    InvalidEnvVar {
+        var: &'static str,
+        value: String,
+        reason: &'static str,
+    },
 }
@@ -1,5 +1,6 @@
 use mongodb::bson::{Binary, DateTime, Document, doc, spec::BinarySubtype};
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 use uuid::Uuid;
 
 // MongoDB document models used by the MongoGameStore.
@@ -10,7 +11,7 @@ use uuid::Uuid;
 // - teams collection:
 //   - `team_game_idx` on { game_id: 1, team_id: 1 } (unique) — enforces one team_id per game
 //     and enables efficient lookup of a team's document within a game.
-use crate::dao::models::{GameEntity, TeamColorEntity, TeamEntity};
+use crate::dao::models::{FinishReasonEntity, GameEntity, TeamColorEntity, TeamEntity};
 
 /// Representation of a game document stored in MongoDB.
 ///
@@ -30,7 +31,12 @@ pub struct MongoGameDocument {
     updated_at: DateTime,
     /// List of team ids in display order. Individual team details live in the `teams`
     /// collection as `MongoTeamDocument` documents.
+    #[serde(default)]
     pub teams: Vec<Uuid>,
+    /// Players embedded by the legacy schema, which predates the `teams` collection. Never
+    /// written back: the next save migrates them into `teams` documents.
+    #[serde(default, skip_serializing)]
+    players: Option<Vec<LegacyPlayerDocument>>,
     /// Referenced playlist id.
     playlist_id: Uuid,
     /// Ordered list of song indices referencing the playlist.
@@ -39,6 +45,15 @@ pub struct MongoGameDocument {
     current_song_index: Option<usize>,
     /// Whether the current song has been found. Default false.
     current_song_found: bool,
+    /// Whether required fields gate the progression. Default false.
+    #[serde(default)]
+    enforce_required_fields: bool,
+    /// Whether the game reached its final scoreboard. Default false.
+    #[serde(default)]
+    finished: bool,
+    /// Why the game reached its final scoreboard, once it did.
+    #[serde(default)]
+    finish_reason: Option<FinishReasonEntity>,
 }
 
 impl From<GameEntity> for MongoGameDocument {
@@ -50,28 +65,85 @@ impl From<GameEntity> for MongoGameDocument {
             created_at: DateTime::from_system_time(game.created_at),
             updated_at: DateTime::from_system_time(game.updated_at),
             teams: team_ids,
+            players: None,
             playlist_id: game.playlist_id,
             playlist_song_order: game.playlist_song_order,
             current_song_index: game.current_song_index,
             current_song_found: game.current_song_found,
+            enforce_required_fields: game.enforce_required_fields,
+            finished: game.finished,
+            finish_reason: game.finish_reason,
         }
     }
 }
 
+impl MongoGameDocument {
+    /// Whether the document was written by the legacy schema embedding its players.
+    pub fn is_legacy(&self) -> bool {
+        self.players.is_some() && self.teams.is_empty()
+    }
+}
+
 impl From<MongoGameDocument> for GameEntity {
     fn from(value: MongoGameDocument) -> Self {
+        let updated_at = value.updated_at.to_system_time();
+        // Teams must be loaded from the `teams` collection; using empty vector here
+        // is a placeholder for call sites that should fetch team documents. Legacy
+        // documents carry their players inline, so those are converted directly.
+        let teams = value
+            .players
+            .unwrap_or_default()
+            .into_iter()
+            .map(|player| player.into_team(updated_at))
+            .collect();
         Self {
             id: value.id,
             name: value.name,
             created_at: value.created_at.to_system_time(),
-            updated_at: value.updated_at.to_system_time(),
-            // Teams must be loaded from the `teams` collection; using empty vector here
-            // is a placeholder for call sites that should fetch team documents.
-            teams: Vec::new(),
+            updated_at,
+            teams,
             playlist_id: value.playlist_id,
             playlist_song_order: value.playlist_song_order,
             current_song_index: value.current_song_index,
             current_song_found: value.current_song_found,
+            enforce_required_fields: value.enforce_required_fields,
+            finished: value.finished,
+            finish_reason: value.finish_reason,
+        }
+    }
+}
+
+/// Player entry embedded in game documents by the legacy schema.
+#[derive(Debug, Clone, Deserialize)]
+struct LegacyPlayerDocument {
+    id: Uuid,
+    name: String,
+    #[serde(default)]
+    score: i32,
+    #[serde(default)]
+    color: Option<TeamColorEntity>,
+    #[serde(default)]
+    updated_at: Option<DateTime>,
+}
+
+impl LegacyPlayerDocument {
+    /// Convert the legacy player into a team, falling back to the game's update time and a
+    /// neutral white color for fields the old schema did not always store.
+    fn into_team(self, game_updated_at: SystemTime) -> TeamEntity {
+        TeamEntity {
+            id: self.id,
+            name: self.name,
+            buzzer_ids: Vec::new(),
+            score: self.score,
+            color: self.color.unwrap_or(TeamColorEntity {
+                h: 0.0,
+                s: 0.0,
+                v: 1.0,
+            }),
+            updated_at: self
+                .updated_at
+                .map(DateTime::to_system_time)
+                .unwrap_or(game_updated_at),
         }
     }
 }
@@ -100,6 +172,9 @@ pub struct MongoTeamDocument {
     pub team_id: Uuid,
     /// Team display name.
     pub name: String,
+    /// Buzzers paired with the team.
+    #[serde(default)]
+    pub buzzer_ids: Vec<String>,
     /// Team score.
     pub score: i32,
     /// Team color.
@@ -114,6 +189,7 @@ impl From<(Uuid, TeamEntity)> for MongoTeamDocument {
             game_id,
             team_id: team.id,
             name: team.name,
+            buzzer_ids: team.buzzer_ids,
             score: team.score,
             color: team.color,
             updated_at: DateTime::from_system_time(team.updated_at),
@@ -126,6 +202,7 @@ impl From<MongoTeamDocument> for (Uuid, TeamEntity) {
         let team = TeamEntity {
             id: doc.team_id,
             name: doc.name,
+            buzzer_ids: doc.buzzer_ids,
             score: doc.score,
             color: doc.color,
             updated_at: doc.updated_at.to_system_time(),
@@ -133,3 +210,77 @@ impl From<MongoTeamDocument> for (Uuid, TeamEntity) {
         (doc.team_id, team)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson;
+
+    #[test]
+    fn legacy_players_are_converted_into_teams() {
+        let game_id = Uuid::new_v4();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let now = DateTime::now();
+        let legacy = doc! {
+            "_id": game_id.to_string(),
+            "name": "legacy",
+            "created_at": now,
+            "updated_at": now,
+            "players": [
+                {
+                    "id": first.to_string(),
+                    "name": "Red",
+                    "score": 7,
+                    "color": { "h": 0.0, "s": 1.0, "v": 1.0 },
+                },
+                { "id": second.to_string(), "name": "Blue" },
+            ],
+            "playlist_id": Uuid::new_v4().to_string(),
+            "playlist_song_order": [0, 1],
+            "current_song_index": null,
+            "current_song_found": false,
+        };
+
+        let document: MongoGameDocument = bson::deserialize_from_document(legacy).unwrap();
+        assert!(document.is_legacy());
+
+        let game: GameEntity = document.clone().into();
+        assert_eq!(game.id, game_id);
+        let teams: Vec<_> = game
+            .teams
+            .iter()
+            .map(|team| (team.id, team.name.as_str(), team.score))
+            .collect();
+        assert_eq!(teams, vec![(first, "Red", 7), (second, "Blue", 0)]);
+        assert_eq!(game.teams[0].color.s, 1.0);
+        assert_eq!(game.teams[1].color.s, 0.0);
+
+        let migrated = bson::serialize_to_document(&MongoGameDocument::from(game)).unwrap();
+        assert!(!migrated.contains_key("players"));
+        assert_eq!(migrated.get_array("teams").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn team_documents_round_trip_buzzer_lists() {
+        let team = TeamEntity {
+            id: Uuid::new_v4(),
+            name: "Red".into(),
+            buzzer_ids: vec!["deadbeef0001".into(), "deadbeef0002".into()],
+            score: 3,
+            color: TeamColorEntity {
+                h: 0.0,
+                s: 1.0,
+                v: 1.0,
+            },
+            updated_at: SystemTime::UNIX_EPOCH,
+        };
+
+        let document =
+            bson::serialize_to_document(&MongoTeamDocument::from((Uuid::new_v4(), team.clone())))
+                .unwrap();
+        let decoded: MongoTeamDocument = bson::deserialize_from_document(document).unwrap();
+        let (_, restored): (Uuid, TeamEntity) = decoded.into();
+        assert_eq!(restored.buzzer_ids, team.buzzer_ids);
+    }
+}
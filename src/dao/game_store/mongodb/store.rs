@@ -13,12 +13,13 @@ use super::{
 };
 use crate::dao::{
     game_store::GameStore,
-    models::{GameEntity, GameListItemEntity, PlaylistEntity, TeamEntity},
+    models::{GameEntity, GameListItemEntity, PlaylistAnalyticsEntity, PlaylistEntity, TeamEntity},
     storage::StorageResult,
 };
 
 const GAME_COLLECTION_NAME: &str = "games";
 const PLAYLIST_COLLECTION_NAME: &str = "playlists";
+const PLAYLIST_ANALYTICS_COLLECTION_NAME: &str = "playlist_analytics";
 
 /// MongoDB implementation of the GameStore trait.
 #[derive(Clone)]
@@ -145,6 +146,13 @@ impl MongoGameStore {
             .collection::<PlaylistEntity>(PLAYLIST_COLLECTION_NAME)
     }
 
+    async fn playlist_analytics_collection(&self) -> Collection<PlaylistAnalyticsEntity> {
+        let guard = self.inner.state.read().await;
+        guard
+            .database
+            .collection::<PlaylistAnalyticsEntity>(PLAYLIST_ANALYTICS_COLLECTION_NAME)
+    }
+
     /// Helper to persist the game document.
     /// Extracts team IDs from the GameEntity.
     async fn save_game_document(&self, game: GameEntity) -> MongoResult<()> {
@@ -182,6 +190,15 @@ impl MongoGameStore {
         self.save_game_document(game).await
     }
 
+    async fn delete_playlist(&self, id: Uuid) -> MongoResult<bool> {
+        let collection = self.playlist_collection().await;
+        let result = collection
+            .delete_one(doc_id(id))
+            .await
+            .map_err(|source| MongoDaoError::DeletePlaylist { id, source })?;
+        Ok(result.deleted_count > 0)
+    }
+
     async fn delete_game(&self, id: Uuid) -> MongoResult<bool> {
         let collection = self.collection().await;
         let result = collection
@@ -219,6 +236,11 @@ impl MongoGameStore {
             None => return Ok(None),
         };
 
+        // Legacy documents embed their players instead of referencing the teams collection.
+        if maybe_doc.is_legacy() {
+            return Ok(Some(maybe_doc.into()));
+        }
+
         // Load team documents for this game and assemble the GameEntity
         let team_coll = self.team_collection().await;
         let team_docs: Vec<MongoTeamDocument> = team_coll
@@ -257,6 +279,34 @@ impl MongoGameStore {
             .map_err(|source| MongoDaoError::LoadPlaylist { id, source })
     }
 
+    async fn find_playlist_analytics(
+        &self,
+        playlist_id: Uuid,
+    ) -> MongoResult<Option<PlaylistAnalyticsEntity>> {
+        let collection = self.playlist_analytics_collection().await;
+
+        collection
+            .find_one(doc_id(playlist_id))
+            .await
+            .map_err(|source| MongoDaoError::LoadPlaylistAnalytics {
+                id: playlist_id,
+                source,
+            })
+    }
+
+    async fn save_playlist_analytics(&self, analytics: PlaylistAnalyticsEntity) -> MongoResult<()> {
+        let collection = self.playlist_analytics_collection().await;
+        let id = analytics.playlist_id;
+
+        collection
+            .replace_one(doc_id(id), &analytics)
+            .upsert(true)
+            .await
+            .map_err(|source| MongoDaoError::SavePlaylistAnalytics { id, source })?;
+
+        Ok(())
+    }
+
     async fn list_games(&self) -> MongoResult<Vec<GameListItemEntity>> {
         let collection = self.collection().await;
 
@@ -336,11 +386,46 @@ impl GameStore for MongoGameStore {
         Box::pin(async move { store.list_playlists().await.map_err(Into::into) })
     }
 
+    fn delete_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
+        let store = self.clone();
+        Box::pin(async move { store.delete_playlist(id).await.map_err(Into::into) })
+    }
+
     fn delete_game(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
         let store = self.clone();
         Box::pin(async move { store.delete_game(id).await.map_err(Into::into) })
     }
 
+    fn find_playlist_analytics(
+        &self,
+        playlist_id: Uuid,
+    ) -> BoxFuture<'static, StorageResult<Option<PlaylistAnalyticsEntity>>> {
+        let store = self.clone();
+        Box::pin(async move {
+            store
+                .find_playlist_analytics(playlist_id)
+                .await
+                .map_err(Into::into)
+        })
+    }
+
+    fn save_playlist_analytics(
+        &self,
+        analytics: PlaylistAnalyticsEntity,
+    ) -> BoxFuture<'static, StorageResult<()>> {
+        let store = self.clone();
+        Box::pin(async move {
+            store
+                .save_playlist_analytics(analytics)
+                .await
+                .map_err(Into::into)
+        })
+    }
+
+    fn kind(&self) -> &'static str {
+        "mongo"
+    }
+
     fn health_check(&self) -> BoxFuture<'static, StorageResult<()>> {
         let store = self.clone();
         Box::pin(async move { store.inner.ping().await.map_err(Into::into) })
@@ -0,0 +1,122 @@
+//! In-memory [`GameStore`] doubles for unit tests.
+
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use futures::{
+    FutureExt,
+    future::{self, BoxFuture},
+};
+use uuid::Uuid;
+
+use super::GameStore;
+use crate::dao::{
+    models::{GameEntity, GameListItemEntity, PlaylistAnalyticsEntity, PlaylistEntity, TeamEntity},
+    storage::StorageResult,
+};
+
+/// Store recording saved games, teams and playlists; every other operation is a no-op.
+#[derive(Default)]
+pub struct RecordingStore {
+    /// Games saved, with or without their teams, in order.
+    pub games: Mutex<Vec<GameEntity>>,
+    /// Teams saved individually, in order.
+    pub teams: Mutex<Vec<TeamEntity>>,
+    /// Playlists saved, in order.
+    pub playlists: Mutex<Vec<PlaylistEntity>>,
+    /// Time each team save takes to complete, to exercise concurrent writes.
+    pub team_save_delay: Duration,
+    /// Highest number of team saves observed in flight at once.
+    pub peak_team_saves: Arc<AtomicUsize>,
+    team_saves_in_flight: Arc<AtomicUsize>,
+}
+
+impl RecordingStore {
+    /// Store whose team saves each take `delay` to complete.
+    pub fn with_team_save_delay(delay: Duration) -> Self {
+        Self {
+            team_save_delay: delay,
+            ..Self::default()
+        }
+    }
+}
+
+impl GameStore for RecordingStore {
+    fn save_game(&self, game: GameEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.games.lock().unwrap().push(game);
+        future::ok(()).boxed()
+    }
+    fn save_game_without_teams(&self, game: GameEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.save_game(game)
+    }
+    fn save_playlist(&self, playlist: PlaylistEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.playlists.lock().unwrap().push(playlist);
+        future::ok(()).boxed()
+    }
+    fn find_game(&self, _: Uuid) -> BoxFuture<'static, StorageResult<Option<GameEntity>>> {
+        future::ok(None).boxed()
+    }
+    fn find_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<Option<PlaylistEntity>>> {
+        let playlists = self.playlists.lock().unwrap();
+        let found = playlists.iter().rev().find(|playlist| playlist.id == id);
+        future::ok(found.cloned()).boxed()
+    }
+    fn list_games(&self) -> BoxFuture<'static, StorageResult<Vec<GameListItemEntity>>> {
+        future::ok(Vec::new()).boxed()
+    }
+    fn list_playlists(&self) -> BoxFuture<'static, StorageResult<Vec<(Uuid, String)>>> {
+        future::ok(Vec::new()).boxed()
+    }
+    fn delete_playlist(&self, id: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
+        let mut playlists = self.playlists.lock().unwrap();
+        let len = playlists.len();
+        playlists.retain(|playlist| playlist.id != id);
+        future::ok(playlists.len() != len).boxed()
+    }
+    fn delete_game(&self, _: Uuid) -> BoxFuture<'static, StorageResult<bool>> {
+        future::ok(false).boxed()
+    }
+    fn save_team(&self, _: Uuid, team: TeamEntity) -> BoxFuture<'static, StorageResult<()>> {
+        self.teams.lock().unwrap().push(team);
+        let delay = self.team_save_delay;
+        let in_flight = self.team_saves_in_flight.clone();
+        let peak = self.peak_team_saves.clone();
+        async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(delay).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }
+        .boxed()
+    }
+    fn delete_team(&self, _: Uuid, _: Uuid) -> BoxFuture<'static, StorageResult<()>> {
+        future::ok(()).boxed()
+    }
+    fn find_playlist_analytics(
+        &self,
+        _: Uuid,
+    ) -> BoxFuture<'static, StorageResult<Option<PlaylistAnalyticsEntity>>> {
+        future::ok(None).boxed()
+    }
+    fn save_playlist_analytics(
+        &self,
+        _: PlaylistAnalyticsEntity,
+    ) -> BoxFuture<'static, StorageResult<()>> {
+        future::ok(()).boxed()
+    }
+    fn kind(&self) -> &'static str {
+        "recording"
+    }
+    fn health_check(&self) -> BoxFuture<'static, StorageResult<()>> {
+        future::ok(()).boxed()
+    }
+    fn try_reconnect(&self) -> BoxFuture<'static, StorageResult<()>> {
+        future::ok(()).boxed()
+    }
+}
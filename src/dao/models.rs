@@ -11,6 +11,9 @@ pub struct PlaylistEntity {
     pub name: String,
     /// Set of songs that make up the game (key is the ID of the song).
     pub songs: Vec<SongEntity>,
+    /// Song IDs in the order new games play them, empty for the insertion order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub song_order: Vec<u32>,
 }
 
 /// Song entry inside a playlist.
@@ -26,6 +29,9 @@ pub struct SongEntity {
     pub point_fields: Vec<PointFieldEntity>,
     /// Optional extra fields that can yield bonus points.
     pub bonus_fields: Vec<PointFieldEntity>,
+    /// Categories the song belongs to (e.g. "90s", "Movies").
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Data for a point field associated to a song of a playlist.
@@ -37,6 +43,63 @@ pub struct PointFieldEntity {
     pub value: String,
     /// The number of points given if this field is found.
     pub points: u8,
+    /// Whether the field must be found before the game can progress past the song.
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Gameplay analytics accumulated across sessions for the songs of a playlist.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlaylistAnalyticsEntity {
+    /// Identifier of the playlist these analytics belong to.
+    pub playlist_id: Uuid,
+    /// Per-song counters, one entry per song that has been resolved at least once.
+    pub songs: Vec<SongAnalyticsEntity>,
+}
+
+/// Counters describing how a single song performed across sessions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SongAnalyticsEntity {
+    /// Identifier of the song inside its playlist.
+    pub song_id: u32,
+    /// Number of times the song has been resolved (revealed).
+    pub plays: u32,
+    /// Number of buzzes received while the song was playing.
+    pub buzzes: u32,
+    /// Sum of the playing time (milliseconds) elapsed before each buzz.
+    pub total_time_to_buzz_ms: u64,
+    /// Number of answers validated as correct.
+    pub correct_count: u32,
+    /// Number of answers validated as wrong.
+    pub wrong_count: u32,
+}
+
+impl PlaylistAnalyticsEntity {
+    /// Build an empty analytics document for the given playlist.
+    pub fn new(playlist_id: Uuid) -> Self {
+        Self {
+            playlist_id,
+            songs: Vec::new(),
+        }
+    }
+
+    /// Add the counters of `delta` to the entry of the matching song.
+    pub fn record(&mut self, delta: SongAnalyticsEntity) {
+        match self
+            .songs
+            .iter_mut()
+            .find(|song| song.song_id == delta.song_id)
+        {
+            Some(song) => {
+                song.plays += delta.plays;
+                song.buzzes += delta.buzzes;
+                song.total_time_to_buzz_ms += delta.total_time_to_buzz_ms;
+                song.correct_count += delta.correct_count;
+                song.wrong_count += delta.wrong_count;
+            }
+            None => self.songs.push(delta),
+        }
+    }
 }
 
 /// Representation of a team stored in persistence and shared across layers.
@@ -46,6 +109,9 @@ pub struct TeamEntity {
     pub id: Uuid,
     /// Display name chosen for the team.
     pub name: String,
+    /// Buzzers paired with the team.
+    #[serde(default)]
+    pub buzzer_ids: Vec<String>,
     /// Current score for the team.
     pub score: i32,
     /// HSV color assigned to the team.
@@ -105,6 +171,25 @@ pub struct GameEntity {
     pub current_song_index: Option<usize>,
     /// Whether the current song has already been revealed.
     pub current_song_found: bool,
+    /// Whether revealing or skipping a song is blocked until its required fields are found.
+    #[serde(default)]
+    pub enforce_required_fields: bool,
+    /// Whether the game reached its final scoreboard.
+    #[serde(default)]
+    pub finished: bool,
+    /// Why the game reached its final scoreboard, once it did.
+    #[serde(default)]
+    pub finish_reason: Option<FinishReasonEntity>,
+}
+
+/// Why a game reached its final scoreboard.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishReasonEntity {
+    /// Every song of the playlist was played.
+    PlaylistCompleted,
+    /// The game master stopped the game early.
+    ManualStop,
 }
 
 /// Aggregate game list item entity (subset of GameEntity) persisted by the storage layer.
@@ -122,6 +207,9 @@ pub struct GameListItemEntity {
     pub teams: Vec<TeamSummaryEntity>,
     /// ID of the playlist used in this game session.
     pub playlist_id: Uuid,
+    /// Why the game reached its final scoreboard, once it did.
+    #[serde(default)]
+    pub finish_reason: Option<FinishReasonEntity>,
 }
 
 impl From<TeamEntity> for TeamSummaryEntity {
@@ -142,6 +230,7 @@ impl From<GameEntity> for GameListItemEntity {
             updated_at: entity.updated_at,
             teams: entity.teams.into_iter().map(Into::into).collect(),
             playlist_id: entity.playlist_id,
+            finish_reason: entity.finish_reason,
         }
     }
 }
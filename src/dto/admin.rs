@@ -7,11 +7,21 @@ use uuid::Uuid;
 use validator::{Validate, ValidationErrors};
 
 use crate::{
-    dao::models::{GameListItemEntity, PlaylistEntity},
+    config::{
+        AppConfig, BonusScoring, BuzzerPatternPreset, ColorAssignment, CountdownExpiry,
+        DEFAULT_COLOR, LeaderboardKey, NonAnsweringPattern, TeamNameCharset, TeamNames,
+    },
+    dao::models::{
+        FinishReasonEntity, GameEntity, GameListItemEntity, PlaylistAnalyticsEntity,
+        PlaylistEntity, SongAnalyticsEntity,
+    },
     dto::{
+        common::TeamColorDto,
         format_system_time,
-        game::{SongSummary, TeamBriefSummary, TeamInput, TeamSummary},
+        game::{RankedTeamSummary, SongSummary, TeamBriefSummary, TeamInput},
+        ws::BuzzerPattern,
     },
+    state::game::random_shuffle_seed,
 };
 
 /// Minimal projection of a game when listed for administrators.
@@ -29,6 +39,27 @@ pub struct GameListItem {
     pub teams: Vec<TeamBriefSummary>,
     /// Minimal playlist information.
     pub playlist: PlaylistListItem,
+    /// Why the game reached its final scoreboard, `null` while it has not.
+    pub finish_reason: Option<GameFinishReason>,
+}
+
+/// Why a game reached its final scoreboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GameFinishReason {
+    /// Every song of the playlist was played.
+    PlaylistCompleted,
+    /// The game master stopped the game early.
+    ManualStop,
+}
+
+impl From<FinishReasonEntity> for GameFinishReason {
+    fn from(value: FinishReasonEntity) -> Self {
+        match value {
+            FinishReasonEntity::PlaylistCompleted => Self::PlaylistCompleted,
+            FinishReasonEntity::ManualStop => Self::ManualStop,
+        }
+    }
 }
 
 /// Minimal projection of a playlist available for game creation.
@@ -40,8 +71,60 @@ pub struct PlaylistListItem {
     pub name: String,
 }
 
+/// Per-song analytics accumulated across sessions for a playlist.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlaylistAnalyticsResponse {
+    /// Identifier of the playlist.
+    pub playlist_id: Uuid,
+    /// Analytics for each song resolved at least once.
+    pub songs: Vec<SongAnalytics>,
+}
+
+/// Aggregated metrics describing how a song performed across sessions.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SongAnalytics {
+    /// Identifier of the song inside the playlist.
+    pub song_id: u32,
+    /// Number of times the song has been revealed.
+    pub plays: u32,
+    /// Number of buzzes received while the song was playing.
+    pub buzzes: u32,
+    /// Average playing time (milliseconds) before a buzz, when at least one buzz occurred.
+    pub average_time_to_buzz_ms: Option<u64>,
+    /// Number of answers validated as correct.
+    pub correct_count: u32,
+    /// Number of answers validated as wrong.
+    pub wrong_count: u32,
+}
+
+impl From<SongAnalyticsEntity> for SongAnalytics {
+    fn from(value: SongAnalyticsEntity) -> Self {
+        Self {
+            song_id: value.song_id,
+            plays: value.plays,
+            buzzes: value.buzzes,
+            average_time_to_buzz_ms: (value.buzzes > 0)
+                .then(|| value.total_time_to_buzz_ms / u64::from(value.buzzes)),
+            correct_count: value.correct_count,
+            wrong_count: value.wrong_count,
+        }
+    }
+}
+
+impl From<PlaylistAnalyticsEntity> for PlaylistAnalyticsResponse {
+    fn from(value: PlaylistAnalyticsEntity) -> Self {
+        let mut songs: Vec<SongAnalytics> = value.songs.into_iter().map(Into::into).collect();
+        songs.sort_by_key(|song| song.song_id);
+        Self {
+            playlist_id: value.playlist_id,
+            songs,
+        }
+    }
+}
+
 /// Payload describing how to spin up a game from an existing playlist definition.
 #[derive(Debug, Deserialize, ToSchema, Validate)]
+#[serde(deny_unknown_fields)]
 pub struct CreateGameRequest {
     /// Display name for the new game.
     pub name: String,
@@ -50,6 +133,20 @@ pub struct CreateGameRequest {
     pub teams: Vec<TeamInput>,
     /// ID of the playlist to use for this game.
     pub playlist_id: Uuid,
+    /// Block reveal and next song until the required fields of the current song are found.
+    #[serde(default)]
+    pub enforce_required_fields: bool,
+    /// Only play the songs carrying at least one of these tags (all songs when empty).
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Request setting which fields of a playlist song are required for progression.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RequiredFieldsRequest {
+    /// Keys of the point or bonus fields to flag as required; every other field is unflagged.
+    pub fields: Vec<String>,
 }
 
 /// Query parameters for game creation.
@@ -59,6 +156,16 @@ pub struct CreateGameQuery {
     /// Whether to shuffle the playlist order.
     #[serde(default)]
     pub shuffle: bool,
+    /// Seed making the shuffle reproducible; implies `shuffle`.
+    #[serde(default)]
+    pub shuffle_seed: Option<u64>,
+}
+
+impl CreateGameQuery {
+    /// Seed to shuffle the playlist with, if any; see [`resolve_shuffle_seed`].
+    pub fn resolved_shuffle_seed(&self) -> Option<u64> {
+        resolve_shuffle_seed(self.shuffle, self.shuffle_seed)
+    }
 }
 
 /// Query parameters for loading an existing game.
@@ -68,6 +175,57 @@ pub struct LoadGameQuery {
     /// Whether to shuffle the playlist order.
     #[serde(default)]
     pub shuffle: bool,
+    /// Seed making the shuffle reproducible; implies `shuffle`.
+    #[serde(default)]
+    pub shuffle_seed: Option<u64>,
+}
+
+impl LoadGameQuery {
+    /// Seed to shuffle the playlist with, if any; see [`resolve_shuffle_seed`].
+    pub fn resolved_shuffle_seed(&self) -> Option<u64> {
+        resolve_shuffle_seed(self.shuffle, self.shuffle_seed)
+    }
+}
+
+/// The requested seed when one is given, a random one when only `shuffle` is set, and `None`
+/// when the playlist order must be kept.
+fn resolve_shuffle_seed(shuffle: bool, seed: Option<u64>) -> Option<u64> {
+    seed.or_else(|| shuffle.then(random_shuffle_seed))
+}
+
+/// Query parameters for importing an exported game.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportGameQuery {
+    /// Keep the identifiers of the export instead of allocating fresh ones.
+    #[serde(default)]
+    pub preserve_ids: bool,
+}
+
+/// Query parameters for kicking a buzzer.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KickBuzzerQuery {
+    /// Also remove the buzzer from its team in the active game.
+    #[serde(default)]
+    pub unpair: bool,
+}
+
+/// Query parameters for duplicating a stored game.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DuplicateGameQuery {
+    /// Store a copy of the playlist for the new game instead of sharing the original one.
+    #[serde(default)]
+    pub copy_playlist: bool,
+}
+
+/// Query parameters for listing the stored games.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListGamesQuery {
+    /// Only list the games that finished for this reason.
+    pub finished_reason: Option<GameFinishReason>,
 }
 
 /// Rejects any query parameters by failing deserialization on unknown fields.
@@ -90,6 +248,42 @@ pub struct LoadGameQuery {
 #[serde(deny_unknown_fields)]
 pub struct NoQuery {}
 
+/// Buzzer pattern presets that can be previewed through the admin API.
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternPresetName {
+    /// Pattern used while waiting for pairing.
+    WaitingForPairing,
+    /// Pattern displayed while a team is standing by.
+    Standby,
+    /// Pattern indicating a team is allowed to answer.
+    Playing,
+    /// Pattern used for the team currently answering.
+    Answering,
+    /// Pattern for teams temporarily waiting.
+    Waiting,
+    /// Pattern flashed on a team whose answer was validated correct.
+    Correct,
+    /// Pattern flashed on a buzzer the GM asked to identify.
+    Identify,
+}
+
+/// Query parameters for previewing a buzzer pattern.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PatternPreviewQuery {
+    /// Preset to resolve.
+    pub preset: PatternPresetName,
+    /// Hue component (degrees) of the team color.
+    pub h: Option<f32>,
+    /// Saturation component (0.0 to 1.0) of the team color.
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub s: Option<f32>,
+    /// Value (brightness) component (0.0 to 1.0) of the team color.
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub v: Option<f32>,
+}
+
 /// Classifies the type of field discovered during gameplay.
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -102,6 +296,7 @@ pub enum FieldKind {
 
 /// Request to mark a point or bonus field as revealed.
 #[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct MarkFieldRequest {
     /// ID of the song containing the field.
     pub song_id: u32,
@@ -109,6 +304,15 @@ pub struct MarkFieldRequest {
     pub field_key: String,
     /// Type of field being marked.
     pub kind: FieldKind,
+    /// Award the field's points to the team whose buzz paused the song.
+    ///
+    /// A field already scored for the current song awards nothing, and neither does marking a bonus
+    /// field when `bonus_scoring` is `separate`.
+    #[serde(default)]
+    pub auto_score: bool,
+    /// Award the points even when they exceed the configured `max_score_delta`.
+    #[serde(default)]
+    pub force: bool,
 }
 
 /// Response summarising the fields uncovered for the current song.
@@ -136,16 +340,189 @@ pub enum AnswerValidation {
 
 /// Request to validate the current answer submission using a tri-state result.
 #[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct AnswerValidationRequest {
     /// Validation result for the answer.
     pub valid: AnswerValidation,
+    /// Team receiving the points of a correct answer. Defaults to the team whose buzzer
+    /// triggered the pause.
+    #[serde(default)]
+    pub team_id: Option<Uuid>,
+    /// Award the points of the fields found for the current song to the team when the answer is
+    /// correct. Each field is awarded at most once per song; scoring is left to the GM otherwise.
+    #[serde(default)]
+    pub auto_score: bool,
+    /// Award the points even when they exceed the configured `max_score_delta`.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Request to award bonus fields of the current song to a team, when `bonus_scoring` is
+/// `separate`.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BonusAwardRequest {
+    /// Team receiving the bonus points.
+    pub team_id: Uuid,
+    /// Keys of the bonus fields awarded.
+    pub field_keys: Vec<String>,
+    /// Award the points even when they exceed the configured `max_score_delta`.
+    #[serde(default)]
+    pub force: bool,
 }
 
 /// Request to adjust a team's score by a delta.
 #[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct ScoreAdjustmentRequest {
     /// Points to add (positive) or subtract (negative).
     pub delta: i32,
+    /// Apply the delta even when it exceeds the configured `max_score_delta`.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Request to rename a game and/or its playlist; at least one name must be given.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RenameGameRequest {
+    /// New display name of the game; surrounding whitespace is trimmed.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// New name of the game's playlist, shared by every game playing it; surrounding whitespace
+    /// is trimmed.
+    #[serde(default)]
+    pub playlist_name: Option<String>,
+}
+
+/// Request to undo the most recent score adjustment of a team.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScoreUndoRequest {
+    /// Team whose last adjustment is reverted.
+    pub team_id: Uuid,
+}
+
+/// Request to show or hide team scores from public clients.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScoresVisibilityRequest {
+    /// Whether public clients receive team scores.
+    pub visible: bool,
+}
+
+/// Request to force the degraded flag for an operational drill.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DegradedOverrideRequest {
+    /// Whether the backend should report degraded mode.
+    pub degraded: bool,
+}
+
+/// Query parameters for importing a playlist from CSV.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CsvImportQuery {
+    /// Display name of the created playlist.
+    pub name: String,
+}
+
+/// Invalid row reported when importing a playlist from CSV.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CsvRowError {
+    /// Line (1-based) on which the offending record starts.
+    pub row: usize,
+    /// Why the row was rejected.
+    pub message: String,
+}
+
+/// Body of a `400 Bad Request` answered to an invalid CSV import.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CsvImportErrorResponse {
+    /// Summary of the failure.
+    pub message: String,
+    /// Every invalid row.
+    pub errors: Vec<CsvRowError>,
+}
+
+/// Outcome of a manual storage flush.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FlushResponse {
+    /// Pending writes successfully persisted.
+    pub flushed: usize,
+    /// Pending writes that could not be persisted and remain pending.
+    pub failed: usize,
+}
+
+/// Request to reorder the teams of the active game.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReorderTeamsRequest {
+    /// Every team id of the active game, in the desired display order.
+    pub team_ids: Vec<Uuid>,
+}
+
+/// Request to set the default song order of a stored playlist.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReorderSongsRequest {
+    /// Every song id of the playlist, in the order new games should play them.
+    pub song_ids: Vec<u32>,
+}
+
+/// Team a buzzer is paired with in the active game.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BuzzerAssignmentResponse {
+    /// Team the buzzer is paired with.
+    pub team: TeamBriefSummary,
+    /// Whether the buzzer currently holds a WebSocket connection.
+    pub connected: bool,
+}
+
+/// Connected buzzer with its pairing status.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectedBuzzer {
+    /// Buzzer identifier.
+    pub id: String,
+    /// Team of the active game the buzzer is paired with, `null` when unpaired or without game.
+    pub team: Option<TeamBriefSummary>,
+    /// Last pattern sent to the buzzer, `null` when none was recorded.
+    pub last_pattern: Option<BuzzerPattern>,
+}
+
+/// Score change of a team since the current song started.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoundDelta {
+    /// ID of the team.
+    pub team_id: Uuid,
+    /// Points gained (positive) or lost (negative) during the current song.
+    pub delta_this_song: i32,
+}
+
+/// Answer-sheet entry for one field of the current song.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SongFieldStatus {
+    /// Name of the field (e.g. "Artist").
+    pub key: String,
+    /// Expected answer for the field.
+    pub value: String,
+    /// Points awarded when the field is found.
+    pub points: u8,
+    /// Whether the field must be found before the game can progress past the song.
+    pub required: bool,
+    /// Whether the field has been found during the current song.
+    pub found: bool,
+}
+
+/// GM answer sheet of the current song, combining its fields with their found state.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CurrentSongFieldsResponse {
+    /// Playlist identifier of the current song.
+    pub song_id: u32,
+    /// Point fields of the song, in playlist order.
+    pub point_fields: Vec<SongFieldStatus>,
+    /// Bonus fields of the song, in playlist order.
+    pub bonus_fields: Vec<SongFieldStatus>,
 }
 
 /// Generic action acknowledgement used by admin endpoints.
@@ -175,6 +552,17 @@ impl Validate for CreateTeamRequest {
     }
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+/// Request payload to create several teams at once during the prep phase.
+#[serde(transparent)]
+pub struct CreateTeamsRequest(pub Vec<TeamInput>);
+
+impl Validate for CreateTeamsRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.0.iter().try_for_each(Validate::validate)
+    }
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 /// Request payload to update an existing team in the active game.
 #[serde(transparent)]
@@ -188,16 +576,46 @@ impl Validate for UpdateTeamRequest {
 
 /// Request payload to start a buzzer pairing session.
 #[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct StartPairingRequest {
     /// ID of the first team to pair their buzzer.
     pub first_team_id: Uuid,
 }
 
+/// Request to pair a buzzer with the team currently pairing on behalf of the GM.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PairingAssignRequest {
+    /// ID of the buzzer to pair.
+    pub buzzer_id: String,
+    /// Confirm taking the buzzer away from the team it is currently paired with.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Request to buzz on behalf of a team, e.g. to stand in for a broken buzzer.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ManualBuzzRequest {
+    /// ID of the team buzzing; it must have a paired buzzer.
+    pub team_id: Uuid,
+}
+
 /// Response emitted when a game starts, including the initial song details.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct StartGameResponse {
     /// Summary of the first song in the game.
     pub song: SongSummary,
+    /// Seed the playlist was shuffled with, to replay the same order later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shuffle_seed: Option<u64>,
+}
+
+/// Response returned after going back to the previous song.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PreviousSongResponse {
+    /// Summary of the song that is current again.
+    pub song: SongSummary,
 }
 
 /// Response describing the state of the playlist after moving to the next song.
@@ -213,8 +631,8 @@ pub struct NextSongResponse {
 /// Response returned when a game is stopped, gathering final team scores.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct StopGameResponse {
-    /// Final scores and details for all teams.
-    pub teams: Vec<TeamSummary>,
+    /// Final standings of all teams, ranked by score.
+    pub teams: Vec<RankedTeamSummary>,
 }
 
 /// Errors that can occur when converting storage entities into API DTOs.
@@ -258,7 +676,251 @@ impl TryFrom<(GameListItemEntity, PlaylistEntity)> for GameListItem {
                     id: playlist.id,
                     name: playlist.name,
                 },
+                finish_reason: game_list_item.finish_reason.map(Into::into),
             })
         }
     }
 }
+
+/// Standing of a team across every finished stored game.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeaderboardEntry {
+    /// Team name, as spelled in the most recently updated game.
+    pub name: String,
+    /// Sum of the final scores of the team.
+    pub total_points: i64,
+    /// Number of finished games the team took part in.
+    pub games_played: u32,
+}
+
+/// Version of the [`ExportedGame`] document layout, bumped on incompatible changes.
+pub const GAME_EXPORT_VERSION: u32 = 1;
+
+/// Self-contained archive of a game: its teams, scores and progress plus the playlist it plays.
+///
+/// The game and playlist are kept in their storage layout so the document can be imported back
+/// as-is.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExportedGame {
+    /// Layout version of the document, see [`GAME_EXPORT_VERSION`].
+    pub version: u32,
+    /// Game document, including its teams.
+    #[schema(value_type = Object)]
+    pub game: GameEntity,
+    /// Playlist referenced by the game.
+    #[schema(value_type = Object)]
+    pub playlist: PlaylistEntity,
+}
+
+impl From<(GameEntity, PlaylistEntity)> for ExportedGame {
+    fn from((game, playlist): (GameEntity, PlaylistEntity)) -> Self {
+        Self {
+            version: GAME_EXPORT_VERSION,
+            game,
+            playlist,
+        }
+    }
+}
+
+/// Effective runtime configuration loaded by the server.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigResponse {
+    /// Colors set used for automatic team color assignment, in order.
+    pub colors: Vec<TeamColorDto>,
+    /// Buzzer pattern resolved for each preset.
+    pub patterns: ConfigPatterns,
+    /// Whether per-song analytics are persisted when a song is resolved.
+    pub song_analytics: bool,
+    /// Minimum OKLab distance between automatically assigned team colors, if enforced.
+    pub min_color_distance: Option<f32>,
+    /// Strategy picking the color of teams created without one.
+    pub color_assignment: ColorAssignment,
+    /// Names given to teams created without one.
+    pub default_team_names: DefaultTeamNames,
+    /// Whether a manual pause freezes the game timers.
+    pub pause_freezes_timers: bool,
+    /// Whether each team may only buzz once per song.
+    pub one_buzz_per_song: bool,
+    /// Minimum delay (milliseconds) between two song advances.
+    pub min_song_gap_ms: u64,
+    /// Maximum size (bytes) of a message received from a buzzer WebSocket.
+    pub ws_max_message_bytes: usize,
+    /// Answer countdown started when a team buzzes, when enabled.
+    pub answer_countdown: Option<AnswerCountdownSettings>,
+    /// Whether pairing a buzzer already paired with another team requires confirmation.
+    pub confirm_pairing_reassignment: bool,
+    /// Time (milliseconds) a disconnected buzzer still counts as present for its team.
+    pub buzzer_reconnect_grace_ms: u64,
+    /// Battery level (percent) below which a buzzer triggers a warning, when enabled.
+    pub low_battery_threshold: Option<u8>,
+    /// Period (milliseconds) of the background autosave, when enabled.
+    pub autosave_interval_ms: Option<u64>,
+    /// Largest score change applied without `force`, when capped.
+    pub max_score_delta: Option<u32>,
+    /// Minimum delay between two saves of the same game or team document.
+    pub persist_cooldown_ms: u64,
+    /// Whether identified buzzers receive a `welcome` message before their first pattern.
+    pub buzzer_welcome: bool,
+    /// Whether the game stays loaded after it ends, ready to be played again.
+    pub keep_game_after_end: bool,
+    /// Maximum number of simultaneous admin SSE connections.
+    pub admin_max_subscribers: usize,
+    /// Period (seconds) of the keepalive comments sent on SSE streams.
+    pub sse_keepalive_secs: u64,
+    /// Duration (milliseconds) of the `correct` pattern after a correct answer, when enabled.
+    pub applause_ms: Option<u64>,
+    /// Whether debug logs may include full playlists, answers included.
+    pub log_answers: bool,
+    /// Debounce delay (milliseconds) of the `spectators` broadcast, when enabled.
+    pub spectators_debounce_ms: Option<u64>,
+    /// Pattern shown by the buzzers of the teams not answering while another team answers.
+    pub non_answering_pattern: NonAnsweringPattern,
+    /// Maximum length (characters) of a team name.
+    pub max_team_name_len: usize,
+    /// Characters accepted in team names.
+    pub team_name_charset: TeamNameCharset,
+    /// How teams are matched across games in the leaderboard.
+    pub leaderboard_key: LeaderboardKey,
+    /// Whether an accepted buzz is announced by a `buzz.cue` event.
+    pub buzz_cue: bool,
+    /// When the bonus fields found for a song are scored.
+    pub bonus_scoring: BonusScoring,
+    /// Whether reads of the active game are served from memory while the storage is unavailable.
+    pub degraded_read_fallback: bool,
+    /// Maximum number of buzzers whose last pattern is remembered for reconnection.
+    pub buzzer_pattern_cache_size: usize,
+    /// Maximum number of game and team writes issued to the store at the same time.
+    pub storage_max_concurrent_writes: usize,
+    /// Time (seconds) a buzzer has to identify itself after opening its WebSocket.
+    pub buzzer_ident_timeout_secs: u64,
+    /// Period (seconds) of the pings sent to buzzers, when enabled.
+    pub buzzer_ping_interval_secs: Option<u64>,
+    /// Time (milliseconds) a phase transition may take before it is aborted, when limited.
+    pub transition_timeout_ms: Option<u64>,
+    /// SSE event recorder settings, when recording is enabled.
+    pub event_recording: Option<EventRecordingSettings>,
+}
+
+impl From<&AppConfig> for ConfigResponse {
+    fn from(config: &AppConfig) -> Self {
+        let pattern = |preset| config.buzzer_pattern(preset);
+        Self {
+            colors: config.colors().iter().cloned().map(Into::into).collect(),
+            patterns: ConfigPatterns {
+                waiting_for_pairing: pattern(BuzzerPatternPreset::WaitingForPairing),
+                standby: pattern(BuzzerPatternPreset::Standby(DEFAULT_COLOR)),
+                playing: pattern(BuzzerPatternPreset::Playing(DEFAULT_COLOR)),
+                answering: pattern(BuzzerPatternPreset::Answering(DEFAULT_COLOR)),
+                waiting: pattern(BuzzerPatternPreset::Waiting),
+                correct: pattern(BuzzerPatternPreset::Correct(DEFAULT_COLOR)),
+                identify: pattern(BuzzerPatternPreset::Identify),
+            },
+            song_analytics: config.song_analytics_enabled(),
+            min_color_distance: config.min_color_distance(),
+            color_assignment: config.color_assignment(),
+            default_team_names: match config.default_team_names() {
+                TeamNames::Template(template) => DefaultTeamNames::Template(template.clone()),
+                TeamNames::List(names) => DefaultTeamNames::List(names.clone()),
+            },
+            pause_freezes_timers: config.pause_freezes_timers(),
+            one_buzz_per_song: config.one_buzz_per_song(),
+            min_song_gap_ms: config.min_song_gap().as_millis() as u64,
+            ws_max_message_bytes: config.ws_max_message_bytes(),
+            answer_countdown: config
+                .answer_countdown()
+                .map(|countdown| AnswerCountdownSettings {
+                    duration_ms: countdown.duration_ms,
+                    on_expiry: countdown.on_expiry,
+                }),
+            confirm_pairing_reassignment: config.confirm_pairing_reassignment(),
+            buzzer_reconnect_grace_ms: config.buzzer_reconnect_grace().as_millis() as u64,
+            low_battery_threshold: config.low_battery_threshold(),
+            autosave_interval_ms: config
+                .autosave_interval()
+                .map(|period| period.as_millis() as u64),
+            max_score_delta: config.max_score_delta(),
+            persist_cooldown_ms: config.persist_cooldown().as_millis() as u64,
+            buzzer_welcome: config.buzzer_welcome(),
+            keep_game_after_end: config.keep_game_after_end(),
+            admin_max_subscribers: config.admin_max_subscribers(),
+            sse_keepalive_secs: config.sse_keepalive().as_secs(),
+            applause_ms: config
+                .applause_duration()
+                .map(|duration| duration.as_millis() as u64),
+            log_answers: config.log_answers(),
+            spectators_debounce_ms: config
+                .spectators_debounce()
+                .map(|delay| delay.as_millis() as u64),
+            non_answering_pattern: config.non_answering_pattern(),
+            max_team_name_len: config.max_team_name_len(),
+            team_name_charset: config.team_name_charset(),
+            leaderboard_key: config.leaderboard_key(),
+            buzz_cue: config.buzz_cue(),
+            bonus_scoring: config.bonus_scoring(),
+            degraded_read_fallback: config.degraded_read_fallback(),
+            buzzer_pattern_cache_size: config.buzzer_pattern_cache_size(),
+            storage_max_concurrent_writes: config.storage_max_concurrent_writes(),
+            buzzer_ident_timeout_secs: config.buzzer_ident_timeout().as_secs(),
+            buzzer_ping_interval_secs: config.buzzer_ping_interval().map(|period| period.as_secs()),
+            transition_timeout_ms: config
+                .transition_timeout()
+                .map(|limit| limit.as_millis() as u64),
+            event_recording: config
+                .event_recording()
+                .map(|recording| EventRecordingSettings {
+                    path: recording.path.display().to_string(),
+                    max_bytes: recording.max_bytes,
+                }),
+        }
+    }
+}
+
+/// Buzzer patterns resolved from the configuration.
+///
+/// Presets shown in the team color are resolved with white unless the configuration sets a
+/// static color for them.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigPatterns {
+    /// Pattern shown while waiting for a buzzer to be paired.
+    pub waiting_for_pairing: BuzzerPattern,
+    /// Pattern shown while a team is standing by.
+    pub standby: BuzzerPattern,
+    /// Pattern shown while teams are allowed to buzz.
+    pub playing: BuzzerPattern,
+    /// Pattern shown on the buzzer of the team answering.
+    pub answering: BuzzerPattern,
+    /// Pattern shown on the other buzzers while a team answers.
+    pub waiting: BuzzerPattern,
+    /// Pattern flashed on a team whose answer was validated correct.
+    pub correct: BuzzerPattern,
+    /// Pattern flashed on a buzzer the GM asked to identify.
+    pub identify: BuzzerPattern,
+}
+
+/// Source of names for teams created without an explicit name.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum DefaultTeamNames {
+    /// Template where `{n}` is replaced by the team position.
+    Template(String),
+    /// Fixed names cycled in order.
+    List(Vec<String>),
+}
+
+/// Settings of the answer countdown.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AnswerCountdownSettings {
+    /// Time (milliseconds) the buzzing team has to answer.
+    pub duration_ms: u64,
+    /// Action taken when the countdown expires.
+    pub on_expiry: CountdownExpiry,
+}
+
+/// Settings of the SSE event recorder.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventRecordingSettings {
+    /// NDJSON file receiving the recorded events.
+    pub path: String,
+    /// Size (bytes) past which the file is rotated.
+    pub max_bytes: u64,
+}
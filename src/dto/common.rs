@@ -4,7 +4,11 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
-    dto::{game::TeamSummary, phase::VisibleGamePhase},
+    dto::{
+        game::RankedTeamSummary,
+        phase::{AdminAction, VisibleGamePhase},
+        validation::{validate_hue, wrap_hue},
+    },
     state::game::{PointField, Song, TeamColor},
 };
 
@@ -17,6 +21,8 @@ pub struct PointFieldSnapshot {
     pub value: String,
     /// Points awarded for finding this field.
     pub points: u8,
+    /// Whether the field must be found before the game can progress past the song.
+    pub required: bool,
 }
 
 impl From<PointField> for PointFieldSnapshot {
@@ -25,6 +31,7 @@ impl From<PointField> for PointFieldSnapshot {
             key: field.key,
             value: field.value,
             points: field.points,
+            required: field.required,
         }
     }
 }
@@ -44,6 +51,8 @@ pub struct SongSnapshot {
     pub point_fields: Vec<PointFieldSnapshot>,
     /// Optional bonus fields for this song.
     pub bonus_fields: Vec<PointFieldSnapshot>,
+    /// Categories the song belongs to.
+    pub tags: Vec<String>,
 }
 
 impl SongSnapshot {
@@ -66,6 +75,7 @@ impl SongSnapshot {
                 .into_iter()
                 .map(PointFieldSnapshot::from)
                 .collect(),
+            tags: song.tags.clone(),
         }
     }
 }
@@ -85,24 +95,53 @@ pub struct GamePhaseSnapshot {
     /// Present during pause phase for buzz-induced pauses to expose the buzzer identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub paused_buzzer: Option<String>,
+    /// Present during buzz-induced pauses: buzzers of the other teams that buzzed meanwhile, in
+    /// the order they pressed, waiting for their turn.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buzz_queue: Option<Vec<String>>,
     /// Present during playing/reveal phases to expose the current song.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub song: Option<SongSnapshot>,
-    /// Present during scores phase to display the final scores.
+    /// Present during scores phase to display the final standings, ranked by score.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scoreboard: Option<Vec<TeamSummary>>,
+    pub scoreboard: Option<Vec<RankedTeamSummary>>,
     /// Present during playing/reveal phases to expose point fields already found.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub found_point_fields: Option<Vec<String>>,
     /// Present during playing/reveal phases to expose bonus fields already found.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub found_bonus_fields: Option<Vec<String>>,
+    /// Present during playing/reveal phases when the game enforces required fields, listing the
+    /// required fields still to be found before progressing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outstanding_required_fields: Option<Vec<String>>,
+    /// Admin controls accepted in this phase. Only sent on the admin stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<Vec<AdminAction>>,
+}
+
+impl GamePhaseSnapshot {
+    /// Drop the scoreboard scores, as exposed to public clients while scores are hidden.
+    pub fn without_scores(mut self) -> Self {
+        // Sorting by name keeps the standings from leaking through the scoreboard order.
+        self.scoreboard = self.scoreboard.map(|teams| {
+            let mut teams: Vec<_> = teams
+                .into_iter()
+                .map(RankedTeamSummary::without_score)
+                .collect();
+            teams.sort_by(|a, b| a.team.name.cmp(&b.team.name));
+            teams
+        });
+        self
+    }
 }
 
 /// HSV representation shared by DTOs (REST, SSE, WS).
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(deny_unknown_fields)]
 pub struct TeamColorDto {
-    /// Hue component (degrees).
+    /// Hue component (degrees), wrapped into `[0, 360)` when applied.
+    #[validate(custom(function = "validate_hue"))]
     pub h: f32,
     /// Saturation component (0.0 to 1.0).
     #[validate(range(min = 0.0, max = 1.0))]
@@ -112,6 +151,16 @@ pub struct TeamColorDto {
     pub v: f32,
 }
 
+impl TeamColorDto {
+    /// Same color with its hue wrapped into `[0, 360)` degrees.
+    pub fn normalized(self) -> Self {
+        Self {
+            h: wrap_hue(self.h),
+            ..self
+        }
+    }
+}
+
 impl From<TeamColor> for TeamColorDto {
     fn from(color: TeamColor) -> Self {
         Self {
@@ -5,15 +5,17 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::{Validate, ValidationErrors};
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::{
+    dao::models::{PointFieldEntity, SongEntity},
     dto::{common::TeamColorDto, format_system_time, validation::validate_buzzer_id},
     state::game::{GameSession, Playlist, PointField, Song, Team},
 };
 
 /// Payload used to bootstrap a brand-new game instance.
 #[derive(Debug, Deserialize, ToSchema, Validate)]
+#[serde(deny_unknown_fields)]
 pub struct CreateGameWithPlaylistRequest {
     /// Display name for the new game.
     pub name: String,
@@ -23,10 +25,17 @@ pub struct CreateGameWithPlaylistRequest {
     /// Playlist definition for the game.
     #[validate(nested)]
     pub playlist: PlaylistInput,
+    /// Block reveal and next song until the required fields of the current song are found.
+    #[serde(default)]
+    pub enforce_required_fields: bool,
+    /// Only play the songs carrying at least one of these tags (all songs when empty).
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Incoming team definition for the game bootstrap.
 #[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct TeamInput {
     /// Display name for the team.
     pub name: String,
@@ -36,6 +45,10 @@ pub struct TeamInput {
     #[serde(default)]
     #[schema(value_type = Option<String>)]
     pub buzzer_id: Option<Option<String>>,
+    /// Full list of buzzers for teams using several of them, replacing the current ones.
+    /// Cannot be combined with `buzzer_id`.
+    #[serde(default)]
+    pub buzzer_ids: Option<Vec<String>>,
     /// Initial score for the team (defaults to 0 if omitted).
     #[serde(default)]
     #[schema(value_type = i32)]
@@ -52,17 +65,39 @@ impl Validate for TeamInput {
         let mut errors = ValidationErrors::new();
 
         // Validate buzzer_id if present
-        if let Some(Some(ref id)) = self.buzzer_id {
-            if let Err(e) = validate_buzzer_id(id) {
-                errors.add("buzzer_id", e);
+        if let Some(Some(ref id)) = self.buzzer_id
+            && let Err(e) = validate_buzzer_id(id)
+        {
+            errors.add("buzzer_id", e);
+        }
+
+        if let Some(ref ids) = self.buzzer_ids {
+            if self.buzzer_id.is_some() {
+                errors.add(
+                    "buzzer_ids",
+                    ValidationError::new("buzzer_ids_conflict")
+                        .with_message("cannot be combined with `buzzer_id`".into()),
+                );
+            }
+            let mut seen = HashSet::new();
+            for id in ids {
+                if let Err(e) = validate_buzzer_id(id) {
+                    errors.add("buzzer_ids", e);
+                } else if !seen.insert(id) {
+                    errors.add(
+                        "buzzer_ids",
+                        ValidationError::new("duplicate_buzzer_id")
+                            .with_message(format!("duplicate buzzer id `{id}`").into()),
+                    );
+                }
             }
         }
 
         // Validate color if present
-        if let Some(ref color) = self.color {
-            if let Err(color_errors) = color.validate() {
-                errors.merge_self("color", Err(color_errors));
-            }
+        if let Some(ref color) = self.color
+            && let Err(color_errors) = color.validate()
+        {
+            errors.merge_self("color", Err(color_errors));
         }
 
         if errors.is_empty() {
@@ -73,8 +108,20 @@ impl Validate for TeamInput {
     }
 }
 
+impl TeamInput {
+    /// Buzzers the team should end up with, or `None` to leave them unchanged.
+    pub fn buzzer_update(&self) -> Option<Vec<String>> {
+        match (&self.buzzer_ids, &self.buzzer_id) {
+            (Some(ids), _) => Some(ids.clone()),
+            (None, Some(id)) => Some(id.iter().cloned().collect()),
+            (None, None) => None,
+        }
+    }
+}
+
 /// Playlist metadata and songs supplied when bootstrapping a game.
 #[derive(Debug, Deserialize, ToSchema, Validate)]
+#[serde(deny_unknown_fields)]
 pub struct PlaylistInput {
     /// Display name for the playlist.
     pub name: String,
@@ -85,6 +132,7 @@ pub struct PlaylistInput {
 
 /// Song details required to populate a playlist.
 #[derive(Debug, Deserialize, ToSchema, Validate)]
+#[serde(deny_unknown_fields)]
 pub struct SongInput {
     /// Start time in milliseconds for the song playback.
     pub starts_at_ms: usize,
@@ -98,10 +146,14 @@ pub struct SongInput {
     /// Bonus fields (optional extra information) for this song.
     #[serde(default)]
     pub bonus_fields: Vec<PointFieldInput>,
+    /// Categories the song belongs to (e.g. "90s", "Movies").
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Point field details required for a song.
 #[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct PointFieldInput {
     /// Unique key identifying this field.
     pub key: String,
@@ -109,6 +161,9 @@ pub struct PointFieldInput {
     pub value: String,
     /// Points awarded for finding this field.
     pub points: u8,
+    /// Whether the field must be found before the game can progress past the song.
+    #[serde(default)]
+    pub required: bool,
 }
 
 /// Summary returned once a game has been created or loaded.
@@ -128,6 +183,20 @@ pub struct GameSummary {
     pub playlist: PlaylistSummary,
     /// Index of the current song being played (if any).
     pub current_song_index: Option<usize>,
+    /// Whether reveal and next song are blocked until required fields are found.
+    pub enforce_required_fields: bool,
+}
+
+impl GameSummary {
+    /// Drop every team score, as exposed to public clients while scores are hidden.
+    pub fn without_scores(mut self) -> Self {
+        self.teams = self
+            .teams
+            .into_iter()
+            .map(TeamSummary::without_score)
+            .collect();
+        self
+    }
 }
 
 /// Public projection of a team exposed to REST/SSE clients.
@@ -135,16 +204,71 @@ pub struct GameSummary {
 pub struct TeamSummary {
     /// Unique identifier for the team.
     pub id: Uuid,
-    /// ID of the buzzer assigned to this team.
+    /// ID of the first buzzer assigned to this team, for clients unaware of `buzzer_ids`.
     pub buzzer_id: Option<String>,
+    /// IDs of every buzzer assigned to this team.
+    pub buzzer_ids: Vec<String>,
     /// Display name of the team.
     pub name: String,
-    /// Current score for the team.
-    pub score: i32,
+    /// Current score for the team, `null` while scores are hidden from public clients.
+    pub score: Option<i32>,
     /// HSV color assigned to the team.
     pub color: TeamColorDto,
 }
 
+impl TeamSummary {
+    /// Drop the score, as exposed to public clients while scores are hidden.
+    pub fn without_score(self) -> Self {
+        Self {
+            score: None,
+            ..self
+        }
+    }
+}
+
+/// Team standing on the final scoreboard.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RankedTeamSummary {
+    /// Team details and score.
+    #[serde(flatten)]
+    pub team: TeamSummary,
+    /// 1-based rank by descending score, shared by tied teams (`1, 1, 3`), `null` while scores
+    /// are hidden from public clients.
+    pub rank: Option<u32>,
+    /// Whether another team has the same score, meaning a playoff is needed to separate them.
+    pub tied: bool,
+}
+
+impl RankedTeamSummary {
+    /// Drop the score and the ranking, as exposed to public clients while scores are hidden.
+    pub fn without_score(self) -> Self {
+        Self {
+            team: self.team.without_score(),
+            rank: None,
+            tied: false,
+        }
+    }
+}
+
+/// Rank teams by descending score; teams with equal scores share a rank and are flagged as tied,
+/// keeping their roster order.
+pub fn rank_teams(mut teams: Vec<TeamSummary>) -> Vec<RankedTeamSummary> {
+    teams.sort_by_key(|team| std::cmp::Reverse(team.score));
+    let scores: Vec<Option<i32>> = teams.iter().map(|team| team.score).collect();
+    teams
+        .into_iter()
+        .map(|team| {
+            let first = scores.partition_point(|score| *score > team.score);
+            let tied = scores.iter().filter(|score| **score == team.score).count() > 1;
+            RankedTeamSummary {
+                team,
+                rank: Some(first as u32 + 1),
+                tied,
+            }
+        })
+        .collect()
+}
+
 /// Brief team information without score or color.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct TeamBriefSummary {
@@ -180,6 +304,8 @@ pub struct SongSummary {
     pub point_fields: Vec<PointFieldSummary>,
     /// Optional bonus fields for this song.
     pub bonus_fields: Vec<PointFieldSummary>,
+    /// Categories the song belongs to.
+    pub tags: Vec<String>,
 }
 
 /// Summary of a point or bonus field within a song.
@@ -191,27 +317,66 @@ pub struct PointFieldSummary {
     pub value: String,
     /// Points awarded for finding this field.
     pub points: u8,
+    /// Whether the field must be found before the game can progress past the song.
+    pub required: bool,
 }
 
 /// Errors that can occur when validating playlist song ordering.
 #[derive(Debug, Error)]
 pub enum PlaylistOrderError {
-    /// Song IDs in the order don't match the playlist songs.
-    #[error("playlist ids mismatch (missing in order: {missing:?}, extra in order: {extra:?})")]
+    /// The order references songs that are not in the playlist.
+    #[error("playlist order references unknown songs: {ids:?}")]
+    UnknownIds {
+        /// Song IDs present in order but not in playlist.
+        ids: Vec<u32>,
+    },
+    /// The order does not list every song of the playlist exactly once.
+    #[error("playlist order must list every song once: missing {missing:?}, extra {extra:?}")]
     MismatchedIds {
-        /// Song IDs present in playlist but missing from order.
+        /// Song IDs of the playlist absent from the order.
         missing: Vec<u32>,
-        /// Song IDs present in order but not in playlist.
+        /// Song IDs unknown to the playlist or listed more than once.
         extra: Vec<u32>,
     },
 }
 
+impl From<PlaylistOrderError> for crate::error::ServiceError {
+    fn from(err: PlaylistOrderError) -> crate::error::ServiceError {
+        crate::error::ServiceError::InvalidInput(err.to_string())
+    }
+}
+
+impl From<PointFieldEntity> for PointFieldInput {
+    fn from(field: PointFieldEntity) -> Self {
+        Self {
+            key: field.key,
+            value: field.value,
+            points: field.points,
+            required: field.required,
+        }
+    }
+}
+
+impl From<SongEntity> for SongInput {
+    fn from(song: SongEntity) -> Self {
+        Self {
+            starts_at_ms: song.starts_at_ms,
+            guess_duration_ms: song.guess_duration_ms,
+            url: song.url,
+            point_fields: song.point_fields.into_iter().map(Into::into).collect(),
+            bonus_fields: song.bonus_fields.into_iter().map(Into::into).collect(),
+            tags: song.tags,
+        }
+    }
+}
+
 impl From<PointField> for PointFieldSummary {
     fn from(field: PointField) -> Self {
         Self {
             key: field.key,
             value: field.value,
             points: field.points,
+            required: field.required,
         }
     }
 }
@@ -220,9 +385,10 @@ impl From<(Uuid, Team)> for TeamSummary {
     fn from((id, team): (Uuid, Team)) -> Self {
         Self {
             id,
-            buzzer_id: team.buzzer_id,
+            buzzer_id: team.buzzer_ids.first().cloned(),
+            buzzer_ids: team.buzzer_ids,
             name: team.name,
-            score: team.score,
+            score: Some(team.score),
             color: team.color.into(),
         }
     }
@@ -237,6 +403,7 @@ impl From<(u32, Song)> for SongSummary {
             url: song.url,
             point_fields: song.point_fields.into_iter().map(Into::into).collect(),
             bonus_fields: song.bonus_fields.into_iter().map(Into::into).collect(),
+            tags: song.tags,
         }
     }
 }
@@ -269,10 +436,13 @@ impl From<GameSession> for GameSummary {
             teams: session.teams.into_iter().map(Into::into).collect(),
             playlist: playlist_summary,
             current_song_index: session.current_song_index,
+            enforce_required_fields: session.enforce_required_fields,
         }
     }
 }
 
+/// Summarise the songs in play order. The order may cover only part of the playlist (e.g. for
+/// tag-filtered games), in which case only the ordered songs are listed.
 fn ordered_song_summaries(
     playlist_songs: IndexMap<u32, Song>,
     order: Vec<u32>,
@@ -280,34 +450,118 @@ fn ordered_song_summaries(
     let playlist_ids = playlist_songs.keys().cloned().collect::<HashSet<_>>();
     let order_ids = order.iter().copied().collect::<HashSet<_>>();
 
-    if playlist_ids != order_ids {
-        let mut missing = playlist_ids
-            .difference(&order_ids)
-            .copied()
-            .collect::<Vec<_>>();
-        let mut extra = order_ids
-            .difference(&playlist_ids)
-            .copied()
-            .collect::<Vec<_>>();
+    let mut unknown = order_ids
+        .difference(&playlist_ids)
+        .copied()
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        unknown.sort_unstable();
+        return Err(PlaylistOrderError::UnknownIds { ids: unknown });
+    }
 
-        missing.sort_unstable();
-        extra.sort_unstable();
+    Ok(order
+        .into_iter()
+        .map(|song_id| (song_id, playlist_songs[&song_id].clone()).into())
+        .collect())
+}
 
-        return Err(PlaylistOrderError::MismatchedIds { missing, extra });
+/// Check that `order` lists every song of the playlist exactly once, as a default play order
+/// must.
+pub fn check_complete_order(
+    playlist_songs: &IndexMap<u32, Song>,
+    order: &[u32],
+) -> Result<(), PlaylistOrderError> {
+    let mut seen = HashSet::with_capacity(order.len());
+    let mut extra: Vec<u32> = order
+        .iter()
+        .copied()
+        .filter(|song_id| !playlist_songs.contains_key(song_id) || !seen.insert(*song_id))
+        .collect();
+    let mut missing: Vec<u32> = playlist_songs
+        .keys()
+        .copied()
+        .filter(|song_id| !seen.contains(song_id))
+        .collect();
+    if missing.is_empty() && extra.is_empty() {
+        return Ok(());
     }
+    missing.sort_unstable();
+    extra.sort_unstable();
+    Err(PlaylistOrderError::MismatchedIds { missing, extra })
+}
 
-    order
-        .into_iter()
-        .map(|song_id| {
-            let Some(song_ref) = playlist_songs.get(&song_id) else {
-                // Safety: mismatch should have been caught above, but guard defensively.
-                return Err(PlaylistOrderError::MismatchedIds {
-                    missing: vec![song_id],
-                    extra: Vec::new(),
-                });
-            };
-
-            Ok((song_id, song_ref.clone()).into())
-        })
-        .collect::<Result<Vec<SongSummary>, _>>()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::admin::CreateTeamRequest;
+    use axum::{Json, response::IntoResponse};
+
+    fn team_with_score(name: &str, score: i32) -> TeamSummary {
+        TeamSummary {
+            id: Uuid::new_v4(),
+            buzzer_id: None,
+            buzzer_ids: Vec::new(),
+            name: name.into(),
+            score: Some(score),
+            color: TeamColorDto {
+                h: 0.0,
+                s: 1.0,
+                v: 1.0,
+            },
+        }
+    }
+
+    #[test]
+    fn tied_teams_share_their_rank() {
+        let ranked = rank_teams(vec![
+            team_with_score("Owls", 3),
+            team_with_score("Foxes", 7),
+            team_with_score("Bats", 3),
+            team_with_score("Cats", 1),
+        ]);
+
+        let standings: Vec<_> = ranked
+            .iter()
+            .map(|entry| (entry.team.name.as_str(), entry.rank, entry.tied))
+            .collect();
+        assert_eq!(
+            standings,
+            [
+                ("Foxes", Some(1), false),
+                ("Owls", Some(2), true),
+                ("Bats", Some(2), true),
+                ("Cats", Some(4), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_team_field_is_rejected_with_its_name() {
+        let body = br#"{"name":"Owls","buzzerId":"deadbeef0001"}"#;
+        let rejection = Json::<CreateTeamRequest>::from_bytes(body).unwrap_err();
+        assert!(rejection.body_text().contains("unknown field `buzzerId`"));
+        assert_eq!(
+            rejection.into_response().status(),
+            axum::http::StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[test]
+    fn unknown_nested_field_is_rejected() {
+        let body = r#"{
+            "name": "Game",
+            "teams": [],
+            "playlist": {
+                "name": "Playlist",
+                "songs": [{
+                    "starts_at_ms": 0,
+                    "guess_duration_ms": 1000,
+                    "url": "https://example.com/song.mp3",
+                    "point_fields": [{"key": "title", "value": "Song", "point": 1}]
+                }]
+            }
+        }"#;
+        let err = serde_json::from_str::<CreateGameWithPlaylistRequest>(body).unwrap_err();
+        assert!(err.to_string().contains("unknown field `point`"));
+    }
 }
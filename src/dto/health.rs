@@ -1,11 +1,18 @@
 use serde::Serialize;
 use utoipa::ToSchema;
 
-/// Simple health response returned by the `/healthcheck` route.
+/// Simple health response returned by the health routes.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct HealthResponse {
     /// Health status ("ok" or "degraded").
     pub status: String,
+    /// Why the service is not ready ("no backend", "health check failed", "degraded mode"),
+    /// `null` when it is.
+    pub detail: Option<String>,
+    /// Kind of the storage backend (`mongo`, `couch` or `memory`), `null` without a backend.
+    pub backend: Option<String>,
+    /// Round-trip time of the storage health check in milliseconds, `null` when degraded.
+    pub latency_ms: Option<f64>,
 }
 
 impl HealthResponse {
@@ -13,13 +20,24 @@ impl HealthResponse {
     pub fn ok() -> Self {
         Self {
             status: "ok".to_string(),
+            detail: None,
+            backend: None,
+            latency_ms: None,
         }
     }
 
-    /// Create a health response indicating the system is in degraded mode.
-    pub fn degraded() -> Self {
+    /// Create a health response indicating the system is in degraded mode, for `detail`.
+    pub fn degraded(detail: &str) -> Self {
         Self {
             status: "degraded".to_string(),
+            detail: Some(detail.to_string()),
+            backend: None,
+            latency_ms: None,
         }
     }
+
+    /// Whether the response reports an operational system.
+    pub fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
 }
@@ -1,7 +1,7 @@
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::state::state_machine::{GamePhase, GameRunningPhase, PrepStatus};
+use crate::state::state_machine::{GameEvent, GamePhase, GameRunningPhase, PrepStatus};
 
 /// Publicly visible game phase exposed to clients (REST/SSE).
 #[derive(Debug, Serialize, ToSchema, Clone, Copy)]
@@ -40,3 +40,61 @@ impl From<&GamePhase> for VisibleGamePhase {
         }
     }
 }
+
+/// Admin control currently accepted by the game, named after its `/admin` endpoint.
+#[derive(Debug, Serialize, ToSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminAction {
+    /// Create or load a game (`POST /admin/games`, `POST /admin/games/{id}/load`).
+    LoadGame,
+    /// Start pairing buzzers with teams.
+    StartPairing,
+    /// Abort the pairing workflow.
+    AbortPairing,
+    /// Start playing the first song.
+    Start,
+    /// Pause the current song.
+    Pause,
+    /// Resume playing after a pause.
+    Resume,
+    /// Reveal the current song.
+    Reveal,
+    /// Move on to the next song.
+    Next,
+    /// Skip the current song without revealing it.
+    Skip,
+    /// Go back to the previous song.
+    Previous,
+    /// Stop the game and show the final scores.
+    Stop,
+    /// Leave the final scores and return to idle.
+    End,
+}
+
+impl AdminAction {
+    /// Admin actions accepted from `phase`, derived from the state machine transitions.
+    pub fn available_in(phase: &GamePhase) -> Vec<Self> {
+        let mut actions: Vec<Self> = phase.available_events().iter().map(Self::from).collect();
+        actions.dedup();
+        actions
+    }
+}
+
+impl From<&GameEvent> for AdminAction {
+    fn from(event: &GameEvent) -> Self {
+        match event {
+            GameEvent::StartGame | GameEvent::ReloadFinished => AdminAction::LoadGame,
+            GameEvent::PairingStarted(_) => AdminAction::StartPairing,
+            GameEvent::PairingFinished => AdminAction::AbortPairing,
+            GameEvent::GameConfigured => AdminAction::Start,
+            GameEvent::Pause(_) => AdminAction::Pause,
+            GameEvent::ContinuePlaying => AdminAction::Resume,
+            GameEvent::Reveal => AdminAction::Reveal,
+            GameEvent::NextSong => AdminAction::Next,
+            GameEvent::SkipSong => AdminAction::Skip,
+            GameEvent::PreviousSong => AdminAction::Previous,
+            GameEvent::Finish(_) => AdminAction::Stop,
+            GameEvent::EndGame => AdminAction::End,
+        }
+    }
+}
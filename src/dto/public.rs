@@ -1,9 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::dto::{
-    common::GamePhaseSnapshot,
+    common::{GamePhaseSnapshot, TeamColorDto},
     game::{SongSummary, TeamSummary},
 };
 
@@ -14,6 +14,37 @@ pub struct TeamsResponse {
     pub teams: Vec<TeamSummary>,
 }
 
+/// Team standing exposed on the public scoreboard, without buzzer ids.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScoreboardEntry {
+    /// Unique identifier for the team.
+    pub id: Uuid,
+    /// Display name of the team.
+    pub name: String,
+    /// Current score for the team, `null` while scores are hidden from public clients.
+    pub score: Option<i32>,
+    /// HSV color assigned to the team.
+    pub color: TeamColorDto,
+}
+
+impl From<TeamSummary> for ScoreboardEntry {
+    fn from(team: TeamSummary) -> Self {
+        Self {
+            id: team.id,
+            name: team.name,
+            score: team.score,
+            color: team.color,
+        }
+    }
+}
+
+/// Response listing the standings of the active game, empty when no game is loaded.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScoreboardResponse {
+    /// Teams of the active game, in display order.
+    pub teams: Vec<ScoreboardEntry>,
+}
+
 /// Response describing the song currently being played and progress made so far.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CurrentSongResponse {
@@ -25,6 +56,35 @@ pub struct CurrentSongResponse {
     pub found_bonus_fields: Vec<String>,
 }
 
+/// Point or bonus field of the current song as exposed to public displays.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicSongField {
+    /// Unique key identifying this field.
+    pub key: String,
+    /// Whether the field was already found.
+    pub found: bool,
+    /// Expected answer, only included while the song is revealed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Response describing the current song for public displays, without its answers until reveal.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicSongResponse {
+    /// Unique identifier for the song.
+    pub id: u32,
+    /// URL of the song media file.
+    pub url: String,
+    /// Start time in milliseconds for playback.
+    pub starts_at_ms: usize,
+    /// Duration in milliseconds for guessing.
+    pub guess_duration_ms: usize,
+    /// Point fields of the song.
+    pub point_fields: Vec<PublicSongField>,
+    /// Bonus fields of the song.
+    pub bonus_fields: Vec<PublicSongField>,
+}
+
 /// Response exposing the game's global phase as seen by the public.
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(transparent)]
@@ -39,3 +99,18 @@ pub struct PairingStatusResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub team_id: Option<Uuid>,
 }
+
+/// Response giving the number of connected public clients.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SpectatorsResponse {
+    /// Number of connected public SSE and WebSocket clients.
+    pub count: usize,
+}
+
+/// Spectator reaction sent to the public displays.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReactionRequest {
+    /// Emoji to display; must be one of the allowed reactions.
+    pub reaction: String,
+}
@@ -1,8 +1,18 @@
+use std::time::{Duration, SystemTime};
+
 use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::dto::{admin::AnswerValidation, common::GamePhaseSnapshot, game::TeamSummary};
+use crate::{
+    config::CountdownExpiry,
+    dto::{
+        admin::AnswerValidation,
+        common::{GamePhaseSnapshot, SongSnapshot, TeamColorDto},
+        format_system_time,
+        game::TeamSummary,
+    },
+};
 
 /// Dispatched payload carried across SSE channels.
 #[derive(Clone, Debug)]
@@ -71,6 +81,19 @@ pub struct AnswerValidationEvent {
 #[serde(transparent)]
 pub struct PhaseChangedEvent(pub GamePhaseSnapshot);
 
+/// Full state sent to a public SSE client right after it connects, so it can render without
+/// waiting for the next broadcast.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnapshotEvent {
+    /// Current phase snapshot, as broadcast in `phase_changed` events.
+    pub phase: GamePhaseSnapshot,
+    /// Teams of the active game, in display order.
+    pub teams: Vec<TeamSummary>,
+    /// Current song of the active game, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub song: Option<SongSnapshot>,
+}
+
 /// Event emitted when the pairing workflow awaits the next team.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct PairingWaitingEvent {
@@ -87,6 +110,18 @@ pub struct PairingAssignedEvent {
     pub buzzer_id: String,
 }
 
+/// Event emitted when a buzzer already paired with another team buzzes during pairing and the
+/// reassignment awaits the GM's confirmation.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PairingConflictEvent {
+    /// ID of the team currently pairing.
+    pub team_id: Uuid,
+    /// ID of the buzzer that buzzed.
+    pub buzzer_id: String,
+    /// ID of the team the buzzer is currently paired with.
+    pub assigned_team_id: Uuid,
+}
+
 /// Event emitted when pairing is aborted and teams restored.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct PairingRestoredEvent {
@@ -121,3 +156,97 @@ pub struct TeamUpdatedEvent {
     /// The updated team with new information.
     pub team: TeamSummary,
 }
+
+/// Event emitted when the admin shows or hides team scores from public clients.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScoresVisibilityEvent {
+    /// Whether public clients receive team scores.
+    pub visible: bool,
+}
+
+/// Event emitted the instant a buzz is accepted, ahead of the phase change, so displays can
+/// play a sound without waiting for the full snapshot.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BuzzCueEvent {
+    /// ID of the team that buzzed.
+    pub team_id: Uuid,
+    /// Color of the team that buzzed.
+    pub color: TeamColorDto,
+}
+
+/// Event emitted when a team buzzes again after using its single buzz for the current song.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BuzzSpentEvent {
+    /// ID of the team whose buzz was rejected.
+    pub team_id: Uuid,
+    /// ID of the buzzer that was pressed.
+    pub buzzer_id: String,
+}
+
+/// Event carrying the battery level last reported by a buzzer.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatteryLevelEvent {
+    /// ID of the reporting buzzer.
+    pub buzzer_id: String,
+    /// Battery level (percent) reported by the buzzer.
+    pub level: u8,
+}
+
+/// Event warning the GM that a buzzer's battery dropped below the configured threshold.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LowBatteryEvent {
+    /// ID of the buzzer running low.
+    pub buzzer_id: String,
+    /// Battery level (percent) reported by the buzzer.
+    pub level: u8,
+    /// Configured warning threshold (percent).
+    pub threshold: u8,
+}
+
+/// Event announcing how many public clients are connected.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SpectatorsEvent {
+    /// Number of connected public SSE and WebSocket clients.
+    pub count: usize,
+}
+
+/// Event relaying a spectator reaction to public displays.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReactionEvent {
+    /// Emoji sent by the spectator.
+    pub reaction: String,
+}
+
+/// Event emitted when a team buzzes and must answer before a server-side deadline.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AnswerCountdownEvent {
+    /// ID of the team that must answer.
+    pub team_id: Uuid,
+    /// Time (milliseconds) the team has to answer.
+    pub duration_ms: u64,
+    /// RFC3339 timestamp at which the countdown expires.
+    pub deadline: String,
+    /// Action taken when the countdown expires.
+    pub on_expiry: CountdownExpiry,
+}
+
+impl AnswerCountdownEvent {
+    /// Build the event for a countdown starting now.
+    pub fn new(team_id: Uuid, duration: Duration, on_expiry: CountdownExpiry) -> Self {
+        Self {
+            team_id,
+            duration_ms: duration.as_millis() as u64,
+            deadline: format_system_time(SystemTime::now() + duration),
+            on_expiry,
+        }
+    }
+}
+
+/// Event emitted when a finished game is loaded again to re-display its final standings.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GameFinishedEvent {
+    /// ID of the finished game.
+    pub game_id: Uuid,
+    /// Final standings, in display order.
+    pub teams: Vec<TeamSummary>,
+}
@@ -31,6 +31,33 @@ pub fn validate_buzzer_id(id: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Validates that a reported battery level is a percentage.
+pub fn validate_battery_level(level: u8) -> Result<(), ValidationError> {
+    if level > 100 {
+        let mut err = ValidationError::new("battery_level_range");
+        err.message = Some(format!("Battery level must be at most 100 (got {level})").into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Validates that a hue is a finite number of degrees.
+pub fn validate_hue(hue: f32) -> Result<(), ValidationError> {
+    if !hue.is_finite() {
+        let mut err = ValidationError::new("hue_not_finite");
+        err.message = Some("Hue must be a finite number of degrees".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Wrap a hue (degrees) into `[0, 360)`, so `-60` and `300` describe the same color.
+pub fn wrap_hue(hue: f32) -> f32 {
+    let wrapped = hue.rem_euclid(360.0);
+    // Rounding can land tiny negative hues exactly on 360.
+    if wrapped >= 360.0 { 0.0 } else { wrapped }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +83,13 @@ mod tests {
         assert!(validate_buzzer_id("deadbeef000g").is_err()); // invalid hex
         assert!(validate_buzzer_id("deadbeef 001").is_err()); // space
     }
+
+    #[test]
+    fn test_wrap_hue() {
+        assert_eq!(wrap_hue(120.0), 120.0);
+        assert_eq!(wrap_hue(360.0), 0.0);
+        assert_eq!(wrap_hue(-60.0), 300.0);
+        assert_eq!(wrap_hue(725.0), 5.0);
+        assert_eq!(wrap_hue(-1e-6), 0.0);
+    }
 }
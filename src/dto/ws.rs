@@ -2,7 +2,11 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::ValidationError;
 
-use crate::dto::{common::TeamColorDto, validation::validate_buzzer_id};
+use crate::dto::{
+    common::TeamColorDto,
+    sse::ServerEvent,
+    validation::{validate_battery_level, validate_buzzer_id},
+};
 
 /// Messages accepted from buzzer WebSocket clients.
 #[derive(Debug, Deserialize, ToSchema)]
@@ -13,6 +17,9 @@ pub enum BuzzerInboundMessage {
     Identification {
         /// Unique identifier for the buzzer device.
         id: String,
+        /// Remaining battery charge (percent), when the device reports it.
+        #[serde(default)]
+        battery: Option<u8>,
     },
     /// Buzzer button was pressed.
     #[serde(rename = "buzz")]
@@ -20,6 +27,14 @@ pub enum BuzzerInboundMessage {
         /// Unique identifier for the buzzer device.
         id: String,
     },
+    /// Buzzer reports its remaining battery charge.
+    #[serde(rename = "battery")]
+    Battery {
+        /// Unique identifier for the buzzer device.
+        id: String,
+        /// Remaining battery charge (percent).
+        percent: u8,
+    },
 }
 
 impl BuzzerInboundMessage {
@@ -34,10 +49,18 @@ impl BuzzerInboundMessage {
         Ok(msg)
     }
 
-    /// Validates the buzzer ID for Identification and Buzz messages.
+    /// Validates the buzzer ID of every message and the reported battery levels.
     fn validate(&self) -> Result<(), ValidationError> {
         match self {
-            Self::Identification { id } | Self::Buzz { id } => validate_buzzer_id(id),
+            Self::Identification { id, battery } => {
+                validate_buzzer_id(id)?;
+                battery.map_or(Ok(()), validate_battery_level)
+            }
+            Self::Buzz { id } => validate_buzzer_id(id),
+            Self::Battery { id, percent } => {
+                validate_buzzer_id(id)?;
+                validate_battery_level(*percent)
+            }
         }
     }
 }
@@ -53,11 +76,49 @@ pub enum BuzzerMessageError {
     ValidationFailed(#[from] ValidationError),
 }
 
+/// Messages accepted from public display WebSocket clients.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PublicInboundMessage {
+    /// Request a full snapshot of the current game session and phase.
+    Resync,
+}
+
+/// Public event forwarded to display WebSocket clients, mirroring an SSE message.
 #[derive(Debug, Serialize, ToSchema)]
-/// Message emitted by the backend to drive LED patterns on a buzzer device.
-pub struct BuzzerOutboundMessage {
+pub struct PublicOutboundMessage {
+    /// Event name, identical to the SSE `event` field.
+    pub event: Option<String>,
+    /// Event payload, identical to the SSE `data` field.
+    #[schema(value_type = Object)]
+    pub data: serde_json::Value,
+}
+
+impl TryFrom<ServerEvent> for PublicOutboundMessage {
+    type Error = serde_json::Error;
+
+    fn try_from(value: ServerEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            event: value.event,
+            data: serde_json::from_str(&value.data)?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Message emitted by the backend to a buzzer device, keyed by its kind.
+#[serde(rename_all = "snake_case")]
+pub enum BuzzerOutboundMessage {
+    /// Acknowledges a successful identification, sent before the first pattern.
+    Welcome {
+        /// Identifier the buzzer registered with.
+        id: String,
+        /// Token to present when reconnecting, when the server issued one.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reconnect_token: Option<String>,
+    },
     /// Visual pattern to display on the target buzzer.
-    pub pattern: BuzzerPattern,
+    Pattern(BuzzerPattern),
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -81,6 +142,8 @@ pub struct BuzzerPatternDetails {
     pub period_ms: usize,
     /// Duty cycle expressed between `0.0` and `1.0`.
     pub dc: f32,
+    /// LED brightness expressed between `0.0` and `1.0`.
+    pub brightness: f32,
     /// HSV color used while the effect is active.
     pub color: TeamColorDto,
 }
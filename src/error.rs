@@ -5,6 +5,7 @@ use validator::ValidationErrors;
 
 use crate::{
     dao::storage::StorageError,
+    dto::admin::CsvRowError,
     state::{AbortError, ApplyError, PlanError},
 };
 
@@ -23,15 +24,24 @@ pub enum ServiceError {
     /// Invalid input provided by the client.
     #[error("invalid input: {0}")]
     InvalidInput(String),
+    /// Imported CSV contains invalid rows.
+    #[error("invalid CSV: {} invalid row(s)", .0.len())]
+    InvalidCsv(Vec<CsvRowError>),
     /// Operation cannot be performed in the current state.
     #[error("invalid state: {0}")]
     InvalidState(String),
     /// Requested resource was not found.
     #[error("not found: {0}")]
     NotFound(String),
+    /// A resource with the same identifier already exists.
+    #[error("conflict: {0}")]
+    Conflict(String),
     /// Operation exceeded its timeout limit.
     #[error("operation timed out")]
     Timeout,
+    /// Client sent too many requests in a short period.
+    #[error("rate limited: {0}")]
+    RateLimited(String),
 }
 
 impl From<StorageError> for ServiceError {
@@ -52,6 +62,9 @@ pub enum AppError {
     /// Bad request with invalid input.
     #[error("bad request: {0}")]
     BadRequest(String),
+    /// Bad request listing the invalid rows of an imported CSV.
+    #[error("bad request: {} invalid CSV row(s)", .0.len())]
+    InvalidCsv(Vec<CsvRowError>),
     /// Unauthorized access attempt.
     #[error("unauthorized: {0}")]
     Unauthorized(String),
@@ -61,6 +74,9 @@ pub enum AppError {
     /// Conflict with current state.
     #[error("conflict: {0}")]
     Conflict(String),
+    /// Client sent too many requests.
+    #[error("too many requests: {0}")]
+    TooManyRequests(String),
     /// Service unavailable or degraded.
     #[error("service unavailable: {0}")]
     ServiceUnavailable(String),
@@ -76,9 +92,12 @@ impl From<ServiceError> for AppError {
             ServiceError::Degraded => AppError::ServiceUnavailable("degraded mode".into()),
             ServiceError::Unauthorized(message) => AppError::Unauthorized(message),
             ServiceError::InvalidInput(message) => AppError::BadRequest(message),
+            ServiceError::InvalidCsv(errors) => AppError::InvalidCsv(errors),
             ServiceError::InvalidState(message) => AppError::Conflict(message),
             ServiceError::NotFound(message) => AppError::NotFound(message),
+            ServiceError::Conflict(message) => AppError::Conflict(message),
             ServiceError::Timeout => AppError::ServiceUnavailable("operation timed out".into()),
+            ServiceError::RateLimited(message) => AppError::TooManyRequests(message),
         }
     }
 }
@@ -86,22 +105,28 @@ impl From<ServiceError> for AppError {
 #[derive(Serialize)]
 struct ErrorBody {
     message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<Vec<CsvRowError>>,
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> axum::response::Response {
         let status = match &self {
-            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::BadRequest(_) | AppError::InvalidCsv(_) => StatusCode::BAD_REQUEST,
             AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
             AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
 
-        let payload = Json(ErrorBody {
-            message: self.to_string(),
-        });
+        let message = self.to_string();
+        let errors = match self {
+            AppError::InvalidCsv(errors) => Some(errors),
+            _ => None,
+        };
+        let payload = Json(ErrorBody { message, errors });
 
         (status, payload).into_response()
     }
@@ -6,7 +6,7 @@ use anyhow::Context;
 use axum::Router;
 use tokio::net::TcpListener;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use neon_beat_back::{dao, routes, services, state};
@@ -14,9 +14,11 @@ use neon_beat_back::{dao, routes, services, state};
 use dao::game_store::GameStore;
 #[cfg(feature = "couch-store")]
 use dao::game_store::couchdb::{CouchConfig, CouchGameStore};
+#[cfg(feature = "memory-store")]
+use dao::game_store::memory::InMemoryGameStore;
 #[cfg(feature = "mongo-store")]
 use dao::game_store::mongodb::{MongoConfig, MongoGameStore};
-use services::storage_supervisor;
+use services::{autosave, event_recorder, storage_supervisor};
 use state::AppState;
 
 #[cfg(not(any(feature = "mongo-store", feature = "couch-store")))]
@@ -29,6 +31,10 @@ async fn main() -> anyhow::Result<()> {
     init_tracing();
 
     let app_state = AppState::new();
+    event_recorder::spawn(&app_state);
+    autosave::spawn(&app_state);
+    #[cfg(unix)]
+    spawn_config_reload(app_state.clone());
 
     let backend = select_store()?;
 
@@ -41,6 +47,10 @@ async fn main() -> anyhow::Result<()> {
         StoreKind::Couch => {
             spawn_couch_supervisor(app_state.clone()).await?;
         }
+        #[cfg(feature = "memory-store")]
+        StoreKind::Memory => {
+            spawn_memory_supervisor(app_state.clone());
+        }
     }
 
     // Build the HTTP router once the shared state is ready.
@@ -56,7 +66,8 @@ async fn main() -> anyhow::Result<()> {
     info!(%addr, "starting server");
 
     let listener = TcpListener::bind(addr).await.context("binding server")?;
-    let service = app.into_make_service();
+    // Client addresses are needed to rate-limit public write endpoints per IP.
+    let service = app.into_make_service_with_connect_info::<SocketAddr>();
     axum::serve(listener, service)
         .with_graceful_shutdown(async move {
             shutdown_signal(app_state).await;
@@ -103,6 +114,20 @@ async fn spawn_couch_supervisor(state: Arc<AppState>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "memory-store")]
+/// Launch the storage supervisor task with a fresh in-memory store, which never fails.
+fn spawn_memory_supervisor(state: Arc<AppState>) {
+    let store: Arc<dyn GameStore> = Arc::new(InMemoryGameStore::new());
+    tracing::warn!("using the in-memory store: games and playlists are lost on restart");
+
+    tokio::spawn(storage_supervisor::run(state, {
+        move || {
+            let store = store.clone();
+            async move { Ok::<Arc<dyn GameStore>, _>(store) }
+        }
+    }));
+}
+
 /// Enumerates the storage backends compiled into the current binary.
 #[derive(Debug, Clone, Copy)]
 enum StoreKind {
@@ -112,11 +137,16 @@ enum StoreKind {
     #[cfg(feature = "couch-store")]
     /// Storage backed by CouchDB.
     Couch,
+    #[cfg(feature = "memory-store")]
+    /// Storage kept in process memory, for tests and demos.
+    Memory,
 }
 
 /// Resolve which storage backend should be booted for this process.
 fn select_store() -> anyhow::Result<StoreKind> {
     match std::env::var("NEON_STORE") {
+        #[cfg(feature = "memory-store")]
+        Ok(value) if is_memory(&value) => Ok(StoreKind::Memory),
         Ok(value) => resolve_store(&value).map_err(|message| anyhow::anyhow!(message)),
         Err(std::env::VarError::NotPresent) => default_store(),
         Err(err) => Err(err.into()),
@@ -137,6 +167,12 @@ fn is_couch(value: &str) -> bool {
     trimmed.eq_ignore_ascii_case("couch") || trimmed.eq_ignore_ascii_case("couchdb")
 }
 
+#[cfg(feature = "memory-store")]
+/// Check whether the provided value selects the in-memory backend.
+fn is_memory(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case("memory")
+}
+
 /// Determine the store to use when no explicit `NEON_STORE` is provided.
 fn default_store() -> anyhow::Result<StoreKind> {
     #[cfg(all(feature = "mongo-store", feature = "couch-store"))]
@@ -208,6 +244,31 @@ fn init_tracing() {
         .init();
 }
 
+/// Reload the configuration file on every SIGHUP, keeping the current configuration when the
+/// file cannot be read or parsed.
+#[cfg(unix)]
+fn spawn_config_reload(app_state: Arc<AppState>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangups = match signal(SignalKind::hangup()) {
+        Ok(hangups) => hangups,
+        Err(err) => {
+            warn!(error = %err, "cannot install SIGHUP handler; configuration reload disabled");
+            return;
+        }
+    };
+    tokio::spawn(async move {
+        while hangups.recv().await.is_some() {
+            match app_state.reload_config() {
+                Ok(()) => info!("configuration reloaded"),
+                Err(err) => {
+                    warn!(error = %err, "configuration reload failed; keeping the current one");
+                }
+            }
+        }
+    });
+}
+
 /// Wait for Ctrl+C or SIGTERM and shut the server down gracefully.
 async fn shutdown_signal(app_state: Arc<AppState>) {
     #[cfg(unix)]
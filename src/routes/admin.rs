@@ -4,8 +4,8 @@ use axum::{
     extract::{Path, Query, State},
     http::{Request, StatusCode},
     middleware::{self, Next},
-    response::Response,
-    routing::{get, post, put},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post, put},
 };
 use axum_valid::Valid;
 use uuid::Uuid;
@@ -13,15 +13,25 @@ use uuid::Uuid;
 use crate::{
     dto::{
         admin::{
-            ActionResponse, AnswerValidationRequest, CreateGameQuery, CreateGameRequest,
-            CreateTeamRequest, FieldsFoundResponse, GameListItem, LoadGameQuery, MarkFieldRequest,
-            NextSongResponse, NoQuery, PlaylistListItem, ScoreAdjustmentRequest,
-            ScoreUpdateResponse, StartGameResponse, StartPairingRequest, StopGameResponse,
+            ActionResponse, AnswerValidationRequest, BonusAwardRequest, BuzzerAssignmentResponse,
+            ConfigResponse, ConnectedBuzzer, CreateGameQuery, CreateGameRequest, CreateTeamRequest,
+            CreateTeamsRequest, CsvImportErrorResponse, CsvImportQuery, CurrentSongFieldsResponse,
+            DegradedOverrideRequest, DuplicateGameQuery, ExportedGame, FieldsFoundResponse,
+            FlushResponse, GameFinishReason, GameListItem, ImportGameQuery, KickBuzzerQuery,
+            LeaderboardEntry, ListGamesQuery, LoadGameQuery, ManualBuzzRequest, MarkFieldRequest,
+            NextSongResponse, NoQuery, PairingAssignRequest, PatternPresetName,
+            PatternPreviewQuery, PlaylistAnalyticsResponse, PlaylistListItem, PreviousSongResponse,
+            RenameGameRequest, ReorderSongsRequest, ReorderTeamsRequest, RequiredFieldsRequest,
+            RoundDelta, ScoreAdjustmentRequest, ScoreUndoRequest, ScoreUpdateResponse,
+            ScoresVisibilityRequest, StartGameResponse, StartPairingRequest, StopGameResponse,
             UpdateTeamRequest,
         },
         game::{
-            CreateGameWithPlaylistRequest, GameSummary, PlaylistInput, PlaylistSummary, TeamSummary,
+            CreateGameWithPlaylistRequest, GameSummary, PlaylistInput, PlaylistSummary, SongInput,
+            SongSummary, TeamSummary,
         },
+        public::TeamsResponse,
+        ws::BuzzerPattern,
     },
     error::AppError,
     services::admin_service,
@@ -38,25 +48,73 @@ pub fn router(state: SharedState) -> Router<SharedState> {
             "/admin/games/with-playlist",
             post(create_game_with_playlist),
         )
-        .route("/admin/games/{id}", get(get_game_by_id).delete(delete_game))
+        .route(
+            "/admin/games/{id}",
+            get(get_game_by_id).patch(update_game).delete(delete_game),
+        )
         .route("/admin/games/{id}/load", post(load_game))
+        .route("/admin/games/{id}/export", get(export_game))
+        .route("/admin/games/import", post(import_game))
+        .route("/admin/games/{id}/duplicate", post(duplicate_game))
+        .route("/admin/leaderboard", get(get_leaderboard))
         .route(
             "/admin/playlists",
             get(list_playlists).post(create_playlist),
         )
+        .route("/admin/playlists/import-csv", post(import_playlist_csv))
+        .route(
+            "/admin/playlists/{id}",
+            put(update_playlist).delete(delete_playlist),
+        )
+        .route("/admin/playlists/{id}/songs", post(add_song))
+        .route("/admin/playlists/{id}/songs/{song_id}", delete(remove_song))
+        .route("/admin/playlists/{id}/reorder", post(reorder_playlist))
+        .route(
+            "/admin/playlists/{id}/analytics",
+            get(get_playlist_analytics),
+        )
+        .route(
+            "/admin/playlists/{id}/songs/{song_id}/required-fields",
+            put(set_required_fields),
+        )
+        .route("/admin/patterns/preview", get(preview_pattern))
+        .route("/admin/config", get(get_config))
+        .route("/admin/storage/flush", post(flush_storage))
+        .route("/admin/storage/degraded", post(override_degraded))
+        .route("/admin/buzzers", get(list_buzzers))
+        .route("/admin/buzzers/unassigned", get(list_unassigned_buzzers))
+        .route("/admin/buzzers/{id}/team", get(get_buzzer_team))
+        .route("/admin/buzzers/{id}/identify", post(identify_buzzer))
+        .route("/admin/buzzers/{id}/kick", post(kick_buzzer))
         .route("/admin/game/start", post(start_game))
         .route("/admin/game/pause", post(pause_game))
         .route("/admin/game/resume", post(resume_game))
+        .route("/admin/game/buzz", post(manual_buzz))
+        .route("/admin/game/buzz/next", post(next_queued_buzz))
         .route("/admin/game/reveal", post(reveal_song))
+        .route("/admin/game/reveal/partial", post(reveal_partial))
         .route("/admin/game/next", post(next_song))
+        .route("/admin/game/skip", post(skip_song))
+        .route("/admin/game/previous", post(previous_song))
         .route("/admin/game/stop", post(stop_game))
         .route("/admin/game/end", post(end_game))
+        .route("/admin/game/replay", post(replay_game))
+        .route("/admin/game/current-song/fields", get(current_song_fields))
         .route("/admin/game/fields/found", post(mark_field_found))
         .route("/admin/game/answer", post(validate_answer))
+        .route("/admin/game/bonus/award", post(award_bonus))
+        .route("/admin/game/score/round-deltas", get(round_deltas))
+        .route("/admin/game/scores/visibility", post(set_scores_visibility))
+        .route("/admin/game/scores/reset", post(reset_scores))
+        .route("/admin/game/rename", post(rename_game))
+        .route("/admin/game/score/undo", post(undo_score_adjustment))
         .route("/admin/teams/{id}/score", post(adjust_score))
         .route("/admin/teams", post(create_team))
+        .route("/admin/teams/bulk", post(create_teams))
+        .route("/admin/teams/reorder", post(reorder_teams))
         .route("/admin/teams/{id}", put(update_team).delete(delete_team))
         .route("/admin/teams/pairing", post(start_pairing))
+        .route("/admin/teams/pairing/assign", post(assign_pairing_buzzer))
         .route("/admin/teams/pairing/abort", post(abort_pairing))
         .route_layer(middleware::from_fn_with_state(state, require_admin_token))
 }
@@ -66,14 +124,15 @@ pub fn router(state: SharedState) -> Router<SharedState> {
     get,
     path = "/admin/games",
     tag = "admin",
-    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("finished_reason" = Option<GameFinishReason>, Query, description = "Only list the games that finished for this reason (`playlist_completed` or `manual_stop`)")),
     responses((status = 200, description = "List available games", body = [GameListItem]))
 )]
 pub async fn list_games(
     State(state): State<SharedState>,
-    Query(_no_query): Query<NoQuery>,
+    Query(query): Query<ListGamesQuery>,
 ) -> Result<Json<Vec<GameListItem>>, AppError> {
-    Ok(Json(admin_service::list_games(&state).await?))
+    Ok(Json(admin_service::list_games(&state, query).await?))
 }
 
 /// Retrieve a game by its ID.
@@ -93,6 +152,88 @@ pub async fn get_game_by_id(
     Ok(Json(admin_service::get_game_by_id(&state, id).await?))
 }
 
+/// Export a game and its playlist as a self-contained JSON document.
+#[utoipa::path(
+    get,
+    path = "/admin/games/{id}/export",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the game to export")),
+    responses(
+        (status = 200, description = "Exported game", body = ExportedGame),
+        (status = 404, description = "Game or playlist not found")
+    )
+)]
+pub async fn export_game(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<ExportedGame>, AppError> {
+    Ok(Json(admin_service::export_game(&state, id).await?))
+}
+
+/// Import a game exported by `GET /admin/games/{id}/export`, without loading it.
+#[utoipa::path(
+    post,
+    path = "/admin/games/import",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("preserve_ids" = Option<bool>, Query, description = "Keep the game, team and playlist identifiers of the export (default false: fresh ones are allocated)")),
+    request_body = ExportedGame,
+    responses(
+        (status = 200, description = "Game imported", body = GameSummary),
+        (status = 400, description = "Invalid or inconsistent export"),
+        (status = 409, description = "Game or playlist identifiers already in use")
+    )
+)]
+pub async fn import_game(
+    State(state): State<SharedState>,
+    Query(options): Query<ImportGameQuery>,
+    Json(exported): Json<ExportedGame>,
+) -> Result<Json<GameSummary>, AppError> {
+    Ok(Json(
+        admin_service::import_game(&state, exported, options.preserve_ids).await?,
+    ))
+}
+
+/// Store a pristine copy of a game (fresh ids, zeroed scores) to replay it, without loading it.
+#[utoipa::path(
+    post,
+    path = "/admin/games/{id}/duplicate",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the game to duplicate"),
+    ("copy_playlist" = Option<bool>, Query, description = "Store a copy of the playlist instead of sharing it (default false)")),
+    responses(
+        (status = 200, description = "Game duplicated", body = GameSummary),
+        (status = 404, description = "Game or playlist not found")
+    )
+)]
+pub async fn duplicate_game(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(options): Query<DuplicateGameQuery>,
+) -> Result<Json<GameSummary>, AppError> {
+    Ok(Json(
+        admin_service::duplicate_game(&state, id, options.copy_playlist).await?,
+    ))
+}
+
+/// Aggregate the final scores of the finished stored games per team.
+#[utoipa::path(
+    get,
+    path = "/admin/leaderboard",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses((status = 200, description = "Teams ranked by total points", body = [LeaderboardEntry]))
+)]
+pub async fn get_leaderboard(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<Vec<LeaderboardEntry>>, AppError> {
+    Ok(Json(admin_service::leaderboard(&state).await?))
+}
+
 /// Delete a persisted game by its identifier.
 #[utoipa::path(
     delete,
@@ -111,6 +252,148 @@ pub async fn delete_game(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Rename a game and/or its playlist, live when it is the active one.
+#[utoipa::path(
+    patch,
+    path = "/admin/games/{id}",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the game to rename")),
+    request_body = RenameGameRequest,
+    responses(
+        (status = 200, description = "Game renamed", body = GameSummary),
+        (status = 404, description = "Game not found")
+    )
+)]
+pub async fn update_game(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<RenameGameRequest>,
+) -> Result<Json<GameSummary>, AppError> {
+    Ok(Json(admin_service::update_game(&state, id, payload).await?))
+}
+
+/// List the connected buzzers with the team they are paired with and their last pattern.
+#[utoipa::path(
+    get,
+    path = "/admin/buzzers",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses((status = 200, description = "Connected buzzers sorted by identifier", body = [ConnectedBuzzer]))
+)]
+pub async fn list_buzzers(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Json<Vec<ConnectedBuzzer>> {
+    Json(admin_service::connected_buzzers(&state).await)
+}
+
+/// List the connected buzzers that are not paired with any team of the active game.
+#[utoipa::path(
+    get,
+    path = "/admin/buzzers/unassigned",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses((status = 200, description = "Identifiers of the connected, unpaired buzzers", body = [String]))
+)]
+pub async fn list_unassigned_buzzers(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Json<Vec<String>> {
+    Json(admin_service::unassigned_buzzers(&state).await)
+}
+
+/// Tell which team of the active game a buzzer is paired with, and whether it is connected.
+#[utoipa::path(
+    get,
+    path = "/admin/buzzers/{id}/team",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Buzzer identifier (12 lowercase hex characters)")),
+    responses(
+        (status = 200, description = "Buzzer is paired with a team", body = BuzzerAssignmentResponse),
+        (status = 204, description = "Buzzer is not paired with any team"),
+        (status = 409, description = "No active game")
+    )
+)]
+pub async fn get_buzzer_team(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Response, AppError> {
+    Ok(match admin_service::buzzer_assignment(&state, &id).await? {
+        Some(assignment) => Json(assignment).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
+
+/// Flash a buzzer for a few seconds so it can be identified physically.
+#[utoipa::path(
+    post,
+    path = "/admin/buzzers/{id}/identify",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Buzzer identifier (12 lowercase hex characters)")),
+    responses(
+        (status = 200, description = "Buzzer is flashing the identify pattern", body = ActionResponse),
+        (status = 404, description = "Buzzer is not connected")
+    )
+)]
+pub async fn identify_buzzer(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(admin_service::identify_buzzer(&state, &id)?))
+}
+
+/// Force-disconnect a buzzer, optionally unpairing it from its team.
+#[utoipa::path(
+    post,
+    path = "/admin/buzzers/{id}/kick",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Buzzer identifier (12 lowercase hex characters)"),
+    ("unpair" = Option<bool>, Query, description = "Also remove the buzzer from its team in the active game (default false)")),
+    responses(
+        (status = 200, description = "Buzzer disconnected", body = ActionResponse),
+        (status = 404, description = "Buzzer is not connected"),
+        (status = 409, description = "Unpairing requested during active pairing")
+    )
+)]
+pub async fn kick_buzzer(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(options): Query<KickBuzzerQuery>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(
+        admin_service::kick_buzzer(&state, &id, options.unpair).await?,
+    ))
+}
+
+/// Answer sheet of the current song: every field with its expected value and found state.
+#[utoipa::path(
+    get,
+    path = "/admin/game/current-song/fields",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Fields of the current song", body = CurrentSongFieldsResponse),
+        (status = 204, description = "No song is currently active"),
+        (status = 409, description = "No active game")
+    )
+)]
+pub async fn current_song_fields(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Response, AppError> {
+    Ok(match admin_service::current_song_fields(&state).await? {
+        Some(fields) => Json(fields).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
+
 /// Retrieve playlists eligible for generating new games.
 #[utoipa::path(
     get,
@@ -143,6 +426,271 @@ pub async fn create_playlist(
     Ok(Json(admin_service::create_playlist(&state, payload).await?))
 }
 
+/// Replace the name and songs of a stored playlist.
+#[utoipa::path(
+    put,
+    path = "/admin/playlists/{id}",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist to update")),
+    request_body = PlaylistInput,
+    responses(
+        (status = 200, description = "Playlist updated", body = PlaylistSummary),
+        (status = 409, description = "The number of songs changed while a stored game uses the playlist")
+    )
+)]
+pub async fn update_playlist(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+    Valid(Json(payload)): Valid<Json<PlaylistInput>>,
+) -> Result<Json<PlaylistSummary>, AppError> {
+    Ok(Json(
+        admin_service::update_playlist(&state, id, payload).await?,
+    ))
+}
+
+/// Append a song to a stored playlist.
+#[utoipa::path(
+    post,
+    path = "/admin/playlists/{id}/songs",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist to extend")),
+    request_body = SongInput,
+    responses(
+        (status = 200, description = "Song added", body = PlaylistSummary),
+        (status = 404, description = "Playlist not found"),
+        (status = 409, description = "A stored game uses the playlist")
+    )
+)]
+pub async fn add_song(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+    Valid(Json(payload)): Valid<Json<SongInput>>,
+) -> Result<Json<PlaylistSummary>, AppError> {
+    Ok(Json(admin_service::add_song(&state, id, payload).await?))
+}
+
+/// Remove a song from a stored playlist; the songs after it move down by one id.
+#[utoipa::path(
+    delete,
+    path = "/admin/playlists/{id}/songs/{song_id}",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist"),
+    ("song_id" = u32, Path, description = "Identifier of the song to remove")),
+    responses(
+        (status = 200, description = "Song removed", body = PlaylistSummary),
+        (status = 400, description = "The song is the last one of the playlist"),
+        (status = 404, description = "Playlist or song not found"),
+        (status = 409, description = "A stored game uses the playlist")
+    )
+)]
+pub async fn remove_song(
+    State(state): State<SharedState>,
+    Path((id, song_id)): Path<(Uuid, u32)>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<PlaylistSummary>, AppError> {
+    Ok(Json(admin_service::remove_song(&state, id, song_id).await?))
+}
+
+/// Set the order in which new games play the songs of a stored playlist.
+#[utoipa::path(
+    post,
+    path = "/admin/playlists/{id}/reorder",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist to reorder")),
+    request_body = ReorderSongsRequest,
+    responses(
+        (status = 200, description = "Playlist reordered", body = PlaylistSummary),
+        (status = 400, description = "The order does not list every song exactly once"),
+        (status = 404, description = "Playlist not found")
+    )
+)]
+pub async fn reorder_playlist(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<ReorderSongsRequest>,
+) -> Result<Json<PlaylistSummary>, AppError> {
+    Ok(Json(
+        admin_service::reorder_playlist(&state, id, payload).await?,
+    ))
+}
+
+/// Delete a stored playlist that no stored game uses.
+#[utoipa::path(
+    delete,
+    path = "/admin/playlists/{id}",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist to delete")),
+    responses(
+        (status = 204, description = "Playlist deleted"),
+        (status = 409, description = "A stored game uses the playlist")
+    )
+)]
+pub async fn delete_playlist(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<StatusCode, AppError> {
+    admin_service::delete_playlist(&state, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Create a playlist from a CSV song list exported from a spreadsheet.
+///
+/// Each record reads `url,starts_at_ms,guess_duration_ms` followed by one `key,value,points`
+/// group per point field (keys prefixed with `bonus:` declare bonus fields). An optional header
+/// record starting with `url` is skipped.
+#[utoipa::path(
+    post,
+    path = "/admin/playlists/import-csv",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("name" = String, Query, description = "Name of the created playlist")),
+    request_body(content = String, content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Playlist created", body = PlaylistSummary),
+        (status = 400, description = "Invalid rows, each reported with its line", body = CsvImportErrorResponse)
+    )
+)]
+pub async fn import_playlist_csv(
+    State(state): State<SharedState>,
+    Query(query): Query<CsvImportQuery>,
+    body: String,
+) -> Result<Json<PlaylistSummary>, AppError> {
+    Ok(Json(
+        admin_service::import_playlist_csv(&state, query.name, &body).await?,
+    ))
+}
+
+/// Retrieve the per-song analytics accumulated for a playlist across sessions.
+#[utoipa::path(
+    get,
+    path = "/admin/playlists/{id}/analytics",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist")),
+    responses(
+        (status = 200, description = "Playlist analytics", body = PlaylistAnalyticsResponse),
+        (status = 404, description = "Playlist not found")
+    )
+)]
+pub async fn get_playlist_analytics(
+    State(state): State<SharedState>,
+    Path(id): Path<Uuid>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<PlaylistAnalyticsResponse>, AppError> {
+    Ok(Json(
+        admin_service::get_playlist_analytics(&state, id).await?,
+    ))
+}
+
+/// Flag which fields of a playlist song must be found before progressing past it.
+#[utoipa::path(
+    put,
+    path = "/admin/playlists/{id}/songs/{song_id}/required-fields",
+    tag = "admin",
+    request_body = RequiredFieldsRequest,
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("id" = String, Path, description = "Identifier of the playlist"),
+    ("song_id" = u32, Path, description = "Identifier of the song inside the playlist")),
+    responses(
+        (status = 200, description = "Updated song", body = SongSummary),
+        (status = 400, description = "Unknown field key"),
+        (status = 404, description = "Playlist or song not found")
+    )
+)]
+pub async fn set_required_fields(
+    State(state): State<SharedState>,
+    Path((id, song_id)): Path<(Uuid, u32)>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<RequiredFieldsRequest>,
+) -> Result<Json<SongSummary>, AppError> {
+    Ok(Json(
+        admin_service::set_required_fields(&state, id, song_id, payload).await?,
+    ))
+}
+
+/// Preview the LED pattern a buzzer would display for a preset and team color.
+#[utoipa::path(
+    get,
+    path = "/admin/patterns/preview",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
+    ("preset" = PatternPresetName, Query, description = "Preset to resolve"),
+    ("h" = Option<f32>, Query, description = "Hue of the team color (required for standby, playing and answering)"),
+    ("s" = Option<f32>, Query, description = "Saturation of the team color (0.0 to 1.0)"),
+    ("v" = Option<f32>, Query, description = "Value of the team color (0.0 to 1.0)")),
+    responses(
+        (status = 200, description = "Resolved buzzer pattern", body = BuzzerPattern),
+        (status = 400, description = "Invalid preset or color")
+    )
+)]
+pub async fn preview_pattern(
+    State(state): State<SharedState>,
+    Valid(Query(query)): Valid<Query<PatternPreviewQuery>>,
+) -> Result<Json<BuzzerPattern>, AppError> {
+    Ok(Json(admin_service::preview_pattern(&state, query)?))
+}
+
+/// Return the effective configuration loaded by the server.
+#[utoipa::path(
+    get,
+    path = "/admin/config",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses((status = 200, description = "Effective configuration", body = ConfigResponse))
+)]
+pub async fn get_config(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Json<ConfigResponse> {
+    Json(admin_service::get_config(&state))
+}
+
+/// Persist every pending debounced write immediately.
+#[utoipa::path(
+    post,
+    path = "/admin/storage/flush",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Pending writes flushed", body = FlushResponse),
+        (status = 503, description = "No storage backend available")
+    )
+)]
+pub async fn flush_storage(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<FlushResponse>, AppError> {
+    Ok(Json(admin_service::flush_storage(&state).await?))
+}
+
+/// Force the degraded flag for an operational drill, until the next storage health transition.
+#[utoipa::path(
+    post,
+    path = "/admin/storage/degraded",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = DegradedOverrideRequest,
+    responses((status = 200, description = "Degraded flag overridden", body = ActionResponse))
+)]
+pub async fn override_degraded(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<DegradedOverrideRequest>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(
+        admin_service::override_degraded(&state, payload).await?,
+    ))
+}
+
 /// Load and activate a stored game for continued play.
 #[utoipa::path(
     post,
@@ -150,7 +698,8 @@ pub async fn create_playlist(
     tag = "admin",
     params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
     ("id" = String, Path, description = "Identifier of the game to load"),
-    ("shuffle" = Option<bool>, Query, description = "Shuffle playlist (default false) ; only applies when loading a game that has not yet started or whose playlist is completely played")),
+    ("shuffle" = Option<bool>, Query, description = "Shuffle playlist (default false) ; only applies when loading a game that has not yet started or whose playlist is completely played"),
+    ("shuffle_seed" = Option<u64>, Query, description = "Seed of the shuffle, for a reproducible order (implies shuffle)")),
     responses((status = 200, description = "Game loaded", body = GameSummary))
 )]
 pub async fn load_game(
@@ -159,7 +708,7 @@ pub async fn load_game(
     Query(options): Query<LoadGameQuery>,
 ) -> Result<Json<GameSummary>, AppError> {
     Ok(Json(
-        admin_service::load_game(&state, id, options.shuffle).await?,
+        admin_service::load_game(&state, id, options.resolved_shuffle_seed()).await?,
     ))
 }
 
@@ -169,7 +718,8 @@ pub async fn load_game(
     path = "/admin/games/with-playlist",
     tag = "admin",
     params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
-    ("shuffle" = Option<bool>, Query, description = "Shuffle playlist (default false)")),
+    ("shuffle" = Option<bool>, Query, description = "Shuffle playlist (default false)"),
+    ("shuffle_seed" = Option<u64>, Query, description = "Seed of the shuffle, for a reproducible order (implies shuffle)")),
     request_body = CreateGameWithPlaylistRequest,
     responses((status = 200, description = "Game created", body = GameSummary))
 )]
@@ -179,7 +729,7 @@ pub async fn create_game_with_playlist(
     Valid(Json(payload)): Valid<Json<CreateGameWithPlaylistRequest>>,
 ) -> Result<Json<GameSummary>, AppError> {
     Ok(Json(
-        admin_service::create_game(&state, payload, options.shuffle).await?,
+        admin_service::create_game(&state, payload, options.resolved_shuffle_seed()).await?,
     ))
 }
 
@@ -189,7 +739,8 @@ pub async fn create_game_with_playlist(
     path = "/admin/games",
     tag = "admin",
     params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream"),
-    ("shuffle" = Option<bool>, Query, description = "Shuffle playlist (default false)")),
+    ("shuffle" = Option<bool>, Query, description = "Shuffle playlist (default false)"),
+    ("shuffle_seed" = Option<u64>, Query, description = "Seed of the shuffle, for a reproducible order (implies shuffle)")),
     request_body = CreateGameRequest,
     responses((status = 200, description = "Game created from playlist", body = GameSummary))
 )]
@@ -198,7 +749,9 @@ pub async fn create_game(
     Query(options): Query<CreateGameQuery>,
     Valid(Json(payload)): Valid<Json<CreateGameRequest>>,
 ) -> Result<Json<GameSummary>, AppError> {
-    let game = admin_service::create_game_from_playlist(&state, payload, options.shuffle).await?;
+    let game =
+        admin_service::create_game_from_playlist(&state, payload, options.resolved_shuffle_seed())
+            .await?;
     Ok(Json(game))
 }
 
@@ -232,6 +785,45 @@ pub async fn pause_game(
     Ok(Json(admin_service::pause_game(&state).await?))
 }
 
+/// Buzz on behalf of a team while a song plays, e.g. to stand in for a broken buzzer.
+#[utoipa::path(
+    post,
+    path = "/admin/game/buzz",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = ManualBuzzRequest,
+    responses(
+        (status = 200, description = "Game paused on the team's buzzer", body = ActionResponse),
+        (status = 404, description = "Team not found"),
+        (status = 409, description = "No song playing, the team has no paired buzzer, or it already buzzed during this song")
+    )
+)]
+pub async fn manual_buzz(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<ManualBuzzRequest>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(admin_service::manual_buzz(&state, payload).await?))
+}
+
+/// Hand the turn to the next team that buzzed while another one was answering.
+#[utoipa::path(
+    post,
+    path = "/admin/game/buzz/next",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Game paused on the next queued buzzer", body = ActionResponse),
+        (status = 409, description = "No team is answering or no buzz is queued")
+    )
+)]
+pub async fn next_queued_buzz(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(admin_service::next_queued_buzz(&state).await?))
+}
+
 /// Resume a previously paused game.
 #[utoipa::path(
     post,
@@ -262,13 +854,37 @@ pub async fn reveal_song(
     Ok(Json(admin_service::reveal(&state).await?))
 }
 
+/// Reveal the point fields of the current song while withholding its bonus fields.
+///
+/// The game stays in its playing or paused phase; the full reveal still goes through
+/// `POST /admin/game/reveal`.
+#[utoipa::path(
+    post,
+    path = "/admin/game/reveal/partial",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Point fields revealed", body = FieldsFoundResponse),
+        (status = 409, description = "Game is not playing or paused")
+    )
+)]
+pub async fn reveal_partial(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<FieldsFoundResponse>, AppError> {
+    Ok(Json(admin_service::reveal_partial(&state).await?))
+}
+
 /// Advance to the next song in the running game.
 #[utoipa::path(
     post,
     path = "/admin/game/next",
     tag = "admin",
     params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
-    responses((status = 200, description = "Advanced to next song", body = NextSongResponse))
+    responses(
+        (status = 200, description = "Advanced to next song", body = NextSongResponse),
+        (status = 409, description = "Invalid phase, or previous advance too recent")
+    )
 )]
 pub async fn next_song(
     State(state): State<SharedState>,
@@ -277,6 +893,42 @@ pub async fn next_song(
     Ok(Json(admin_service::next_song(&state).await?))
 }
 
+/// Skip the current song without revealing it.
+#[utoipa::path(
+    post,
+    path = "/admin/game/skip",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Skipped to next song", body = NextSongResponse),
+        (status = 409, description = "Invalid phase, or previous advance too recent")
+    )
+)]
+pub async fn skip_song(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<NextSongResponse>, AppError> {
+    Ok(Json(admin_service::skip_song(&state).await?))
+}
+
+/// Go back to the previous song.
+#[utoipa::path(
+    post,
+    path = "/admin/game/previous",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Back to the previous song", body = PreviousSongResponse),
+        (status = 409, description = "Invalid phase, already at the first song, or previous advance too recent")
+    )
+)]
+pub async fn previous_song(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<PreviousSongResponse>, AppError> {
+    Ok(Json(admin_service::previous_song(&state).await?))
+}
+
 /// Stop the game early and return final team standings.
 #[utoipa::path(
     post,
@@ -307,6 +959,24 @@ pub async fn end_game(
     Ok(Json(admin_service::end_game(&state).await?))
 }
 
+/// Play the game kept in memory after it ended again, with every score reset.
+#[utoipa::path(
+    post,
+    path = "/admin/game/replay",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Game ready to be played again", body = GameSummary),
+        (status = 409, description = "Not idle, or no ended game kept in memory")
+    )
+)]
+pub async fn replay_game(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<GameSummary>, AppError> {
+    Ok(Json(admin_service::replay_game(&state).await?))
+}
+
 /// Flag a point or bonus field as discovered for the current song.
 #[utoipa::path(
     post,
@@ -332,7 +1002,10 @@ pub async fn mark_field_found(
     tag = "admin",
     params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
     request_body = AnswerValidationRequest,
-    responses((status = 200, description = "Answer validation applied", body = ActionResponse))
+    responses(
+        (status = 200, description = "Answer validation applied", body = ActionResponse),
+        (status = 404, description = "Awarded team not found")
+    )
 )]
 pub async fn validate_answer(
     State(state): State<SharedState>,
@@ -342,6 +1015,122 @@ pub async fn validate_answer(
     Ok(Json(admin_service::validate_answer(&state, payload).await?))
 }
 
+/// Award bonus fields of the current song to a team, when bonus fields are scored separately.
+#[utoipa::path(
+    post,
+    path = "/admin/game/bonus/award",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = BonusAwardRequest,
+    responses(
+        (status = 200, description = "Bonus points awarded", body = ScoreUpdateResponse),
+        (status = 404, description = "Team not found"),
+        (status = 409, description = "Bonus fields are awarded with the answer, a field was already awarded for this song, or no song is playing")
+    )
+)]
+pub async fn award_bonus(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<BonusAwardRequest>,
+) -> Result<Json<ScoreUpdateResponse>, AppError> {
+    Ok(Json(admin_service::award_bonus(&state, payload).await?))
+}
+
+/// Report each team's score change since the current song started.
+#[utoipa::path(
+    get,
+    path = "/admin/game/score/round-deltas",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses((status = 200, description = "Score deltas for the current song", body = [RoundDelta]))
+)]
+pub async fn round_deltas(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<Vec<RoundDelta>>, AppError> {
+    Ok(Json(admin_service::round_deltas(&state).await?))
+}
+
+/// Show or hide team scores from public clients.
+#[utoipa::path(
+    post,
+    path = "/admin/game/scores/visibility",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = ScoresVisibilityRequest,
+    responses((status = 200, description = "Scores visibility updated", body = ActionResponse))
+)]
+pub async fn set_scores_visibility(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<ScoresVisibilityRequest>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(
+        admin_service::set_scores_visibility(&state, payload).await?,
+    ))
+}
+
+/// Undo the most recent score adjustment of a team.
+#[utoipa::path(
+    post,
+    path = "/admin/game/score/undo",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = ScoreUndoRequest,
+    responses(
+        (status = 200, description = "Score adjustment reverted", body = ScoreUpdateResponse),
+        (status = 404, description = "Team not found"),
+        (status = 409, description = "No score adjustment to undo for this team")
+    )
+)]
+pub async fn undo_score_adjustment(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<ScoreUndoRequest>,
+) -> Result<Json<ScoreUpdateResponse>, AppError> {
+    Ok(Json(
+        admin_service::undo_score_adjustment(&state, payload).await?,
+    ))
+}
+
+/// Rename the active game and/or its playlist and broadcast the updated session.
+#[utoipa::path(
+    post,
+    path = "/admin/game/rename",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = RenameGameRequest,
+    responses(
+        (status = 200, description = "Game renamed", body = GameSummary),
+        (status = 409, description = "No active game")
+    )
+)]
+pub async fn rename_game(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<RenameGameRequest>,
+) -> Result<Json<GameSummary>, AppError> {
+    Ok(Json(admin_service::rename_game(&state, payload).await?))
+}
+
+/// Zero every team's score without restarting the game.
+#[utoipa::path(
+    post,
+    path = "/admin/game/scores/reset",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    responses(
+        (status = 200, description = "Scores reset", body = [TeamSummary]),
+        (status = 409, description = "No game is running")
+    )
+)]
+pub async fn reset_scores(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<Vec<TeamSummary>>, AppError> {
+    Ok(Json(admin_service::reset_scores(&state).await?))
+}
+
 /// Adjust the score for a specific team by team ID.
 #[utoipa::path(
     post,
@@ -381,6 +1170,26 @@ pub async fn create_team(
     Ok(Json(summary))
 }
 
+#[utoipa::path(
+    post,
+    path = "/admin/teams/bulk",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = CreateTeamsRequest,
+    responses(
+        (status = 200, description = "Teams created, in request order", body = [TeamSummary]),
+        (status = 400, description = "A team is invalid or reuses a buzzer; no team was created")
+    )
+)]
+/// Create several teams in the active game at once during prep phase.
+pub async fn create_teams(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Valid(Json(payload)): Valid<Json<CreateTeamsRequest>>,
+) -> Result<Json<Vec<TeamSummary>>, AppError> {
+    Ok(Json(admin_service::create_teams(&state, payload).await?))
+}
+
 #[utoipa::path(
     put,
     path = "/admin/teams/{id}",
@@ -401,6 +1210,27 @@ pub async fn update_team(
     Ok(Json(summary))
 }
 
+#[utoipa::path(
+    post,
+    path = "/admin/teams/reorder",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = ReorderTeamsRequest,
+    responses(
+        (status = 200, description = "Teams reordered", body = TeamsResponse),
+        (status = 400, description = "Ids are not a permutation of the current teams"),
+        (status = 409, description = "No active game or pairing in progress")
+    )
+)]
+/// Set the display order of the teams of the active game.
+pub async fn reorder_teams(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<ReorderTeamsRequest>,
+) -> Result<Json<TeamsResponse>, AppError> {
+    Ok(Json(admin_service::reorder_teams(&state, payload).await?))
+}
+
 #[utoipa::path(
     delete,
     path = "/admin/teams/{id}",
@@ -437,6 +1267,30 @@ pub async fn start_pairing(
     Ok(StatusCode::ACCEPTED)
 }
 
+#[utoipa::path(
+    post,
+    path = "/admin/teams/pairing/assign",
+    tag = "admin",
+    params(("X-Admin-Token" = String, Header, description = "Admin token issued by the /sse/admin stream")),
+    request_body = PairingAssignRequest,
+    responses(
+        (status = 200, description = "Buzzer paired with the team currently pairing", body = ActionResponse),
+        (status = 404, description = "Buzzer not connected"),
+        (status = 409, description = "No pairing in progress, or the buzzer is paired with another team and `force` is not set")
+    )
+)]
+/// Pair a connected buzzer with the team currently pairing, e.g. to confirm a reassignment
+/// reported by a `pairing.conflict` event.
+pub async fn assign_pairing_buzzer(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<PairingAssignRequest>,
+) -> Result<Json<ActionResponse>, AppError> {
+    Ok(Json(
+        admin_service::assign_pairing_buzzer(&state, payload).await?,
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/admin/teams/pairing/abort",
@@ -467,16 +1321,19 @@ async fn require_admin_token(
             AppError::Unauthorized("missing admin token header `X-Admin-Token`".into())
         })?;
 
-    let expected = {
-        let guard = state.admin_token().lock().await;
-        guard.clone()
+    let accepted = {
+        let tokens = state.admin_tokens().lock().await;
+        if tokens.is_empty() {
+            return Err(AppError::Unauthorized(
+                "admin SSE stream not initialised yet".into(),
+            ));
+        }
+        tokens.contains(&provided)
     };
 
-    match expected {
-        Some(token) if token == provided => Ok(next.run(req).await),
-        Some(_) => Err(AppError::Unauthorized("invalid admin token".into())),
-        None => Err(AppError::Unauthorized(
-            "admin SSE stream not initialised yet".into(),
-        )),
+    if accepted {
+        Ok(next.run(req).await)
+    } else {
+        Err(AppError::Unauthorized("invalid admin token".into()))
     }
 }
@@ -1,14 +1,34 @@
-use axum::Router;
+use axum::{Json, Router, routing::get};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{services::documentation::ApiDoc, state::SharedState};
+use crate::{
+    services::documentation::{self, ApiDoc, EventsDoc},
+    state::SharedState,
+};
 
-/// Serve the Swagger UI backed by the generated OpenAPI document.
+/// Serve the Swagger UI backed by the generated OpenAPI document, along with the catalog of
+/// realtime SSE and WebSocket messages.
 pub fn router(state: SharedState) -> Router<SharedState> {
     let ui: Router<SharedState> = SwaggerUi::new("/docs")
         .url("/api-doc/openapi.json", ApiDoc::openapi())
         .into();
 
-    ui.with_state(state)
+    ui.route("/docs/events", get(events_doc)).with_state(state)
+}
+
+/// List each SSE event and WebSocket message with a reference to its payload schema.
+async fn events_doc() -> Json<EventsDoc> {
+    Json(documentation::events_doc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AppState;
+
+    #[test]
+    fn events_catalog_route_coexists_with_swagger_ui() {
+        let _ = router(AppState::new());
+    }
 }
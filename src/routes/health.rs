@@ -1,6 +1,7 @@
 use axum::{
     Json, Router,
     extract::{Query, State},
+    http::StatusCode,
     routing::get,
 };
 
@@ -13,18 +14,78 @@ use crate::{
 #[utoipa::path(
     get,
     path = "/healthcheck",
-    responses((status = 200, description = "Service is healthy", body = HealthResponse))
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is ready", body = HealthResponse),
+        (status = 503, description = "Service is in degraded mode", body = HealthResponse)
+    )
 )]
-/// Return the current health status of the backend and ping the storage backend.
+/// Kept for existing clients: same answer as [`readiness`].
 pub async fn healthcheck(
+    State(state): State<SharedState>,
+    Query(no_query): Query<NoQuery>,
+) -> (StatusCode, Json<HealthResponse>) {
+    readiness(State(state), Query(no_query)).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses((status = 200, description = "Process is up", body = HealthResponse))
+)]
+/// Liveness probe: answers as long as the process serves requests, whatever the storage state.
+pub async fn liveness(Query(_no_query): Query<NoQuery>) -> Json<HealthResponse> {
+    Json(HealthResponse::ok())
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is ready", body = HealthResponse),
+        (status = 503, description = "Service is in degraded mode", body = HealthResponse)
+    )
+)]
+/// Readiness probe: `503 Service Unavailable` while in degraded mode or when the storage backend
+/// fails its health check.
+pub async fn readiness(
     State(state): State<SharedState>,
     Query(_no_query): Query<NoQuery>,
-) -> Json<HealthResponse> {
+) -> (StatusCode, Json<HealthResponse>) {
     let status = health_service::health_status(&state).await;
-    Json(status)
+    let code = if status.is_ok() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(status))
+}
+
+/// Render the operational counters and the current game phase in the Prometheus text format.
+#[cfg(feature = "metrics")]
+pub async fn metrics(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> impl axum::response::IntoResponse {
+    let phase = state.state_machine_phase().await;
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        state.metrics().render(&phase),
+    )
 }
 
 /// Configure the health routes subtree.
 pub fn router() -> Router<SharedState> {
-    Router::<SharedState>::new().route("/healthcheck", get(healthcheck))
+    let router = Router::<SharedState>::new()
+        .route("/healthcheck", get(healthcheck))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness));
+    #[cfg(feature = "metrics")]
+    let router = router.route("/metrics", get(metrics));
+    router
 }
@@ -1,26 +1,36 @@
+use std::net::SocketAddr;
+
 use axum::{
     Json, Router,
-    extract::{Query, State},
-    routing::get,
+    extract::{ConnectInfo, Query, State},
+    http::StatusCode,
+    routing::{get, post},
 };
 
 use crate::{
     dto::{
         admin::NoQuery,
-        public::{CurrentSongResponse, GamePhaseResponse, PairingStatusResponse, TeamsResponse},
+        public::{
+            CurrentSongResponse, GamePhaseResponse, PairingStatusResponse, PublicSongResponse,
+            ReactionRequest, ScoreboardResponse, SpectatorsResponse, TeamsResponse,
+        },
     },
     error::AppError,
     services::public_service,
     state::SharedState,
 };
 
-/// Public read-only endpoints that expose the current game state.
+/// Public endpoints that expose the current game state and accept spectator reactions.
 pub fn router() -> Router<SharedState> {
     Router::new()
         .route("/public/teams", get(get_teams))
+        .route("/public/scoreboard", get(get_scoreboard))
         .route("/public/song", get(get_current_song))
+        .route("/public/song/current", get(get_public_song))
         .route("/public/phase", get(get_game_phase))
         .route("/public/pairing", get(get_pairing_status))
+        .route("/public/spectators", get(get_spectators))
+        .route("/public/react", post(react))
 }
 
 #[utoipa::path(
@@ -38,6 +48,20 @@ pub async fn get_teams(
     Ok(Json(payload))
 }
 
+#[utoipa::path(
+    get,
+    path = "/public/scoreboard",
+    tag = "public",
+    responses((status = 200, description = "Team standings, empty when no game is loaded", body = ScoreboardResponse))
+)]
+/// Return the team names, colors and scores of the active game, without buzzer ids.
+pub async fn get_scoreboard(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Json<ScoreboardResponse> {
+    Json(public_service::get_scoreboard(&state).await)
+}
+
 #[utoipa::path(
     get,
     path = "/public/song",
@@ -56,6 +80,26 @@ pub async fn get_current_song(
     Ok(Json(payload))
 }
 
+#[utoipa::path(
+    get,
+    path = "/public/song/current",
+    tag = "public",
+    responses(
+        (status = 200, description = "Current song media and field keys, with values once revealed", body = PublicSongResponse),
+        (status = 404, description = "No active song"),
+        (status = 409, description = "No active game")
+    )
+)]
+/// Return the media URL, playback window and field keys of the current song, hiding the
+/// expected values until the song is revealed.
+pub async fn get_public_song(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Result<Json<PublicSongResponse>, AppError> {
+    let payload = public_service::get_public_song(&state).await?;
+    Ok(Json(payload))
+}
+
 #[utoipa::path(
     get,
     path = "/public/phase",
@@ -85,3 +129,39 @@ pub async fn get_pairing_status(
     let payload = public_service::get_pairing_status(&state).await?;
     Ok(Json(payload))
 }
+
+#[utoipa::path(
+    get,
+    path = "/public/spectators",
+    tag = "public",
+    responses((status = 200, description = "Number of connected public clients", body = SpectatorsResponse))
+)]
+/// Return how many public SSE and WebSocket clients are connected.
+pub async fn get_spectators(
+    State(state): State<SharedState>,
+    Query(_no_query): Query<NoQuery>,
+) -> Json<SpectatorsResponse> {
+    Json(public_service::get_spectators(&state))
+}
+
+#[utoipa::path(
+    post,
+    path = "/public/react",
+    tag = "public",
+    request_body = ReactionRequest,
+    responses(
+        (status = 202, description = "Reaction relayed to the public displays"),
+        (status = 400, description = "Reaction not in the allowed list"),
+        (status = 429, description = "Client reacted too recently")
+    )
+)]
+/// Send a spectator reaction, relayed to the public displays as a `reaction` event.
+pub async fn react(
+    State(state): State<SharedState>,
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
+    Query(_no_query): Query<NoQuery>,
+    Json(payload): Json<ReactionRequest>,
+) -> Result<StatusCode, AppError> {
+    public_service::react(&state, client.ip(), payload)?;
+    Ok(StatusCode::ACCEPTED)
+}
@@ -6,7 +6,10 @@ use tracing::info;
 
 use crate::{
     error::AppError,
-    services::sse_service::{self, StreamKind},
+    services::{
+        sse_events,
+        sse_service::{self, StreamKind},
+    },
     state::SharedState,
 };
 
@@ -15,15 +18,27 @@ use crate::{
     path = "/sse/public",
     responses((status = 200, description = "Public SSE stream", content_type = "text/event-stream", body = String))
 )]
-/// Stream realtime public events to connected frontends.
+/// Stream realtime public events to connected frontends, starting with a `handshake` and a
+/// `snapshot` of the current state sent to this client only.
 pub async fn public_stream(
     State(state): State<SharedState>,
 ) -> Sse<impl Stream<Item = Result<axum::response::sse::Event, Infallible>>> {
     let receiver = sse_service::subscribe_public(&state);
     let degraded_rx = state.degraded_watcher();
     info!("New public SSE connection");
-    sse_service::broadcast_public_handshake(state.public_sse(), state.is_degraded().await);
-    sse_service::to_sse_stream(receiver, StreamKind::Public, degraded_rx)
+    sse_events::spectator_connected(&state);
+    let initial = sse_service::public_handshake(state.is_degraded().await)
+        .into_iter()
+        .chain(sse_events::public_snapshot_event(&state).await)
+        .collect();
+    let keepalive = state.config().sse_keepalive();
+    sse_service::to_sse_stream(
+        receiver,
+        StreamKind::Public(state),
+        degraded_rx,
+        initial,
+        keepalive,
+    )
 }
 
 #[utoipa::path(
@@ -38,11 +53,14 @@ pub async fn admin_stream(
     let (receiver, token) = sse_service::subscribe_admin(&state).await?;
     let degraded_rx = state.degraded_watcher();
     info!("New admin SSE connection");
-    sse_service::broadcast_admin_handshake(state.admin_sse(), &token, state.is_degraded().await);
+    let handshake = sse_service::admin_handshake(&token, state.is_degraded().await);
+    let keepalive = state.config().sse_keepalive();
     Ok(sse_service::to_sse_stream(
         receiver,
-        StreamKind::Admin(state),
+        StreamKind::Admin(state, token),
         degraded_rx,
+        handshake.into_iter().collect(),
+        keepalive,
     ))
 }
 
@@ -5,7 +5,10 @@ use axum::{
     routing::get,
 };
 
-use crate::{services::websocket_service, state::SharedState};
+use crate::{
+    services::{public_ws_service, websocket_service},
+    state::SharedState,
+};
 
 #[utoipa::path(
     get,
@@ -13,14 +16,72 @@ use crate::{services::websocket_service, state::SharedState};
     responses((status = 101, description = "Switching protocols to WebSocket"))
 )]
 /// Upgrade the HTTP connection into a buzzer WebSocket session.
+///
+/// Frames and messages larger than the configured `ws_max_message_bytes` are refused.
 pub async fn ws_handler(
     State(state): State<SharedState>,
     ws: WebSocketUpgrade,
 ) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| websocket_service::handle_socket(state, socket))
+    let max_size = state.config().ws_max_message_bytes();
+    ws.max_message_size(max_size)
+        .max_frame_size(max_size)
+        .on_upgrade(move |socket| websocket_service::handle_socket(state, socket))
 }
 
-/// Configure the WebSocket endpoint.
+#[utoipa::path(
+    get,
+    path = "/ws/public",
+    responses((status = 101, description = "Switching protocols to WebSocket"))
+)]
+/// Upgrade the HTTP connection into a public display WebSocket session streaming the same
+/// events as `/sse/public` and answering `{"type":"resync"}` requests.
+pub async fn public_ws_handler(
+    State(state): State<SharedState>,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| public_ws_service::handle_public_socket(state, socket))
+}
+
+/// Configure the WebSocket endpoints.
 pub fn router() -> Router<SharedState> {
-    Router::<SharedState>::new().route("/ws", get(ws_handler))
+    Router::<SharedState>::new()
+        .route("/ws", get(ws_handler))
+        .route("/ws/public", get(public_ws_handler))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AppState;
+    use tungstenite::{Message, protocol::frame::coding::CloseCode};
+
+    #[tokio::test]
+    async fn oversized_buzzer_frame_closes_the_connection() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let max_size = AppState::new().config().ws_max_message_bytes();
+        tokio::spawn(async move {
+            axum::serve(listener, router().with_state(AppState::new()))
+                .await
+                .unwrap();
+        });
+
+        let close = tokio::task::spawn_blocking(move || {
+            let (mut socket, _) = tungstenite::connect(format!("ws://{addr}/ws")).unwrap();
+            socket
+                .send(Message::text("x".repeat(max_size + 1)))
+                .unwrap();
+            loop {
+                match socket.read() {
+                    Ok(Message::Close(frame)) => return frame,
+                    Ok(_) => continue,
+                    Err(err) => panic!("connection dropped without close frame: {err}"),
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(close.unwrap().code, CloseCode::Size);
+    }
 }
@@ -2,42 +2,63 @@
 //! Storage persistence, in-memory state updates, and state-machine transitions
 //! while honouring the single-transition-at-a-time requirement.
 
-use std::time::SystemTime;
-use tracing::{debug, warn};
+use indexmap::IndexMap;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::{
-    config::BuzzerPatternPreset,
+    config::{BonusScoring, BuzzerPatternPreset, CountdownExpiry, LeaderboardKey},
+    dao::models::{PlaylistAnalyticsEntity, PlaylistEntity, SongAnalyticsEntity},
     dto::{
         admin::{
-            ActionResponse, AnswerValidationRequest, CreateGameRequest, CreateTeamRequest,
-            FieldKind, FieldsFoundResponse, GameListItem, MarkFieldRequest, NextSongResponse,
-            PlaylistListItem, ScoreAdjustmentRequest, ScoreUpdateResponse, StartGameResponse,
-            StartPairingRequest, StopGameResponse, UpdateTeamRequest,
+            ActionResponse, AnswerValidation, AnswerValidationRequest, BonusAwardRequest,
+            BuzzerAssignmentResponse, ConfigResponse, ConnectedBuzzer, CreateGameRequest,
+            CreateTeamRequest, CreateTeamsRequest, CurrentSongFieldsResponse,
+            DegradedOverrideRequest, ExportedGame, FieldKind, FieldsFoundResponse, FlushResponse,
+            GameFinishReason, GameListItem, LeaderboardEntry, ListGamesQuery, ManualBuzzRequest,
+            MarkFieldRequest, NextSongResponse, PairingAssignRequest, PatternPresetName,
+            PatternPreviewQuery, PlaylistAnalyticsResponse, PlaylistListItem, PreviousSongResponse,
+            RenameGameRequest, ReorderSongsRequest, ReorderTeamsRequest, RequiredFieldsRequest,
+            RoundDelta, ScoreAdjustmentRequest, ScoreUndoRequest, ScoreUpdateResponse,
+            ScoresVisibilityRequest, SongFieldStatus, StartGameResponse, StartPairingRequest,
+            StopGameResponse, UpdateTeamRequest,
         },
         game::{
-            CreateGameWithPlaylistRequest, GameSummary, PlaylistInput, PlaylistSummary,
-            SongSummary, TeamInput, TeamSummary,
+            CreateGameWithPlaylistRequest, GameSummary, PlaylistInput, PlaylistSummary, SongInput,
+            SongSummary, TeamBriefSummary, TeamInput, TeamSummary, rank_teams,
         },
+        public::TeamsResponse,
+        sse::AnswerCountdownEvent,
+        validation::validate_buzzer_id,
+        ws::BuzzerPattern,
     },
     error::ServiceError,
     services::{
         game_service,
         pairing::{PairingSessionUpdate, apply_pairing_update, handle_pairing_progress},
-        sse_events,
-        websocket_service::send_pattern_to_team_buzzer,
+        playlist_csv, sse_events,
+        websocket_service::{self, send_pattern_to_team_buzzer},
     },
     state::{
         SharedState,
-        game::{GameSession, PointField},
+        game::{GameSession, PointField, Song, Team, TeamColor},
         state_machine::{
             FinishReason, GameEvent, GamePhase, GameRunningPhase, PairingSession, PauseKind,
             PrepStatus,
         },
+        timers::TimerKind,
         transitions::run_transition_with_broadcast,
     },
 };
 
+/// How long a buzzer flashes the `identify` pattern before returning to its previous pattern.
+const IDENTIFY_DURATION: Duration = Duration::from_secs(3);
+
 async fn ensure_prep_phase(state: &SharedState) -> Result<PrepStatus, ServiceError> {
     match state.state_machine_phase().await {
         GamePhase::GameRunning(GameRunningPhase::Prep(status)) => Ok(status),
@@ -47,19 +68,21 @@ async fn ensure_prep_phase(state: &SharedState) -> Result<PrepStatus, ServiceErr
     }
 }
 
-fn assert_unique_buzzer(
+fn assert_unique_buzzers(
     game: &GameSession,
     exclude: Option<Uuid>,
-    buzzer_id: &str,
+    buzzer_ids: &[String],
 ) -> Result<(), ServiceError> {
-    if game
-        .teams
-        .iter()
-        .any(|(id, team)| team.buzzer_id.as_deref() == Some(buzzer_id) && Some(*id) != exclude)
-    {
-        return Err(ServiceError::InvalidInput(format!(
-            "duplicate buzzer id `{buzzer_id}` detected"
-        )));
+    for buzzer_id in buzzer_ids {
+        if game
+            .teams
+            .iter()
+            .any(|(id, team)| team.has_buzzer(buzzer_id) && Some(*id) != exclude)
+        {
+            return Err(ServiceError::InvalidInput(format!(
+                "duplicate buzzer id `{buzzer_id}` detected"
+            )));
+        }
     }
     Ok(())
 }
@@ -80,12 +103,20 @@ fn ensure_running_phase(phase: GamePhase) -> Result<GameRunningPhase, ServiceErr
 // ---------------------------------------------------------------------------
 
 /// List all games from storage with their basic information.
-pub async fn list_games(state: &SharedState) -> Result<Vec<GameListItem>, ServiceError> {
+pub async fn list_games(
+    state: &SharedState,
+    query: ListGamesQuery,
+) -> Result<Vec<GameListItem>, ServiceError> {
     let store = state.require_game_store().await?;
     let game_entities = store.list_games().await?;
 
     let mut games_list = Vec::with_capacity(game_entities.len());
     for game in game_entities {
+        if query.finished_reason.is_some()
+            && game.finish_reason.map(GameFinishReason::from) != query.finished_reason
+        {
+            continue;
+        }
         let playlist = store
             .find_playlist(game.playlist_id)
             .await?
@@ -99,7 +130,34 @@ pub async fn list_games(state: &SharedState) -> Result<Vec<GameListItem>, Servic
 }
 
 /// Retrieve a specific game by ID from storage.
+///
+/// While the storage is unavailable, the active game is still served from memory (see
+/// [`degraded_fallback`]).
 pub async fn get_game_by_id(state: &SharedState, id: Uuid) -> Result<GameSummary, ServiceError> {
+    match stored_game_summary(state, id).await {
+        Err(err) => degraded_fallback(state, id, |game| game.clone().into())
+            .await
+            .ok_or(err),
+        summary => summary,
+    }
+}
+
+/// Project the active game when it is the game `id`, the backend is degraded and
+/// `degraded_read_fallback` lets reads fall back on the in-memory session.
+async fn degraded_fallback<T>(
+    state: &SharedState,
+    id: Uuid,
+    project: impl FnOnce(&GameSession) -> T,
+) -> Option<T> {
+    if !state.config().degraded_read_fallback() || !state.is_degraded().await {
+        return None;
+    }
+    state
+        .read_current_game(|game| game.filter(|game| game.id == id).map(project))
+        .await
+}
+
+async fn stored_game_summary(state: &SharedState, id: Uuid) -> Result<GameSummary, ServiceError> {
     let store = state.require_game_store().await?;
 
     let Some(game) = store.find_game(id).await? else {
@@ -118,6 +176,110 @@ pub async fn get_game_by_id(state: &SharedState, id: Uuid) -> Result<GameSummary
     Ok(game_session.into())
 }
 
+/// Assemble a game and its playlist into a document that can be archived and imported back.
+///
+/// The active game is exported from memory so that changes not yet persisted are included.
+pub async fn export_game(state: &SharedState, id: Uuid) -> Result<ExportedGame, ServiceError> {
+    let active = state
+        .read_current_game(|game| {
+            game.filter(|game| game.id == id).map(|game| {
+                (
+                    game.to_entity(),
+                    PlaylistEntity::from(game.playlist.clone()),
+                )
+            })
+        })
+        .await;
+    if let Some(exported) = active {
+        return Ok(exported.into());
+    }
+
+    let game = game_service::find_game(state, id).await?;
+    let store = state.require_game_store().await?;
+    let playlist = store
+        .find_playlist(game.playlist_id)
+        .await?
+        .ok_or_else(|| {
+            ServiceError::NotFound(format!("playlist {} not found", game.playlist_id))
+        })?;
+    Ok((game, playlist).into())
+}
+
+/// Persist a game exported by [`export_game`] along with its playlist.
+pub async fn import_game(
+    state: &SharedState,
+    exported: ExportedGame,
+    preserve_ids: bool,
+) -> Result<GameSummary, ServiceError> {
+    game_service::import_game(state, exported, preserve_ids).await
+}
+
+/// Store a pristine copy of a game, see [`game_service::duplicate_game`].
+pub async fn duplicate_game(
+    state: &SharedState,
+    id: Uuid,
+    copy_playlist: bool,
+) -> Result<GameSummary, ServiceError> {
+    game_service::duplicate_game(state, id, copy_playlist).await
+}
+
+/// Sum the final scores of every finished stored game per team, best total first.
+///
+/// Teams get fresh ids in every game, so they are matched following the configured
+/// [`LeaderboardKey`] instead.
+pub async fn leaderboard(state: &SharedState) -> Result<Vec<LeaderboardEntry>, ServiceError> {
+    let store = state.require_game_store().await?;
+    let key = state.config().leaderboard_key();
+
+    let mut games = Vec::new();
+    for item in store.list_games().await? {
+        if let Some(game) = store.find_game(item.id).await?
+            && game.finished
+        {
+            games.push(game);
+        }
+    }
+    // The latest spelling of a name wins.
+    games.sort_by_key(|game| game.updated_at);
+
+    let mut standings: IndexMap<String, LeaderboardEntry> = IndexMap::new();
+    for team in games.into_iter().flat_map(|game| game.teams) {
+        let buzzer = match key {
+            LeaderboardKey::Name => None,
+            LeaderboardKey::Buzzer => team.buzzer_ids.first(),
+        };
+        let standing_key = match buzzer {
+            Some(buzzer_id) => format!("buzzer:{buzzer_id}"),
+            None => format!(
+                "name:{}",
+                team.name
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase()
+            ),
+        };
+        let entry = standings
+            .entry(standing_key)
+            .or_insert_with(|| LeaderboardEntry {
+                name: String::new(),
+                total_points: 0,
+                games_played: 0,
+            });
+        entry.name = team.name.trim().to_string();
+        entry.total_points += i64::from(team.score);
+        entry.games_played += 1;
+    }
+
+    let mut entries: Vec<_> = standings.into_values().collect();
+    entries.sort_by(|a, b| {
+        b.total_points
+            .cmp(&a.total_points)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(entries)
+}
+
 /// Return the playlists that can seed new games.
 pub async fn list_playlists(state: &SharedState) -> Result<Vec<PlaylistListItem>, ServiceError> {
     let store = state.require_game_store().await?;
@@ -128,6 +290,129 @@ pub async fn list_playlists(state: &SharedState) -> Result<Vec<PlaylistListItem>
         .collect())
 }
 
+/// Return the per-song analytics accumulated for a playlist.
+pub async fn get_playlist_analytics(
+    state: &SharedState,
+    id: Uuid,
+) -> Result<PlaylistAnalyticsResponse, ServiceError> {
+    let store = state.require_game_store().await?;
+
+    if store.find_playlist(id).await?.is_none() {
+        return Err(ServiceError::NotFound(format!("playlist `{id}` not found")));
+    }
+
+    let analytics = store
+        .find_playlist_analytics(id)
+        .await?
+        .unwrap_or_else(|| PlaylistAnalyticsEntity::new(id));
+
+    Ok(analytics.into())
+}
+
+/// Flag the given fields of a playlist song as required for progression, unflagging the others.
+///
+/// The change is persisted on the playlist and mirrored into the active game when it uses it.
+pub async fn set_required_fields(
+    state: &SharedState,
+    playlist_id: Uuid,
+    song_id: u32,
+    request: RequiredFieldsRequest,
+) -> Result<SongSummary, ServiceError> {
+    let store = state.require_game_store().await?;
+    let mut playlist = store
+        .find_playlist(playlist_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("playlist `{playlist_id}` not found")))?;
+    let song = playlist
+        .songs
+        .get_mut(song_id as usize)
+        .ok_or_else(|| ServiceError::NotFound(format!("song `{song_id}` not found")))?;
+
+    if let Some(unknown) = request.fields.iter().find(|key| {
+        !song
+            .point_fields
+            .iter()
+            .chain(&song.bonus_fields)
+            .any(|field| &field.key == *key)
+    }) {
+        return Err(ServiceError::InvalidInput(format!(
+            "field `{unknown}` is not part of song `{song_id}`"
+        )));
+    }
+
+    for field in song.point_fields.iter_mut().chain(&mut song.bonus_fields) {
+        field.required = request.fields.contains(&field.key);
+    }
+    let song: Song = song.clone().into();
+    store.save_playlist(playlist).await?;
+
+    state
+        .with_current_game_slot_mut(|slot| {
+            if let Some(game) = slot.as_mut().filter(|game| game.playlist.id == playlist_id) {
+                game.playlist.songs.insert(song_id, song.clone());
+            }
+        })
+        .await;
+
+    Ok((song_id, song).into())
+}
+
+/// Return the configuration currently loaded by the server.
+pub fn get_config(state: &SharedState) -> ConfigResponse {
+    ConfigResponse::from(state.config().as_ref())
+}
+
+/// Persist every pending debounced write right away and report how many were flushed.
+pub async fn flush_storage(state: &SharedState) -> Result<FlushResponse, ServiceError> {
+    state.require_game_store().await?;
+    let report = state.flush_all_pending().await;
+    Ok(FlushResponse {
+        flushed: report.flushed,
+        failed: report.failed,
+    })
+}
+
+/// Resolve the buzzer pattern the configuration produces for a preset and optional color.
+pub fn preview_pattern(
+    state: &SharedState,
+    query: PatternPreviewQuery,
+) -> Result<BuzzerPattern, ServiceError> {
+    let preset = build_pattern_preset(query)?;
+    Ok(state.config().buzzer_pattern(preset))
+}
+
+/// Build the [`BuzzerPatternPreset`] described by a preview query, requiring a color for the
+/// presets that carry one.
+fn build_pattern_preset(query: PatternPreviewQuery) -> Result<BuzzerPatternPreset, ServiceError> {
+    let color = match (query.h, query.s, query.v) {
+        (Some(h), Some(s), Some(v)) => Some(TeamColor { h, s, v }),
+        (None, None, None) => None,
+        _ => {
+            return Err(ServiceError::InvalidInput(
+                "color components h, s and v must be provided together".into(),
+            ));
+        }
+    };
+    let require_color = || {
+        color.clone().ok_or_else(|| {
+            ServiceError::InvalidInput(format!(
+                "preset `{:?}` requires a color (h, s, v)",
+                query.preset
+            ))
+        })
+    };
+
+    Ok(match query.preset {
+        PatternPresetName::WaitingForPairing => BuzzerPatternPreset::WaitingForPairing,
+        PatternPresetName::Standby => BuzzerPatternPreset::Standby(require_color()?),
+        PatternPresetName::Playing => BuzzerPatternPreset::Playing(require_color()?),
+        PatternPresetName::Answering => BuzzerPatternPreset::Answering(require_color()?),
+        PatternPresetName::Waiting => BuzzerPatternPreset::Waiting,
+        PatternPresetName::Correct => BuzzerPatternPreset::Correct(require_color()?),
+        PatternPresetName::Identify => BuzzerPatternPreset::Identify,
+    })
+}
+
 /// Delete a game from storage by ID. Cannot delete a currently running game.
 pub async fn delete_game(state: &SharedState, id: Uuid) -> Result<(), ServiceError> {
     let current_game_id = state.read_current_game(|game| game.map(|g| g.id)).await;
@@ -155,6 +440,64 @@ pub async fn delete_game(state: &SharedState, id: Uuid) -> Result<(), ServiceErr
     }
 }
 
+/// Rename a game: the active game is renamed live (see [`rename_game`]), a stored one is
+/// updated in storage.
+pub async fn update_game(
+    state: &SharedState,
+    id: Uuid,
+    request: RenameGameRequest,
+) -> Result<GameSummary, ServiceError> {
+    let active = state
+        .read_current_game(|game| game.is_some_and(|game| game.id == id))
+        .await;
+    if active {
+        return rename_game(state, request).await;
+    }
+
+    let (name, playlist_name) = checked_new_names(request)?;
+    let store = state.require_game_store().await?;
+    let mut game = store
+        .find_game(id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("game `{id}` not found")))?;
+    if let Some(playlist_name) = playlist_name {
+        game_service::rename_playlist(state, game.playlist_id, playlist_name).await?;
+    }
+    if let Some(name) = name {
+        game.name = name;
+        game.updated_at = std::time::SystemTime::now();
+        store.save_game_without_teams(game).await?;
+    }
+
+    stored_game_summary(state, id).await
+}
+
+/// Trim the new game and playlist names, rejecting blank ones and requests renaming nothing.
+fn checked_new_names(
+    request: RenameGameRequest,
+) -> Result<(Option<String>, Option<String>), ServiceError> {
+    let checked = |name: Option<String>, what: &str| {
+        name.map(|name| {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(ServiceError::InvalidInput(format!(
+                    "{what} name must not be empty"
+                )));
+            }
+            Ok(name.to_string())
+        })
+        .transpose()
+    };
+    let name = checked(request.name, "game")?;
+    let playlist_name = checked(request.playlist_name, "playlist")?;
+    if name.is_none() && playlist_name.is_none() {
+        return Err(ServiceError::InvalidInput(
+            "`name` or `playlist_name` must be provided".into(),
+        ));
+    }
+    Ok((name, playlist_name))
+}
+
 /// Create and persist a reusable playlist definition on behalf of admins.
 pub async fn create_playlist(
     state: &SharedState,
@@ -164,27 +507,266 @@ pub async fn create_playlist(
     Ok(summary)
 }
 
+/// Replace the name and songs of a stored playlist.
+pub async fn update_playlist(
+    state: &SharedState,
+    id: Uuid,
+    request: PlaylistInput,
+) -> Result<PlaylistSummary, ServiceError> {
+    game_service::update_playlist(state, id, request).await
+}
+
+/// Append a song to a stored playlist.
+pub async fn add_song(
+    state: &SharedState,
+    id: Uuid,
+    song: SongInput,
+) -> Result<PlaylistSummary, ServiceError> {
+    game_service::add_song(state, id, song).await
+}
+
+/// Remove a song from a stored playlist.
+pub async fn remove_song(
+    state: &SharedState,
+    id: Uuid,
+    song_id: u32,
+) -> Result<PlaylistSummary, ServiceError> {
+    game_service::remove_song(state, id, song_id).await
+}
+
+/// Set the order in which new games play the songs of a stored playlist.
+pub async fn reorder_playlist(
+    state: &SharedState,
+    id: Uuid,
+    request: ReorderSongsRequest,
+) -> Result<PlaylistSummary, ServiceError> {
+    game_service::reorder_playlist(state, id, request.song_ids).await
+}
+
+/// Delete a stored playlist that no stored game references.
+pub async fn delete_playlist(state: &SharedState, id: Uuid) -> Result<(), ServiceError> {
+    game_service::delete_playlist(state, id).await
+}
+
+/// Create a playlist from a CSV song list, see [`playlist_csv`] for the column contract.
+pub async fn import_playlist_csv(
+    state: &SharedState,
+    name: String,
+    csv: &str,
+) -> Result<PlaylistSummary, ServiceError> {
+    let songs = playlist_csv::parse_songs(csv).map_err(ServiceError::InvalidCsv)?;
+    create_playlist(state, PlaylistInput { name, songs }).await
+}
+
+/// Look up the team a buzzer is paired with in the active game, if any.
+pub async fn buzzer_assignment(
+    state: &SharedState,
+    buzzer_id: &str,
+) -> Result<Option<BuzzerAssignmentResponse>, ServiceError> {
+    validate_buzzer_id(buzzer_id)
+        .map_err(|err| ServiceError::InvalidInput(format!("invalid buzzer id: {err}")))?;
+    let team = state
+        .with_current_game(|game| {
+            Ok(game
+                .teams
+                .iter()
+                .find(|(_, team)| team.has_buzzer(buzzer_id))
+                .map(|(id, team)| TeamBriefSummary {
+                    id: *id,
+                    name: team.name.clone(),
+                }))
+        })
+        .await?;
+    Ok(team.map(|team| BuzzerAssignmentResponse {
+        team,
+        connected: state.buzzers().contains_key(buzzer_id),
+    }))
+}
+
+/// List the connected buzzers that no team of the active game is paired with, sorted by id.
+///
+/// Without an active game, every connected buzzer is unassigned.
+pub async fn unassigned_buzzers(state: &SharedState) -> Vec<String> {
+    let mut unassigned: Vec<String> = state
+        .buzzers()
+        .iter()
+        .map(|entry| entry.key().clone())
+        .collect();
+    state
+        .read_current_game(|game| {
+            if let Some(game) = game {
+                unassigned.retain(|id| !game.teams.values().any(|team| team.has_buzzer(id)));
+            }
+        })
+        .await;
+    unassigned.sort();
+    unassigned
+}
+
+/// List the connected buzzers sorted by id, with the team each one is paired with in the active
+/// game and the last pattern it was sent.
+pub async fn connected_buzzers(state: &SharedState) -> Vec<ConnectedBuzzer> {
+    let mut ids: Vec<String> = state
+        .buzzers()
+        .iter()
+        .map(|entry| entry.key().clone())
+        .collect();
+    ids.sort();
+    let teams: Vec<Option<TeamBriefSummary>> = state
+        .read_current_game(|game| {
+            ids.iter()
+                .map(|id| {
+                    game?
+                        .teams
+                        .iter()
+                        .find(|(_, team)| team.has_buzzer(id))
+                        .map(|(team_id, team)| TeamBriefSummary {
+                            id: *team_id,
+                            name: team.name.clone(),
+                        })
+                })
+                .collect()
+        })
+        .await;
+    let config = state.config();
+    ids.into_iter()
+        .zip(teams)
+        .map(|(id, team)| ConnectedBuzzer {
+            last_pattern: state
+                .buzzer_last_patterns()
+                .peek(&id)
+                .map(|preset| config.buzzer_pattern(preset)),
+            id,
+            team,
+        })
+        .collect()
+}
+
+/// Flash a connected buzzer for a few seconds so the GM can spot it, whatever the phase.
+pub fn identify_buzzer(
+    state: &SharedState,
+    buzzer_id: &str,
+) -> Result<ActionResponse, ServiceError> {
+    validate_buzzer_id(buzzer_id)
+        .map_err(|err| ServiceError::InvalidInput(format!("invalid buzzer id: {err}")))?;
+    websocket_service::identify_buzzer(state, buzzer_id, IDENTIFY_DURATION)?;
+    Ok(ActionResponse {
+        message: format!("buzzer {buzzer_id} identifying"),
+    })
+}
+
+/// Force-disconnect a connected buzzer, e.g. one sending spurious buzzes.
+///
+/// Its last pattern is forgotten so it is not restored on reconnection. With `unpair`, the buzzer
+/// is also removed from its team in the active game, which is refused during active pairing.
+pub async fn kick_buzzer(
+    state: &SharedState,
+    buzzer_id: &str,
+    unpair: bool,
+) -> Result<ActionResponse, ServiceError> {
+    validate_buzzer_id(buzzer_id)
+        .map_err(|err| ServiceError::InvalidInput(format!("invalid buzzer id: {err}")))?;
+    if unpair && state.pairing_session().await.is_some() {
+        return Err(ServiceError::InvalidState(
+            "cannot modify teams during active pairing".into(),
+        ));
+    }
+    if !state.kick_buzzer(buzzer_id) {
+        return Err(ServiceError::NotFound(format!(
+            "buzzer `{buzzer_id}` is not connected"
+        )));
+    }
+    info!(buzzer_id, unpair, "buzzer kicked by admin");
+
+    if unpair {
+        let unpaired = state
+            .with_current_game_slot_mut(|slot| {
+                let game = slot.as_mut()?;
+                let (team_id, team) = game
+                    .teams
+                    .iter_mut()
+                    .find(|(_, team)| team.has_buzzer(buzzer_id))?;
+                team.buzzer_ids.retain(|id| id != buzzer_id);
+                team.updated_at = std::time::SystemTime::now();
+                Some((game.id, *team_id, team.clone()))
+            })
+            .await;
+        if let Some((game_id, team_id, team)) = unpaired {
+            state.persist_team(game_id, team_id, team.clone()).await?;
+            sse_events::broadcast_team_updated(state, TeamSummary::from((team_id, team)));
+        }
+    }
+
+    Ok(ActionResponse {
+        message: format!("buzzer {buzzer_id} kicked"),
+    })
+}
+
+/// Build the GM answer sheet of the current song, or `None` when no song is current.
+pub async fn current_song_fields(
+    state: &SharedState,
+) -> Result<Option<CurrentSongFieldsResponse>, ServiceError> {
+    state
+        .with_current_game(|game| {
+            let Some((song_id, song)) = game.current_song_index.and_then(|idx| game.get_song(idx))
+            else {
+                return Ok(None);
+            };
+            let statuses = |fields: Vec<PointField>, found: &[String]| {
+                fields
+                    .into_iter()
+                    .map(|field| SongFieldStatus {
+                        found: found.contains(&field.key),
+                        key: field.key,
+                        value: field.value,
+                        points: field.points,
+                        required: field.required,
+                    })
+                    .collect()
+            };
+            Ok(Some(CurrentSongFieldsResponse {
+                song_id,
+                point_fields: statuses(song.point_fields, &game.found_point_fields),
+                bonus_fields: statuses(song.bonus_fields, &game.found_bonus_fields),
+            }))
+        })
+        .await
+}
+
 // ---------------------------------------------------------------------------
 // Game bootstrap / lifecycle operations
 // ---------------------------------------------------------------------------
 
 /// Load a persisted game, apply the appropriate SSE event and return the summary.
+///
+/// Finished games land directly in `ShowScores` and re-broadcast their final standings.
 pub async fn load_game(
     state: &SharedState,
     id: Uuid,
-    shuffle_playlist: bool,
+    shuffle_seed: Option<u64>,
 ) -> Result<GameSummary, ServiceError> {
-    run_transition_with_broadcast(state, GameEvent::StartGame, move || async move {
-        game_service::load_game(state, id, shuffle_playlist).await
+    let game = game_service::find_game(state, id).await?;
+    let finished = game.finished;
+    let event = if finished {
+        GameEvent::ReloadFinished
+    } else {
+        GameEvent::StartGame
+    };
+    let session = run_transition_with_broadcast(state, event, move || async move {
+        game_service::load_game(state, game, shuffle_seed).await
     })
-    .await
+    .await?;
+    if finished {
+        sse_events::broadcast_game_finished(state, &session);
+    }
+    Ok(session.into())
 }
 
 /// Create a new game definition on behalf of admins.
 pub async fn create_game(
     state: &SharedState,
     request: CreateGameWithPlaylistRequest,
-    shuffle_playlist: bool,
+    shuffle_seed: Option<u64>,
 ) -> Result<GameSummary, ServiceError> {
     run_transition_with_broadcast(state, GameEvent::StartGame, move || async move {
         let (_playlist_summary, playlist_model) =
@@ -195,7 +777,11 @@ pub async fn create_game(
             request.teams,
             playlist_model.id,
             Some(playlist_model),
-            shuffle_playlist,
+            game_service::GameOptions {
+                shuffle_seed,
+                enforce_required_fields: request.enforce_required_fields,
+                tags: request.tags,
+            },
         )
         .await
     })
@@ -206,7 +792,7 @@ pub async fn create_game(
 pub async fn create_game_from_playlist(
     state: &SharedState,
     request: CreateGameRequest,
-    shuffle_playlist: bool,
+    shuffle_seed: Option<u64>,
 ) -> Result<GameSummary, ServiceError> {
     run_transition_with_broadcast(state, GameEvent::StartGame, move || async move {
         game_service::create_game(
@@ -215,7 +801,11 @@ pub async fn create_game_from_playlist(
             request.teams,
             request.playlist_id,
             None,
-            shuffle_playlist,
+            game_service::GameOptions {
+                shuffle_seed,
+                enforce_required_fields: request.enforce_required_fields,
+                tags: request.tags,
+            },
         )
         .await
     })
@@ -241,14 +831,11 @@ pub async fn start_game(state: &SharedState) -> Result<StartGameResponse, Servic
                     ));
                 }
 
-                if !state.buzzers().iter().all(|r| {
-                    game.teams.iter().any(|(_, t)| {
-                        t.buzzer_id
-                            .as_ref()
-                            .map(|id| id == r.key())
-                            .unwrap_or(false)
-                    })
-                }) {
+                if !state
+                    .buzzers()
+                    .iter()
+                    .all(|r| game.teams.values().any(|t| t.has_buzzer(r.key())))
+                {
                     warn!("Some buzzers are not paired to any team while starting the game");
                 }
 
@@ -257,10 +844,16 @@ pub async fn start_game(state: &SharedState) -> Result<StartGameResponse, Servic
             .await?;
     }
 
-    let song_summary = load_next_song(state, true)
+    let song_summary = load_next_song(state, SongAdvance::Start)
         .await?
         .expect("Error during game start: no song found in playlist after transitionning the state (should not happen)");
-    Ok(StartGameResponse { song: song_summary })
+    let shuffle_seed = state
+        .with_current_game(|game| Ok(game.shuffle_seed))
+        .await?;
+    Ok(StartGameResponse {
+        song: song_summary,
+        shuffle_seed,
+    })
 }
 
 /// Pause gameplay manually through the admin controls.
@@ -275,8 +868,12 @@ pub async fn pause_game(state: &SharedState) -> Result<ActionResponse, ServiceEr
         },
     )
     .await?;
+    if state.config().pause_freezes_timers() {
+        state.timers().suspend_all();
+    }
     state
-        .with_current_game(|game| {
+        .with_current_game_mut(|game| {
+            game.song_stats.stop_playing();
             game.teams.iter().for_each(|(team_id, team)| {
                 send_pattern_to_team_buzzer(state, team_id, team, BuzzerPatternPreset::Waiting)
             });
@@ -295,8 +892,11 @@ pub async fn resume_game(state: &SharedState) -> Result<ActionResponse, ServiceE
             })
         })
         .await?;
+    state.timers().cancel(TimerKind::AnswerCountdown);
+    state.timers().resume_all();
     state
-        .with_current_game(|game| {
+        .with_current_game_mut(|game| {
+            game.song_stats.start_playing();
             game.teams.iter().for_each(|(team_id, team)| {
                 send_pattern_to_team_buzzer(
                     state,
@@ -311,54 +911,333 @@ pub async fn resume_game(state: &SharedState) -> Result<ActionResponse, ServiceE
     Ok(result)
 }
 
-/// Reveal the current song and conclude any outstanding buzz sequence.
-pub async fn reveal(state: &SharedState) -> Result<ActionResponse, ServiceError> {
-    let result = run_transition_with_broadcast(state, GameEvent::Reveal, move || async move {
-        state
-            .with_current_game_mut(|game| {
-                game.current_song_found = true;
-                game.updated_at = SystemTime::now();
-                Ok(())
-            })
-            .await?;
+/// Reveal the current song automatically once its guess time is over; `0` disables it.
+///
+/// The timer is frozen while the game is paused and resumes with the time that was left.
+fn schedule_auto_reveal(state: &SharedState, guess_duration_ms: usize) {
+    if guess_duration_ms == 0 {
+        state.timers().cancel(TimerKind::AutoReveal);
+        return;
+    }
+    let reveal_state = Arc::clone(state);
+    state.timers().schedule(
+        TimerKind::AutoReveal,
+        Duration::from_millis(guess_duration_ms as u64),
+        Arc::new(move || {
+            let state = Arc::clone(&reveal_state);
+            Box::pin(async move { auto_reveal(&state).await })
+        }),
+    );
+}
 
-        state.persist_current_game_without_teams().await?;
+/// Buzz on behalf of a team while the song plays, going through the same flow as a hardware
+/// buzz: the game pauses on the team's buzzer and the buzzer patterns are updated.
+pub async fn manual_buzz(
+    state: &SharedState,
+    request: ManualBuzzRequest,
+) -> Result<ActionResponse, ServiceError> {
+    if state.state_machine_phase().await != GamePhase::GameRunning(GameRunningPhase::Playing) {
+        return Err(ServiceError::InvalidState(
+            "buzzes can only be simulated while a song is playing".into(),
+        ));
+    }
+    websocket_service::simulate_team_buzz(state, request.team_id).await?;
+    Ok(ActionResponse {
+        message: "buzzed".into(),
+    })
+}
 
-        Ok(ActionResponse {
-            message: "revealed".into(),
-        })
+/// Give the turn to the next team in the buzz queue after the answering team, typically once
+/// its answer was wrong.
+pub async fn next_queued_buzz(state: &SharedState) -> Result<ActionResponse, ServiceError> {
+    if !matches!(
+        state.state_machine_phase().await,
+        GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz { .. }))
+    ) {
+        return Err(ServiceError::InvalidState(
+            "the turn can only be handed over while a team is answering".into(),
+        ));
+    }
+    let buzzer_id = websocket_service::give_turn_to_next_buzz(state).await?;
+    Ok(ActionResponse {
+        message: format!("turn given to buzzer {buzzer_id}"),
     })
-    .await?;
-    state
-        .with_current_game(|game| {
-            game.teams.iter().for_each(|(team_id, team)| {
-                send_pattern_to_team_buzzer(
-                    state,
-                    team_id,
-                    team,
-                    BuzzerPatternPreset::Standby(team.color.clone()),
-                )
-            });
-            Ok(())
-        })
-        .await?;
-    Ok(result)
 }
 
-/// Advance to the next song or finish the playlist when exhausted.
-pub async fn next_song(state: &SharedState) -> Result<NextSongResponse, ServiceError> {
-    let next_song_summary = load_next_song(state, false).await?;
-    let response = NextSongResponse {
+/// Reveal the current song when its guess time ran out while it was still playing.
+async fn auto_reveal(state: &SharedState) {
+    if state.state_machine_phase().await != GamePhase::GameRunning(GameRunningPhase::Playing) {
+        return;
+    }
+    if let Err(err) = reveal(state).await {
+        warn!(error = %err, "automatic reveal failed");
+    }
+}
+
+/// Start the countdown given to the team that just buzzed to answer, when configured.
+pub fn start_answer_countdown(state: &SharedState, team_id: Uuid) {
+    if let Some(countdown) = state.config().answer_countdown() {
+        schedule_answer_countdown(
+            state,
+            team_id,
+            Duration::from_millis(countdown.duration_ms),
+            countdown.on_expiry,
+        );
+    }
+}
+
+fn schedule_answer_countdown(
+    state: &SharedState,
+    team_id: Uuid,
+    duration: Duration,
+    on_expiry: CountdownExpiry,
+) {
+    let expiry_state = Arc::clone(state);
+    state.timers().schedule(
+        TimerKind::AnswerCountdown,
+        duration,
+        Arc::new(move || {
+            let state = Arc::clone(&expiry_state);
+            Box::pin(async move { expire_answer_countdown(&state, on_expiry).await })
+        }),
+    );
+    sse_events::broadcast_answer_countdown(
+        state,
+        &AnswerCountdownEvent::new(team_id, duration, on_expiry),
+    );
+}
+
+/// Apply the configured expiry action once the buzzing team ran out of time.
+async fn expire_answer_countdown(state: &SharedState, on_expiry: CountdownExpiry) {
+    let result = match on_expiry {
+        CountdownExpiry::Resume => resume_game(state).await,
+        CountdownExpiry::Reveal => reveal(state).await,
+    };
+    if let Err(err) = result {
+        warn!(error = %err, ?on_expiry, "answer countdown expiry action failed");
+    }
+}
+
+/// Reveal the current song and conclude any outstanding buzz sequence.
+pub async fn reveal(state: &SharedState) -> Result<ActionResponse, ServiceError> {
+    ensure_required_fields_found(state).await?;
+    let (result, song_analytics) =
+        run_transition_with_broadcast(state, GameEvent::Reveal, move || async move {
+            let song_analytics = state
+                .with_current_game_mut(|game| {
+                    game.current_song_found = true;
+                    game.updated_at = SystemTime::now();
+                    let song_id = game
+                        .current_song_index
+                        .and_then(|index| game.playlist_song_order.get(index).copied());
+                    let stats = std::mem::take(&mut game.song_stats);
+                    Ok(song_id
+                        .and_then(|song_id| stats.into_analytics(song_id))
+                        .map(|analytics| (game.playlist.id, analytics)))
+                })
+                .await?;
+
+            state.persist_current_game_without_teams().await?;
+
+            Ok((
+                ActionResponse {
+                    message: "revealed".into(),
+                },
+                song_analytics,
+            ))
+        })
+        .await?;
+    state.timers().cancel(TimerKind::AnswerCountdown);
+    state.timers().cancel(TimerKind::AutoReveal);
+    if let Some((playlist_id, analytics)) = song_analytics {
+        record_song_analytics(state, playlist_id, analytics).await;
+    }
+    state
+        .with_current_game(|game| {
+            game.teams.iter().for_each(|(team_id, team)| {
+                send_pattern_to_team_buzzer(
+                    state,
+                    team_id,
+                    team,
+                    BuzzerPatternPreset::Standby(team.color.clone()),
+                )
+            });
+            Ok(())
+        })
+        .await?;
+    Ok(result)
+}
+
+/// Reveal the point fields of the current song while withholding its bonus fields.
+///
+/// Marks every point field as found and broadcasts the found fields, without leaving the
+/// playing or paused phase. A subsequent [`reveal`] completes the song.
+pub async fn reveal_partial(state: &SharedState) -> Result<FieldsFoundResponse, ServiceError> {
+    match state.state_machine_phase().await {
+        GamePhase::GameRunning(GameRunningPhase::Playing | GameRunningPhase::Paused(_)) => {}
+        other => {
+            return Err(ServiceError::InvalidState(format!(
+                "cannot partially reveal while in phase {other:?}"
+            )));
+        }
+    }
+
+    let response = state
+        .with_current_game_mut(|game| {
+            let index = game.current_song_index.ok_or_else(|| {
+                ServiceError::InvalidState("no active song: playlist is over".into())
+            })?;
+            let (song_id, song) = game
+                .get_song(index)
+                .ok_or_else(|| ServiceError::InvalidState("song not found in playlist".into()))?;
+            let missing: Vec<String> = song
+                .point_fields
+                .iter()
+                .map(|field| field.key.clone())
+                .filter(|key| !game.found_point_fields.contains(key))
+                .collect();
+            game.found_point_fields.extend(missing);
+            game.updated_at = SystemTime::now();
+
+            Ok(FieldsFoundResponse {
+                song_id,
+                point_fields: game.found_point_fields.clone(),
+                bonus_fields: game.found_bonus_fields.clone(),
+            })
+        })
+        .await?;
+
+    state.persist_current_game_without_teams().await?;
+
+    sse_events::broadcast_fields_found(
+        state,
+        response.song_id,
+        &response.point_fields,
+        &response.bonus_fields,
+    );
+
+    Ok(response)
+}
+
+/// Reject progression past the current song while the game enforces required fields and some
+/// of them are still to be found.
+async fn ensure_required_fields_found(state: &SharedState) -> Result<(), ServiceError> {
+    state.with_current_game(check_required_fields).await
+}
+
+fn check_required_fields(game: &GameSession) -> Result<(), ServiceError> {
+    if !game.enforce_required_fields {
+        return Ok(());
+    }
+    let outstanding = game.outstanding_required_fields();
+    if outstanding.is_empty() {
+        Ok(())
+    } else {
+        Err(ServiceError::InvalidState(format!(
+            "required fields not found yet: {}",
+            outstanding.join(", ")
+        )))
+    }
+}
+
+/// Fold the statistics of a resolved song into the playlist analytics, when enabled.
+///
+/// Failures are only logged: analytics must never prevent the game from progressing.
+async fn record_song_analytics(
+    state: &SharedState,
+    playlist_id: Uuid,
+    analytics: SongAnalyticsEntity,
+) {
+    if !state.config().song_analytics_enabled() {
+        return;
+    }
+
+    let result = async {
+        let store = state.require_game_store().await?;
+        let mut playlist_analytics = store
+            .find_playlist_analytics(playlist_id)
+            .await?
+            .unwrap_or_else(|| PlaylistAnalyticsEntity::new(playlist_id));
+        playlist_analytics.record(analytics);
+        store.save_playlist_analytics(playlist_analytics).await?;
+        Ok::<_, ServiceError>(())
+    }
+    .await;
+
+    if let Err(err) = result {
+        warn!(playlist_id = %playlist_id, error = %err, "failed to record song analytics");
+    }
+}
+
+/// Advance to the next song or finish the playlist when exhausted.
+pub async fn next_song(state: &SharedState) -> Result<NextSongResponse, ServiceError> {
+    ensure_required_fields_found(state).await?;
+    ensure_song_advance_gap(state)?;
+    let next_song_summary = load_next_song(state, SongAdvance::Next).await?;
+    state.record_song_advance();
+    let response = NextSongResponse {
         finished: next_song_summary.is_none(),
         song: next_song_summary,
     };
     Ok(response)
 }
 
+/// Move on to the next song without revealing the current one, finishing the playlist when
+/// exhausted.
+pub async fn skip_song(state: &SharedState) -> Result<NextSongResponse, ServiceError> {
+    ensure_song_advance_gap(state)?;
+    let next_song_summary = load_next_song(state, SongAdvance::Skip).await?;
+    state.record_song_advance();
+    // A skipped buzz no longer needs an answer.
+    state.timers().cancel(TimerKind::AnswerCountdown);
+    Ok(NextSongResponse {
+        finished: next_song_summary.is_none(),
+        song: next_song_summary,
+    })
+}
+
+/// Go back to the previous song, e.g. when the game master advanced by mistake.
+pub async fn previous_song(state: &SharedState) -> Result<PreviousSongResponse, ServiceError> {
+    ensure_song_advance_gap(state)?;
+    let song = load_next_song(state, SongAdvance::Previous)
+        .await?
+        .ok_or_else(|| ServiceError::InvalidState("no previous song".into()))?;
+    state.record_song_advance();
+    Ok(PreviousSongResponse { song })
+}
+
+/// Reject a song advance issued too soon after the previous one (e.g. a double-clicked "next").
+///
+/// Only successful advances start the gap, recorded with [`crate::state::AppState::record_song_advance`].
+fn ensure_song_advance_gap(state: &SharedState) -> Result<(), ServiceError> {
+    state
+        .check_song_advance(state.config().min_song_gap())
+        .map_err(|remaining| {
+            ServiceError::InvalidState(format!(
+                "song advanced too recently, retry in {} ms",
+                remaining.as_millis()
+            ))
+        })
+}
+
+/// How the game moves on to another song.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SongAdvance {
+    /// Start (or restart) the game on its current or first song.
+    Start,
+    /// Move on after the current song was revealed.
+    Next,
+    /// Move on without revealing the current song.
+    Skip,
+    /// Go back to the song before the current one.
+    Previous,
+}
+
 async fn load_next_song(
     state: &SharedState,
-    start: bool,
+    advance: SongAdvance,
 ) -> Result<Option<SongSummary>, ServiceError> {
+    let start = advance == SongAdvance::Start;
     let (current_song_index, playlist_length, current_song_found) = state
         .with_current_game(|game| {
             Ok((
@@ -368,7 +1247,16 @@ async fn load_next_song(
             ))
         })
         .await?;
-    let next_song_index: Option<usize> = if start && !current_song_found {
+    let next_song_index: Option<usize> = if advance == SongAdvance::Previous {
+        match current_song_index {
+            Some(index) if index > 0 => Some(index - 1),
+            _ => {
+                return Err(ServiceError::InvalidState(
+                    "already at the first song".into(),
+                ));
+            }
+        }
+    } else if start && !current_song_found {
         current_song_index.or(Some(0)) // "New Game +" if playlist was completed in the previous session
     } else {
         let next_song_index = current_song_index
@@ -382,43 +1270,45 @@ async fn load_next_song(
             None // Playlist completed
         }
     };
-    let event = if start {
-        GameEvent::GameConfigured
-    } else if next_song_index.is_some() {
-        GameEvent::NextSong
-    } else {
-        GameEvent::Finish(FinishReason::PlaylistCompleted)
+    let event = match (advance, next_song_index) {
+        (SongAdvance::Start, _) => GameEvent::GameConfigured,
+        (SongAdvance::Previous, _) => GameEvent::PreviousSong,
+        (_, None) => GameEvent::Finish(FinishReason::PlaylistCompleted),
+        (SongAdvance::Next, Some(_)) => GameEvent::NextSong,
+        (SongAdvance::Skip, Some(_)) => GameEvent::SkipSong,
     };
 
-    let result = run_transition_with_broadcast(state, event, move || async move {
-        let summary = state
-            .with_current_game_mut(|game| {
-                if game.current_song_index != next_song_index {
-                    game.found_point_fields.clear();
-                    game.found_bonus_fields.clear();
-                }
-                game.current_song_index = next_song_index;
-                game.current_song_found = false;
-                game.updated_at = SystemTime::now();
-
-                if let Some(index) = next_song_index {
-                    let (song_id, song) = game.get_song(index).ok_or_else(|| {
-                        ServiceError::InvalidState("song not found in playlist".into())
-                    })?;
-                    Ok(Some((song_id, song).into()))
-                } else {
-                    Ok(None)
-                }
-            })
-            .await?;
+    let result: Option<SongSummary> =
+        run_transition_with_broadcast(state, event, move || async move {
+            let summary = state
+                .with_current_game_mut(|game| {
+                    game.enter_song(next_song_index);
+                    game.finished = next_song_index.is_none();
+                    game.finish_reason = game.finished.then_some(FinishReason::PlaylistCompleted);
 
-        state.persist_current_game_without_teams().await?;
-        Ok(summary)
-    })
-    .await?;
+                    if let Some(index) = next_song_index {
+                        let (song_id, song) = game.get_song(index).ok_or_else(|| {
+                            ServiceError::InvalidState("song not found in playlist".into())
+                        })?;
+                        Ok(Some((song_id, song).into()))
+                    } else {
+                        Ok(None)
+                    }
+                })
+                .await?;
+
+            state.persist_current_game_without_teams().await?;
+            Ok(summary)
+        })
+        .await?;
+    match &result {
+        Some(song) => schedule_auto_reveal(state, song.guess_duration_ms),
+        None => state.timers().cancel(TimerKind::AutoReveal),
+    }
     if next_song_index.is_some() {
         state
-            .with_current_game(|game| {
+            .with_current_game_mut(|game| {
+                game.song_stats.start_playing();
                 game.teams.iter().for_each(|(team_id, team)| {
                     send_pattern_to_team_buzzer(
                         state,
@@ -436,24 +1326,30 @@ async fn load_next_song(
 
 /// Stop the running game early, capture standings, and persist them.
 pub async fn stop_game(state: &SharedState) -> Result<StopGameResponse, ServiceError> {
-    run_transition_with_broadcast(
+    let response = run_transition_with_broadcast(
         state,
         GameEvent::Finish(FinishReason::ManualStop),
         move || async move {
             let teams = state
-                .with_current_game(|game| {
-                    Ok(game
-                        .teams
-                        .iter()
-                        .map(|(id, team)| (*id, team.clone()))
-                        .map(Into::into)
-                        .collect())
+                .with_current_game_mut(|game| {
+                    game.finished = true;
+                    game.finish_reason = Some(FinishReason::ManualStop);
+                    Ok(rank_teams(
+                        game.teams
+                            .iter()
+                            .map(|(id, team)| (*id, team.clone()))
+                            .map(Into::into)
+                            .collect(),
+                    ))
                 })
                 .await?;
+            state.persist_current_game_without_teams().await?;
             Ok(StopGameResponse { teams })
         },
     )
-    .await
+    .await?;
+    state.timers().cancel_all();
+    Ok(response)
 }
 
 /// Clean up any remaining shared state after the game is complete.
@@ -465,11 +1361,13 @@ pub async fn end_game(state: &SharedState) -> Result<ActionResponse, ServiceErro
                 .read_current_game(|game| game.map(|g| g.teams.clone()).unwrap_or_default())
                 .await;
 
-            state
-                .with_current_game_slot_mut(|slot| {
-                    slot.take();
-                })
-                .await;
+            if !state.config().keep_game_after_end() {
+                state
+                    .with_current_game_slot_mut(|slot| {
+                        slot.take();
+                    })
+                    .await;
+            }
 
             Ok((
                 ActionResponse {
@@ -479,6 +1377,7 @@ pub async fn end_game(state: &SharedState) -> Result<ActionResponse, ServiceErro
             ))
         })
         .await?;
+    state.timers().cancel_all();
 
     // Send patterns only if transition succeeded
     for (team_id, team) in teams {
@@ -493,10 +1392,114 @@ pub async fn end_game(state: &SharedState) -> Result<ActionResponse, ServiceErro
     Ok(response)
 }
 
+/// Play the game kept in memory after `end_game` again, from its first song with reset scores.
+///
+/// The replay is stored as a new game, leaving the finished one and its scores untouched.
+pub async fn replay_game(state: &SharedState) -> Result<GameSummary, ServiceError> {
+    let session = run_transition_with_broadcast(state, GameEvent::StartGame, move || async move {
+        let ids = state.ids();
+        let session = state
+            .with_current_game_slot_mut(|slot| {
+                let game = slot.as_mut().ok_or_else(|| {
+                    ServiceError::InvalidState("no ended game kept in memory".into())
+                })?;
+                game.renew_ids(|| ids.next_id());
+                game.reset_progress();
+                Ok::<_, ServiceError>(game.clone())
+            })
+            .await?;
+        state.clear_game_state().await;
+        state.persist_current_game().await?;
+        sse_events::broadcast_game_session(state, &session);
+        Ok(session)
+    })
+    .await?;
+    Ok(session.into())
+}
+
 // ---------------------------------------------------------------------------
 // Gameplay adjustments that do not alter the state machine
 // ---------------------------------------------------------------------------
 
+/// Rename the active game and/or its playlist, persist them and broadcast the updated session.
+pub async fn rename_game(
+    state: &SharedState,
+    request: RenameGameRequest,
+) -> Result<GameSummary, ServiceError> {
+    let (name, playlist_name) = checked_new_names(request)?;
+    if let Some(playlist_name) = playlist_name {
+        let playlist_id = state.with_current_game(|game| Ok(game.playlist.id)).await?;
+        game_service::rename_playlist(state, playlist_id, playlist_name).await?;
+    }
+    let renamed = name.is_some();
+    let session = state
+        .with_current_game_mut(|game| {
+            if let Some(name) = name {
+                game.name = name;
+                game.updated_at = std::time::SystemTime::now();
+            }
+            Ok(game.clone())
+        })
+        .await?;
+
+    if renamed {
+        state.persist_current_game_without_teams().await?;
+    }
+    sse_events::broadcast_game_session(state, &session);
+
+    Ok(session.into())
+}
+
+/// Report how much each team's score moved since the current song became current.
+pub async fn round_deltas(state: &SharedState) -> Result<Vec<RoundDelta>, ServiceError> {
+    state
+        .with_current_game(|game| {
+            Ok(game
+                .round_deltas()
+                .into_iter()
+                .map(|(team_id, delta_this_song)| RoundDelta {
+                    team_id,
+                    delta_this_song,
+                })
+                .collect())
+        })
+        .await
+}
+
+/// Show or hide team scores from public clients and broadcast the new visibility.
+pub async fn set_scores_visibility(
+    state: &SharedState,
+    request: ScoresVisibilityRequest,
+) -> Result<ActionResponse, ServiceError> {
+    state.set_scores_visible(request.visible);
+    sse_events::broadcast_scores_visibility(state, request.visible);
+    let message = if request.visible {
+        "scores shown to public clients"
+    } else {
+        "scores hidden from public clients"
+    };
+    Ok(ActionResponse {
+        message: message.into(),
+    })
+}
+
+/// Force the degraded flag for an operational drill; clients are notified through the
+/// `system_status` event.
+pub async fn override_degraded(
+    state: &SharedState,
+    request: DegradedOverrideRequest,
+) -> Result<ActionResponse, ServiceError> {
+    state.override_degraded(request.degraded).await;
+    let message = if request.degraded {
+        "degraded mode forced until the next storage health transition"
+    } else {
+        "degraded mode cleared until the next storage health transition"
+    };
+    Ok(ActionResponse {
+        message: message.into(),
+    })
+}
+
 /// Register a discovered field and broadcast the updated state to clients.
 pub async fn mark_field_found(
     state: &SharedState,
@@ -514,10 +1517,35 @@ pub async fn mark_field_found(
         song_id,
         field_key,
         kind,
+        auto_score,
+        force,
     } = request;
+    let max_score_delta = state.config().max_score_delta();
+    // Separately scored bonus fields are left to `award_bonus`.
+    let auto_score = auto_score
+        && (matches!(kind, FieldKind::Point)
+            || state.config().bonus_scoring() == BonusScoring::WithAnswer);
 
-    let response = state
+    let (response, award) = state
         .with_current_game_mut(|game| {
+            // Points go to the team whose buzz paused the song.
+            let scorer = if auto_score {
+                match &running_phase {
+                    GameRunningPhase::Paused(PauseKind::Buzz { id }) => {
+                        Some(game.team_id_by_buzzer(id).ok_or_else(|| {
+                            ServiceError::InvalidState("buzzing team not found".into())
+                        })?)
+                    }
+                    _ => {
+                        return Err(ServiceError::InvalidState(
+                            "automatic scoring requires the game to be paused on a buzz".into(),
+                        ));
+                    }
+                }
+            } else {
+                None
+            };
+
             let index = game.current_song_index.ok_or_else(|| {
                 ServiceError::InvalidState("no active song: playlist is over".into())
             })?;
@@ -537,30 +1565,58 @@ pub async fn mark_field_found(
                 .get(&song_id)
                 .ok_or_else(|| ServiceError::InvalidState("song not found".into()))?;
 
-            match kind {
-                FieldKind::Point => {
-                    ensure_field_exists(&song.point_fields, &field_key)?;
-                    if !game.found_point_fields.contains(&field_key) {
-                        game.found_point_fields.push(field_key.clone());
-                    }
-                }
-                FieldKind::Bonus => {
-                    ensure_field_exists(&song.bonus_fields, &field_key)?;
-                    if !game.found_bonus_fields.contains(&field_key) {
-                        game.found_bonus_fields.push(field_key.clone());
-                    }
-                }
+            let (fields, found, scored) = match kind {
+                FieldKind::Point => (
+                    &song.point_fields,
+                    &mut game.found_point_fields,
+                    &mut game.scored_point_fields,
+                ),
+                FieldKind::Bonus => (
+                    &song.bonus_fields,
+                    &mut game.found_bonus_fields,
+                    &mut game.scored_bonus_fields,
+                ),
+            };
+            let points = i32::from(field_points(fields, &field_key)?);
+            // A field is scored once per song, here or by a later validated answer.
+            let newly_scored = scorer.is_some() && !scored.contains(&field_key);
+            if newly_scored {
+                check_score_delta(points, max_score_delta, force)?;
+                scored.insert(field_key.clone());
+            }
+            if !found.contains(&field_key) {
+                found.push(field_key.clone());
             }
 
-            Ok(FieldsFoundResponse {
-                song_id,
-                point_fields: game.found_point_fields.clone(),
-                bonus_fields: game.found_bonus_fields.clone(),
-            })
+            let award = match scorer {
+                Some(team_id) if newly_scored => {
+                    let team = game
+                        .teams
+                        .get_mut(&team_id)
+                        .ok_or_else(|| ServiceError::NotFound("team not found".into()))?;
+                    team.score += points;
+                    team.updated_at = SystemTime::now();
+                    Some((game.id, team_id, team.clone()))
+                }
+                _ => None,
+            };
+
+            Ok((
+                FieldsFoundResponse {
+                    song_id,
+                    point_fields: game.found_point_fields.clone(),
+                    bonus_fields: game.found_bonus_fields.clone(),
+                },
+                award,
+            ))
         })
         .await?;
 
     state.persist_current_game_without_teams().await?;
+    if let Some((game_id, team_id, team)) = award {
+        state.persist_team(game_id, team_id, team.clone()).await?;
+        sse_events::broadcast_score_adjustment(state, team_id, team);
+    }
 
     sse_events::broadcast_fields_found(
         state,
@@ -577,52 +1633,306 @@ pub async fn validate_answer(
     state: &SharedState,
     request: AnswerValidationRequest,
 ) -> Result<ActionResponse, ServiceError> {
-    match state.state_machine_phase().await {
-        GamePhase::GameRunning(GameRunningPhase::Paused(_)) => {
-            sse_events::broadcast_answer_validation(state, request.valid);
-            Ok(ActionResponse {
-                message: "answered".into(),
-            })
+    let pause_kind = match state.state_machine_phase().await {
+        GamePhase::GameRunning(GameRunningPhase::Paused(kind)) => kind,
+        other => {
+            return Err(ServiceError::InvalidState(format!(
+                "cannot validate answer while in phase {other:?}"
+            )));
         }
-        other => Err(ServiceError::InvalidState(format!(
-            "cannot validate answer while in phase {other:?}"
-        ))),
-    }
-}
+    };
 
-/// Adjust a team's score by a delta during gameplay.
-pub async fn adjust_score(
-    state: &SharedState,
-    team_id: Uuid,
-    request: ScoreAdjustmentRequest,
-) -> Result<ScoreUpdateResponse, ServiceError> {
-    let phase = state.state_machine_phase().await;
-    ensure_running_phase(phase)?;
+    let AnswerValidationRequest {
+        valid,
+        team_id,
+        auto_score,
+        force,
+    } = request;
+    let auto_score = auto_score && matches!(valid, AnswerValidation::Correct);
 
-    let ScoreAdjustmentRequest { delta } = request;
+    // Bonus fields wait for their own decision when they are scored separately.
+    let with_bonus = state.config().bonus_scoring() == BonusScoring::WithAnswer;
 
-    let (game_id, team_id, updated_team) = state
-        .with_current_game_mut(|game| {
-            let team = game
-                .teams
-                .get_mut(&team_id)
-                .ok_or_else(|| ServiceError::NotFound("team not found".into()))?;
-            team.score += delta;
-            team.updated_at = std::time::SystemTime::now();
-            Ok((game.id, team_id, team.clone()))
+    // Resolve (and validate) the awarded team and points before broadcasting anything.
+    let (awarded_team, points) = state
+        .with_current_game(|game| {
+            Ok((
+                resolve_award_team(game, &pause_kind, team_id)?,
+                game.unscored_points(with_bonus),
+            ))
         })
         .await?;
+    if auto_score && awarded_team.is_some() {
+        check_score_delta(points, state.config().max_score_delta(), force)?;
+    }
 
-    // Persist only the updated team, not the entire game
-    state
-        .persist_team(game_id, team_id, updated_team.clone())
-        .await?;
-
-    let score = updated_team.score;
-    sse_events::broadcast_score_adjustment(state, team_id, updated_team);
+    // The GM resolved the buzz, so the answer countdown no longer applies.
+    state.timers().cancel(TimerKind::AnswerCountdown);
 
-    Ok(ScoreUpdateResponse { team_id, score })
-}
+    if matches!(valid, AnswerValidation::Correct)
+        && let Some(team_id) = awarded_team
+    {
+        if auto_score {
+            let award = state
+                .with_current_game_mut(|game| {
+                    if !game.teams.contains_key(&team_id) {
+                        return Err(ServiceError::NotFound("team not found".into()));
+                    }
+                    // Fields already awarded, by `auto_score` when marked found or by an earlier
+                    // validation, are left out.
+                    let points = game.claim_unscored_points(with_bonus);
+                    if points == 0 {
+                        return Ok(None);
+                    }
+                    let team = &mut game.teams[&team_id];
+                    team.score += points;
+                    team.updated_at = SystemTime::now();
+                    Ok(Some((game.id, team.clone())))
+                })
+                .await?;
+
+            if let Some((game_id, team)) = award {
+                state.persist_team(game_id, team_id, team.clone()).await?;
+                sse_events::broadcast_score_adjustment(state, team_id, team);
+            }
+        }
+
+        if let Some(duration) = state.config().applause_duration() {
+            let team = state
+                .with_current_game(|game| Ok(game.teams.get(&team_id).cloned()))
+                .await?;
+            if let Some(team) = team {
+                websocket_service::celebrate_team_buzzers(state, &team, duration);
+            }
+        }
+    }
+
+    state
+        .with_current_game_mut(|game| {
+            game.song_stats.record_validation(&valid);
+            Ok(())
+        })
+        .await?;
+
+    sse_events::broadcast_answer_validation(state, valid);
+    Ok(ActionResponse {
+        message: "answered".into(),
+    })
+}
+
+/// Award bonus fields of the current song to a team, marking them as found, when
+/// `bonus_scoring` is `separate`.
+pub async fn award_bonus(
+    state: &SharedState,
+    request: BonusAwardRequest,
+) -> Result<ScoreUpdateResponse, ServiceError> {
+    if state.config().bonus_scoring() != BonusScoring::Separate {
+        return Err(ServiceError::InvalidState(
+            "bonus fields are awarded with the answer; set `bonus_scoring` to `separate`".into(),
+        ));
+    }
+    let phase = state.state_machine_phase().await;
+    if matches!(ensure_running_phase(phase)?, GameRunningPhase::Prep(_)) {
+        return Err(ServiceError::InvalidState(
+            "cannot award bonus fields during preparation".into(),
+        ));
+    }
+
+    let BonusAwardRequest {
+        team_id,
+        mut field_keys,
+        force,
+    } = request;
+    field_keys.sort_unstable();
+    field_keys.dedup();
+    if field_keys.is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "at least one bonus field must be awarded".into(),
+        ));
+    }
+    let max_score_delta = state.config().max_score_delta();
+
+    let (game_id, song_id, team, found) = state
+        .with_current_game_mut(|game| {
+            let (song_id, song) = game
+                .current_song_index
+                .and_then(|index| game.get_song(index))
+                .ok_or_else(|| {
+                    ServiceError::InvalidState("no active song: playlist is over".into())
+                })?;
+            let points = field_keys
+                .iter()
+                .map(|key| field_points(&song.bonus_fields, key).map(i32::from))
+                .sum::<Result<i32, ServiceError>>()?;
+            if let Some(key) = field_keys
+                .iter()
+                .find(|key| game.scored_bonus_fields.contains(*key))
+            {
+                return Err(ServiceError::InvalidState(format!(
+                    "bonus field `{key}` has already been awarded for this song"
+                )));
+            }
+            check_score_delta(points, max_score_delta, force)?;
+            if !game.teams.contains_key(&team_id) {
+                return Err(ServiceError::NotFound("team not found".into()));
+            }
+
+            for key in field_keys {
+                if !game.found_bonus_fields.contains(&key) {
+                    game.found_bonus_fields.push(key.clone());
+                }
+                game.scored_bonus_fields.insert(key);
+            }
+            let team = &mut game.teams[&team_id];
+            team.score += points;
+            team.updated_at = SystemTime::now();
+            Ok((
+                game.id,
+                song_id,
+                team.clone(),
+                (
+                    game.found_point_fields.clone(),
+                    game.found_bonus_fields.clone(),
+                ),
+            ))
+        })
+        .await?;
+
+    state.persist_current_game_without_teams().await?;
+    state.persist_team(game_id, team_id, team.clone()).await?;
+    let score = team.score;
+    sse_events::broadcast_score_adjustment(state, team_id, team);
+    sse_events::broadcast_fields_found(state, song_id, &found.0, &found.1);
+
+    Ok(ScoreUpdateResponse { team_id, score })
+}
+
+/// Reject a score change larger than `max` in absolute value unless it is forced.
+fn check_score_delta(delta: i32, max: Option<u32>, force: bool) -> Result<(), ServiceError> {
+    match max {
+        Some(max) if !force && delta.unsigned_abs() > max => Err(ServiceError::InvalidInput(
+            format!("score change {delta} exceeds the maximum of {max}; set `force` to apply it"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Determine which team receives the points of a validated answer: the explicitly requested
+/// team when provided, otherwise the team paired with the buzzer that triggered the pause.
+fn resolve_award_team(
+    game: &GameSession,
+    pause_kind: &PauseKind,
+    requested: Option<Uuid>,
+) -> Result<Option<Uuid>, ServiceError> {
+    match (requested, pause_kind) {
+        (Some(team_id), _) if game.teams.contains_key(&team_id) => Ok(Some(team_id)),
+        (Some(_), _) => Err(ServiceError::NotFound("team not found".into())),
+        (None, PauseKind::Buzz { id }) => Ok(game.team_id_by_buzzer(id)),
+        (None, PauseKind::Manual) => Ok(None),
+    }
+}
+
+/// Adjust a team's score by a delta during gameplay.
+pub async fn adjust_score(
+    state: &SharedState,
+    team_id: Uuid,
+    request: ScoreAdjustmentRequest,
+) -> Result<ScoreUpdateResponse, ServiceError> {
+    let phase = state.state_machine_phase().await;
+    ensure_running_phase(phase)?;
+
+    let ScoreAdjustmentRequest { delta, force } = request;
+    check_score_delta(delta, state.config().max_score_delta(), force)?;
+
+    let (game_id, team_id, updated_team) = state
+        .with_current_game_mut(|game| {
+            let team = game
+                .teams
+                .get_mut(&team_id)
+                .ok_or_else(|| ServiceError::NotFound("team not found".into()))?;
+            team.score += delta;
+            team.updated_at = std::time::SystemTime::now();
+            Ok((game.id, team_id, team.clone()))
+        })
+        .await?;
+    state.record_score_adjustment(team_id, delta);
+
+    // Persist only the updated team, not the entire game
+    state
+        .persist_team(game_id, team_id, updated_team.clone())
+        .await?;
+
+    let score = updated_team.score;
+    sse_events::broadcast_score_adjustment(state, team_id, updated_team);
+
+    Ok(ScoreUpdateResponse { team_id, score })
+}
+
+/// Revert the most recent score adjustment of a team, applying the inverse delta.
+///
+/// The last ten adjustments of each team are remembered until another game is loaded.
+pub async fn undo_score_adjustment(
+    state: &SharedState,
+    request: ScoreUndoRequest,
+) -> Result<ScoreUpdateResponse, ServiceError> {
+    let phase = state.state_machine_phase().await;
+    ensure_running_phase(phase)?;
+
+    let team_id = request.team_id;
+    let (game_id, updated_team) = state
+        .with_current_game_mut(|game| {
+            let team = game
+                .teams
+                .get_mut(&team_id)
+                .ok_or_else(|| ServiceError::NotFound("team not found".into()))?;
+            let delta = state.take_last_score_adjustment(team_id).ok_or_else(|| {
+                ServiceError::InvalidState("no score adjustment to undo for this team".into())
+            })?;
+            team.score -= delta;
+            team.updated_at = std::time::SystemTime::now();
+            Ok((game.id, team.clone()))
+        })
+        .await?;
+
+    state
+        .persist_team(game_id, team_id, updated_team.clone())
+        .await?;
+
+    let score = updated_team.score;
+    sse_events::broadcast_score_adjustment(state, team_id, updated_team);
+
+    Ok(ScoreUpdateResponse { team_id, score })
+}
+
+/// Zero every team's score in the running game, keeping the roster and the game's progress.
+///
+/// Each team goes through the debounced team persistence, so the zeroed state is the one that
+/// ends up stored even when resets follow each other within the cooldown.
+pub async fn reset_scores(state: &SharedState) -> Result<Vec<TeamSummary>, ServiceError> {
+    let phase = state.state_machine_phase().await;
+    ensure_running_phase(phase)?;
+
+    let (game_id, teams) = state
+        .with_current_game_mut(|game| {
+            let now = std::time::SystemTime::now();
+            for team in game.teams.values_mut() {
+                team.score = 0;
+                team.updated_at = now;
+            }
+            Ok((game.id, game.teams.clone()))
+        })
+        .await?;
+
+    for (team_id, team) in &teams {
+        state.persist_team(game_id, *team_id, team.clone()).await?;
+    }
+
+    let summaries = teams.clone().into_iter().map(Into::into).collect();
+    for (team_id, team) in teams {
+        sse_events::broadcast_score_adjustment(state, team_id, team);
+    }
+    Ok(summaries)
+}
 
 /// Create a new team during the prep phase, automatically assigning an unused color from colors set when
 /// one is not provided.
@@ -637,35 +1947,35 @@ pub async fn create_team(
         ));
     }
 
+    let buzzer_ids = request.0.buzzer_update().unwrap_or_default();
     let CreateTeamRequest(TeamInput {
         name,
-        buzzer_id: buzzer_input,
         score,
         color: color_input,
+        ..
     }) = request;
 
-    if name.trim().is_empty() {
-        return Err(ServiceError::InvalidInput(
-            "team name must not be empty".into(),
-        ));
-    }
-
-    let buzzer_id = buzzer_input.unwrap_or_default();
     let config = state.config();
+    let name = game_service::normalize_team_name(config.as_ref(), &name)?;
+    let color_input = color_input
+        .map(game_service::checked_team_color)
+        .transpose()?;
+    let team_id = state.ids().next_id();
 
     let (game_id, team_id, team) = state
         .with_current_game_mut(move |game| {
-            if let Some(ref buzzer) = buzzer_id {
-                assert_unique_buzzer(game, None, buzzer)?;
-            }
-            let (team_id, team) = game.add_team(
+            assert_unique_buzzers(game, None, &buzzer_ids)?;
+            let (team_id, _) = game.add_team(
+                team_id,
                 config.as_ref(),
                 Some(name),
-                buzzer_id,
+                None,
                 score,
-                color_input.map(Into::into),
+                color_input,
             );
-            Ok((game.id, team_id, team))
+            let team = &mut game.teams[&team_id];
+            team.buzzer_ids = buzzer_ids;
+            Ok((game.id, team_id, team.clone()))
         })
         .await?;
 
@@ -679,17 +1989,87 @@ pub async fn create_team(
     Ok(summary)
 }
 
+/// Create several teams at once while in prep phase, persisting the game once.
+///
+/// The whole batch is rejected when any team is invalid or reuses a buzzer, whether of another
+/// team of the batch or of an existing team.
+pub async fn create_teams(
+    state: &SharedState,
+    request: CreateTeamsRequest,
+) -> Result<Vec<TeamSummary>, ServiceError> {
+    let prep_status = ensure_prep_phase(state).await?;
+    if matches!(prep_status, PrepStatus::Pairing(_)) {
+        return Err(ServiceError::InvalidState(
+            "cannot modify teams during active pairing".into(),
+        ));
+    }
+    if request.0.is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "at least one team must be provided".into(),
+        ));
+    }
+
+    let config = state.config();
+    let ids = state.ids();
+    let mut seen_buzzers = HashSet::new();
+    let teams = request
+        .0
+        .into_iter()
+        .map(|team| {
+            let buzzer_ids = team.buzzer_update().unwrap_or_default();
+            if let Some(id) = buzzer_ids
+                .iter()
+                .find(|id| !seen_buzzers.insert((*id).clone()))
+            {
+                return Err(ServiceError::InvalidInput(format!(
+                    "duplicate buzzer id `{id}` detected"
+                )));
+            }
+            let name = game_service::normalize_team_name(config.as_ref(), &team.name)?;
+            let color = team
+                .color
+                .map(game_service::checked_team_color)
+                .transpose()?;
+            Ok((ids.next_id(), buzzer_ids, name, team.score, color))
+        })
+        .collect::<Result<Vec<_>, ServiceError>>()?;
+
+    let created = state
+        .with_current_game_mut(move |game| {
+            for (_, buzzer_ids, ..) in &teams {
+                assert_unique_buzzers(game, None, buzzer_ids)?;
+            }
+            Ok(teams
+                .into_iter()
+                .map(|(team_id, buzzer_ids, name, score, color)| {
+                    let (team_id, _) =
+                        game.add_team(team_id, config.as_ref(), Some(name), None, score, color);
+                    let team = &mut game.teams[&team_id];
+                    team.buzzer_ids = buzzer_ids;
+                    TeamSummary::from((team_id, team.clone()))
+                })
+                .collect::<Vec<_>>())
+        })
+        .await?;
+
+    state.persist_current_game().await?;
+
+    for summary in &created {
+        sse_events::broadcast_team_created(state, summary.clone());
+    }
+
+    Ok(created)
+}
+
 /// Update team metadata (name, buzzer, score) while in prep phase.
 pub async fn update_team(
     state: &SharedState,
     team_id: Uuid,
     request: UpdateTeamRequest,
 ) -> Result<TeamSummary, ServiceError> {
+    let buzzer_ids = request.0.buzzer_update();
     let UpdateTeamRequest(TeamInput {
-        name,
-        buzzer_id,
-        score,
-        color,
+        name, score, color, ..
     }) = request;
 
     let prep_status = ensure_prep_phase(state).await?;
@@ -699,16 +2079,13 @@ pub async fn update_team(
         ));
     }
 
-    if name.trim().is_empty() {
-        return Err(ServiceError::InvalidInput(
-            "team name must not be empty".into(),
-        ));
-    }
+    let name = game_service::normalize_team_name(state.config().as_ref(), &name)?;
+    let color = color.map(game_service::checked_team_color).transpose()?;
 
     let (game_id, updated_team) = state
         .with_current_game_mut(move |game| {
-            if let Some(Some(ref buzzer)) = buzzer_id {
-                assert_unique_buzzer(game, Some(team_id), buzzer)?;
+            if let Some(ref buzzers) = buzzer_ids {
+                assert_unique_buzzers(game, Some(team_id), buzzers)?;
             }
 
             let team = game
@@ -717,14 +2094,14 @@ pub async fn update_team(
                 .ok_or_else(|| ServiceError::NotFound(format!("team `{team_id}` not found")))?;
 
             team.name = name;
-            if let Some(buzzer) = buzzer_id {
-                team.buzzer_id = buzzer;
+            if let Some(buzzers) = buzzer_ids {
+                team.buzzer_ids = buzzers;
             }
             if let Some(new_score) = score {
                 team.score = new_score;
             }
             if let Some(color_update) = color {
-                team.color = color_update.into();
+                team.color = color_update;
             }
             team.updated_at = std::time::SystemTime::now();
 
@@ -783,6 +2160,64 @@ pub async fn delete_team(state: &SharedState, team_id: Uuid) -> Result<(), Servi
     Ok(())
 }
 
+/// Set the display order of the active game's teams, then persist and broadcast the session.
+pub async fn reorder_teams(
+    state: &SharedState,
+    request: ReorderTeamsRequest,
+) -> Result<TeamsResponse, ServiceError> {
+    if matches!(
+        state.state_machine_phase().await,
+        GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Pairing(_)))
+    ) {
+        return Err(ServiceError::InvalidState(
+            "cannot modify teams during active pairing".into(),
+        ));
+    }
+
+    let teams = state
+        .with_current_game_mut(move |game| {
+            reorder_roster(&mut game.teams, &request.team_ids)?;
+            game.updated_at = SystemTime::now();
+            Ok(game
+                .teams
+                .iter()
+                .map(|(id, team)| TeamSummary::from((*id, team.clone())))
+                .collect())
+        })
+        .await?;
+
+    // Team order lives in the game document, team documents are untouched
+    state.persist_current_game_without_teams().await?;
+    state
+        .read_current_game(|game| {
+            if let Some(game) = game {
+                sse_events::broadcast_game_session(state, game);
+            }
+        })
+        .await;
+
+    Ok(TeamsResponse { teams })
+}
+
+/// Rebuild `teams` following `order`, which must list every current team exactly once.
+fn reorder_roster(teams: &mut IndexMap<Uuid, Team>, order: &[Uuid]) -> Result<(), ServiceError> {
+    let unique: HashSet<&Uuid> = order.iter().collect();
+    if order.len() != teams.len()
+        || unique.len() != order.len()
+        || !order.iter().all(|id| teams.contains_key(id))
+    {
+        return Err(ServiceError::InvalidInput(
+            "team ids must list every team of the active game exactly once".into(),
+        ));
+    }
+    let mut previous = std::mem::take(teams);
+    *teams = order
+        .iter()
+        .filter_map(|id| previous.swap_remove_entry(id))
+        .collect();
+    Ok(())
+}
+
 /// Begin a pairing workflow for assigning buzzers to teams.
 pub async fn start_pairing(
     state: &SharedState,
@@ -829,6 +2264,23 @@ pub async fn start_pairing(
     Ok(())
 }
 
+/// Pair a buzzer with the team currently pairing, `force` confirming a reassignment from
+/// another team when the configuration requires it.
+pub async fn assign_pairing_buzzer(
+    state: &SharedState,
+    request: PairingAssignRequest,
+) -> Result<ActionResponse, ServiceError> {
+    if !matches!(ensure_prep_phase(state).await?, PrepStatus::Pairing(_)) {
+        return Err(ServiceError::InvalidState(
+            "no pairing workflow in progress".into(),
+        ));
+    }
+    websocket_service::confirm_pairing_buzzer(state, &request.buzzer_id, request.force).await?;
+    Ok(ActionResponse {
+        message: "assigned".into(),
+    })
+}
+
 /// Abort an active pairing workflow and restore the previous roster.
 pub async fn abort_pairing(state: &SharedState) -> Result<Vec<TeamSummary>, ServiceError> {
     match ensure_prep_phase(state).await? {
@@ -854,10 +2306,10 @@ pub async fn abort_pairing(state: &SharedState) -> Result<Vec<TeamSummary>, Serv
                     let mut modified_teams = Vec::new();
 
                     for (team_id, snapshot_team) in snapshot.iter() {
-                        if let Some(current_team) = game.teams.get(team_id) {
-                            if current_team.buzzer_id != snapshot_team.buzzer_id {
-                                modified_teams.push((*team_id, snapshot_team.clone()));
-                            }
+                        if let Some(current_team) = game.teams.get(team_id)
+                            && current_team.buzzer_ids != snapshot_team.buzzer_ids
+                        {
+                            modified_teams.push((*team_id, snapshot_team.clone()));
                         }
                     }
 
@@ -881,13 +2333,1950 @@ pub async fn abort_pairing(state: &SharedState) -> Result<Vec<TeamSummary>, Serv
     Ok(teams)
 }
 
-/// Validate that the requested field is part of the song definition.
-fn ensure_field_exists(fields: &[PointField], field_key: &str) -> Result<(), ServiceError> {
-    if fields.iter().any(|field| field.key == field_key) {
-        Ok(())
-    } else {
-        Err(ServiceError::InvalidInput(format!(
-            "field `{field_key}` does not exist for this song"
-        )))
+/// Points worth of the field with the given key, failing when the song has no such field.
+fn field_points(fields: &[PointField], field_key: &str) -> Result<u8, ServiceError> {
+    fields
+        .iter()
+        .find(|field| field.key == field_key)
+        .map(|field| field.points)
+        .ok_or_else(|| {
+            ServiceError::InvalidInput(format!("field `{field_key}` does not exist for this song"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::AppConfig,
+        dao::game_store::testing::RecordingStore,
+        dto::common::TeamColorDto,
+        state::game::{Playlist, Song},
+    };
+
+    fn game_with_two_teams() -> (GameSession, Uuid, Uuid) {
+        let config = AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "test".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (buzzing, _) = game.add_team(
+            Uuid::new_v4(),
+            &config,
+            None,
+            Some("deadbeef0001".into()),
+            None,
+            None,
+        );
+        let (other, _) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        (game, buzzing, other)
+    }
+
+    #[test]
+    fn award_defaults_to_buzzing_team() {
+        let (game, buzzing, _) = game_with_two_teams();
+        let pause = PauseKind::Buzz {
+            id: "deadbeef0001".into(),
+        };
+        assert_eq!(
+            resolve_award_team(&game, &pause, None).unwrap(),
+            Some(buzzing)
+        );
+    }
+
+    #[test]
+    fn found_fields_are_awarded_once_per_song() {
+        let (mut game, _, _) = game_with_two_teams();
+        let field = |key: &str, points| PointField {
+            key: key.into(),
+            value: key.into(),
+            points,
+            required: false,
+        };
+        game.playlist.songs.insert(
+            1,
+            Song {
+                starts_at_ms: 0,
+                guess_duration_ms: 0,
+                url: "https://example.com/song".into(),
+                point_fields: vec![field("title", 1), field("artist", 2)],
+                bonus_fields: vec![field("year", 3)],
+                tags: Vec::new(),
+            },
+        );
+        game.playlist_song_order = vec![1];
+        game.found_point_fields.push("title".into());
+        game.found_bonus_fields.push("year".into());
+
+        assert_eq!(game.unscored_points(true), 4);
+        assert_eq!(game.claim_unscored_points(true), 4);
+        assert_eq!(game.claim_unscored_points(true), 0);
+
+        game.found_point_fields.push("artist".into());
+        assert_eq!(game.claim_unscored_points(true), 2);
+    }
+
+    #[test]
+    fn award_can_target_non_buzzing_team() {
+        let (game, _, other) = game_with_two_teams();
+        let pause = PauseKind::Buzz {
+            id: "deadbeef0001".into(),
+        };
+        assert_eq!(
+            resolve_award_team(&game, &pause, Some(other)).unwrap(),
+            Some(other)
+        );
+        assert!(matches!(
+            resolve_award_team(&game, &pause, Some(Uuid::new_v4())),
+            Err(ServiceError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn config_view_follows_a_reload() {
+        let path = std::env::temp_dir().join(format!("neon-beat-config-{}.json", Uuid::new_v4()));
+        let state = crate::state::AppState::with_config(AppConfig::default());
+        assert!(!get_config(&state).one_buzz_per_song);
+
+        std::fs::write(
+            &path,
+            r#"{"one_buzz_per_song": true, "max_score_delta": 5, "default_team_names": ["Owls"]}"#,
+        )
+        .unwrap();
+        state.reload_config_from(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let config = serde_json::to_value(get_config(&state)).unwrap();
+        assert_eq!(config["one_buzz_per_song"], true);
+        assert_eq!(config["max_score_delta"], 5);
+        assert_eq!(config["default_team_names"], serde_json::json!(["Owls"]));
+    }
+
+    fn preview(preset: PatternPresetName, color: Option<(f32, f32, f32)>) -> serde_json::Value {
+        let (h, s, v) = match color {
+            Some((h, s, v)) => (Some(h), Some(s), Some(v)),
+            None => (None, None, None),
+        };
+        let preset = build_pattern_preset(PatternPreviewQuery { preset, h, s, v }).unwrap();
+        serde_json::to_value(AppConfig::default().buzzer_pattern(preset)).unwrap()
+    }
+
+    #[test]
+    fn preview_resolves_each_preset() {
+        let color = Some((200.0, 0.5, 0.75));
+
+        let standby = preview(PatternPresetName::Standby, color);
+        assert_eq!(standby["type"], "wave");
+        assert_eq!(standby["details"]["period_ms"], 5_000);
+        assert_eq!(standby["details"]["color"]["h"], 200.0);
+
+        let playing = preview(PatternPresetName::Playing, color);
+        assert_eq!(playing["type"], "wave");
+        assert_eq!(playing["details"]["period_ms"], 3_000);
+        assert_eq!(playing["details"]["color"]["s"], 0.5);
+
+        let answering = preview(PatternPresetName::Answering, color);
+        assert_eq!(answering["type"], "blink");
+        assert_eq!(answering["details"]["color"]["v"], 0.75);
+
+        assert_eq!(preview(PatternPresetName::Waiting, None)["type"], "off");
+    }
+
+    #[test]
+    fn preview_static_color_overrides_requested_color() {
+        let pairing = preview(PatternPresetName::WaitingForPairing, Some((10.0, 1.0, 1.0)));
+        assert_eq!(pairing["type"], "blink");
+        assert_eq!(pairing["details"]["color"]["h"], 125.0);
+    }
+
+    #[test]
+    fn preview_rejects_missing_or_partial_color() {
+        let missing = PatternPreviewQuery {
+            preset: PatternPresetName::Playing,
+            h: None,
+            s: None,
+            v: None,
+        };
+        assert!(matches!(
+            build_pattern_preset(missing),
+            Err(ServiceError::InvalidInput(_))
+        ));
+
+        let partial = PatternPreviewQuery {
+            preset: PatternPresetName::Waiting,
+            h: Some(10.0),
+            s: None,
+            v: None,
+        };
+        assert!(matches!(
+            build_pattern_preset(partial),
+            Err(ServiceError::InvalidInput(_))
+        ));
+    }
+
+    fn field(key: &str, required: bool) -> PointField {
+        PointField {
+            key: key.into(),
+            value: key.to_uppercase(),
+            points: 1,
+            required,
+        }
+    }
+
+    #[test]
+    fn progression_blocked_until_required_fields_found() {
+        let song = Song {
+            starts_at_ms: 0,
+            guess_duration_ms: 1_000,
+            url: "https://example.com/song.mp3".into(),
+            point_fields: vec![field("title", true), field("artist", false)],
+            bonus_fields: vec![field("year", true)],
+            tags: Vec::new(),
+        };
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "gate".into(),
+            IndexMap::new(),
+            Playlist::new(
+                Uuid::new_v4(),
+                "playlist".into(),
+                IndexMap::from([(0, song)]),
+            ),
+            None,
+        );
+        assert!(check_required_fields(&game).is_ok());
+
+        game.enforce_required_fields = true;
+        assert_eq!(game.outstanding_required_fields(), ["title", "year"]);
+        assert!(matches!(
+            check_required_fields(&game),
+            Err(ServiceError::InvalidState(_))
+        ));
+
+        game.found_point_fields.push("title".into());
+        game.found_bonus_fields.push("year".into());
+        assert!(game.outstanding_required_fields().is_empty());
+        assert!(check_required_fields(&game).is_ok());
+    }
+
+    #[tokio::test]
+    async fn rapid_song_advance_is_rejected() {
+        let state = crate::state::AppState::new();
+        let gap = std::time::Duration::from_millis(50);
+
+        assert!(state.check_song_advance(gap).is_ok());
+        state.record_song_advance();
+        let remaining = state.check_song_advance(gap).unwrap_err();
+        assert!(remaining <= gap);
+
+        tokio::time::sleep(gap).await;
+        assert!(state.check_song_advance(gap).is_ok());
+    }
+
+    #[tokio::test]
+    async fn failed_song_advance_does_not_start_the_gap() {
+        let state = paused_on_buzz().await;
+
+        // Still on the first song, so going back fails every time.
+        for _ in 0..2 {
+            match previous_song(&state).await {
+                Err(ServiceError::InvalidState(message)) => {
+                    assert!(!message.contains("too recently"), "{message}")
+                }
+                other => panic!("expected the advance to fail, got {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn reloading_finished_game_shows_final_standings() {
+        let state = crate::state::AppState::new();
+        let (mut game, buzzing, other) = game_with_two_teams();
+        game.teams.get_mut(&buzzing).unwrap().score = 12;
+        game.finished = true;
+        let playlist = game.playlist.clone().into();
+        let reloaded = GameSession::from((game.to_entity(), playlist));
+        assert!(reloaded.finished);
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(reloaded);
+            })
+            .await;
+
+        let (_, phase) = state
+            .run_transition(GameEvent::ReloadFinished, || async { Ok(()) })
+            .await
+            .unwrap();
+        assert_eq!(phase, GamePhase::ShowScores);
+
+        let scoreboard = state.game_phase_snapshot(&phase).await.scoreboard.unwrap();
+        let scores: Vec<_> = scoreboard
+            .iter()
+            .map(|entry| (entry.team.id, entry.team.score, entry.rank))
+            .collect();
+        assert_eq!(
+            scores,
+            [(buzzing, Some(12), Some(1)), (other, Some(0), Some(2))]
+        );
+    }
+
+    #[test]
+    fn roster_follows_requested_order() {
+        let (mut game, buzzing, other) = game_with_two_teams();
+        reorder_roster(&mut game.teams, &[other, buzzing]).unwrap();
+        assert_eq!(
+            game.teams.keys().copied().collect::<Vec<_>>(),
+            [other, buzzing]
+        );
+    }
+
+    #[test]
+    fn roster_reorder_rejects_non_permutations() {
+        let (mut game, buzzing, other) = game_with_two_teams();
+        for order in [
+            vec![buzzing],
+            vec![buzzing, buzzing],
+            vec![buzzing, Uuid::new_v4()],
+            vec![buzzing, other, Uuid::new_v4()],
+        ] {
+            assert!(matches!(
+                reorder_roster(&mut game.teams, &order),
+                Err(ServiceError::InvalidInput(_))
+            ));
+        }
+        assert_eq!(
+            game.teams.keys().copied().collect::<Vec<_>>(),
+            [buzzing, other]
+        );
+    }
+
+    async fn paused_on_buzz() -> SharedState {
+        paused_on_buzz_with(AppConfig::default()).await
+    }
+
+    /// Same as [`paused_on_buzz`], running with the given configuration.
+    async fn paused_on_buzz_with(config: AppConfig) -> SharedState {
+        let state = crate::state::AppState::with_config(config);
+        let (game, _, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [
+            GameEvent::StartGame,
+            GameEvent::GameConfigured,
+            GameEvent::Pause(PauseKind::Buzz {
+                id: "deadbeef0001".into(),
+            }),
+        ] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        state
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn finish_reason_is_persisted_and_filterable() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::with_config(AppConfig::from_json(
+            r#"{"persist_cooldown_ms": 0, "min_song_gap_ms": 0}"#,
+        ));
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+
+        let mut game_ids = Vec::new();
+        for stop in [false, true] {
+            let (mut game, _, _) = game_with_two_teams();
+            add_song(&mut game, vec![field("title", false)], Vec::new());
+            game_ids.push(game.id);
+            store.save_game(game.to_entity()).await.unwrap();
+            store
+                .save_playlist(game.playlist.clone().into())
+                .await
+                .unwrap();
+            state
+                .with_current_game_slot_mut(|slot| {
+                    slot.replace(game);
+                })
+                .await;
+            for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+                state
+                    .run_transition(event, || async { Ok(()) })
+                    .await
+                    .unwrap();
+            }
+            if stop {
+                stop_game(&state).await.unwrap();
+            } else {
+                assert!(next_song(&state).await.unwrap().finished);
+            }
+            end_game(&state).await.unwrap();
+        }
+
+        let reasons = |games: Vec<GameListItem>| {
+            let mut reasons: Vec<_> = games
+                .into_iter()
+                .map(|game| (game.id, game.finish_reason))
+                .collect();
+            reasons.sort_by_key(|(id, _)| game_ids.iter().position(|game_id| game_id == id));
+            reasons
+        };
+        let all = list_games(&state, ListGamesQuery::default()).await.unwrap();
+        assert_eq!(
+            reasons(all),
+            [
+                (game_ids[0], Some(GameFinishReason::PlaylistCompleted)),
+                (game_ids[1], Some(GameFinishReason::ManualStop)),
+            ]
+        );
+        let stopped = list_games(
+            &state,
+            ListGamesQuery {
+                finished_reason: Some(GameFinishReason::ManualStop),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            reasons(stopped),
+            [(game_ids[1], Some(GameFinishReason::ManualStop))]
+        );
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn export_prefers_the_active_game_over_storage() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (mut game, buzzing, _) = game_with_two_teams();
+        add_song(&mut game, vec![field("title", false)], Vec::new());
+        let game_id = game.id;
+        store.save_game(game.to_entity()).await.unwrap();
+        store
+            .save_playlist(game.playlist.clone().into())
+            .await
+            .unwrap();
+        game.teams.get_mut(&buzzing).unwrap().score = 7;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        let score = |exported: &ExportedGame| {
+            exported
+                .game
+                .teams
+                .iter()
+                .find(|team| team.id == buzzing)
+                .map(|team| team.score)
+        };
+        let active = export_game(&state, game_id).await.unwrap();
+        assert_eq!(score(&active), Some(7));
+
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.take();
+            })
+            .await;
+        let stored = export_game(&state, game_id).await.unwrap();
+        assert_eq!(score(&stored), Some(0));
+        assert_eq!(stored.playlist, active.playlist);
+
+        let json = serde_json::to_string(&stored).unwrap();
+        let reimported: ExportedGame = serde_json::from_str(&json).unwrap();
+        assert_eq!(reimported.version, crate::dto::admin::GAME_EXPORT_VERSION);
+        assert_eq!(reimported.game, stored.game);
+        assert!(matches!(
+            export_game(&state, Uuid::new_v4()).await,
+            Err(ServiceError::NotFound(_))
+        ));
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn leaderboard_sums_finished_games_per_team_name() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let config = AppConfig::default();
+        for (teams, finished) in [
+            (vec![("Team A", 3), ("Owls", 4)], true),
+            (vec![(" team  a ", 5)], true),
+            (vec![("Team A", 100)], false),
+        ] {
+            let mut game = GameSession::new(
+                Uuid::new_v4(),
+                "season".into(),
+                IndexMap::new(),
+                Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+                None,
+            );
+            for (name, score) in teams {
+                game.add_team(
+                    Uuid::new_v4(),
+                    &config,
+                    Some(name.into()),
+                    None,
+                    Some(score),
+                    None,
+                );
+            }
+            game.finished = finished;
+            store.save_game(game.to_entity()).await.unwrap();
+        }
+
+        let standings: Vec<_> = leaderboard(&state)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.total_points, entry.games_played))
+            .collect();
+        assert_eq!(standings, [(8, 2), (4, 1)]);
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn duplicated_game_starts_afresh_beside_the_active_one() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (mut game, buzzing, _) = game_with_two_teams();
+        game.teams.get_mut(&buzzing).unwrap().score = 5;
+        game.current_song_found = true;
+        game.finished = true;
+        store.save_game(game.to_entity()).await.unwrap();
+        store
+            .save_playlist(game.playlist.clone().into())
+            .await
+            .unwrap();
+        let (game_id, playlist_id) = (game.id, game.playlist.id);
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        let copy = duplicate_game(&state, game_id, false).await.unwrap();
+        assert_ne!(copy.id, game_id.to_string());
+        assert_eq!(copy.playlist.id, playlist_id);
+        assert_eq!(copy.current_song_index, Some(0));
+        assert!(copy.teams.iter().all(|team| team.score == Some(0)));
+        assert!(copy.teams.iter().all(|team| team.id != buzzing));
+        let stored = store
+            .find_game(copy.id.parse().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.finished && !stored.current_song_found);
+        let active = state
+            .with_current_game(|game| Ok((game.id, game.teams[&buzzing].score)))
+            .await
+            .unwrap();
+        assert_eq!(active, (game_id, 5));
+
+        let copy = duplicate_game(&state, game_id, true).await.unwrap();
+        assert_ne!(copy.playlist.id, playlist_id);
+        assert_eq!(store.list_playlists().await.unwrap().len(), 2);
+        assert!(matches!(
+            duplicate_game(&state, Uuid::new_v4(), false).await,
+            Err(ServiceError::NotFound(_))
+        ));
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn exported_games_are_imported_back() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (mut game, _, _) = game_with_two_teams();
+        for (id, key) in [(0, "title"), (1, "artist")] {
+            game.playlist.songs.insert(
+                id,
+                Song {
+                    starts_at_ms: 0,
+                    guess_duration_ms: 30_000,
+                    url: "https://example.com/song".into(),
+                    point_fields: vec![field(key, false)],
+                    bonus_fields: Vec::new(),
+                    tags: Vec::new(),
+                },
+            );
+        }
+        game.playlist_song_order = vec![1, 0];
+        let game_id = game.id;
+        store.save_game(game.to_entity()).await.unwrap();
+        store
+            .save_playlist(game.playlist.clone().into())
+            .await
+            .unwrap();
+        let exported = export_game(&state, game_id).await.unwrap();
+
+        assert!(matches!(
+            import_game(&state, exported.clone(), true).await,
+            Err(ServiceError::Conflict(_))
+        ));
+        let imported = import_game(&state, exported.clone(), false).await.unwrap();
+        let copy = export_game(&state, imported.id.parse().unwrap())
+            .await
+            .unwrap();
+        assert_ne!(copy.game.id, game_id);
+        assert_ne!(copy.playlist.id, exported.playlist.id);
+        assert_eq!(copy.game.playlist_id, copy.playlist.id);
+        assert_eq!(copy.game.playlist_song_order, [1, 0]);
+        assert_eq!(copy.playlist.songs, exported.playlist.songs);
+        let names = |exported: &ExportedGame| {
+            exported
+                .game
+                .teams
+                .iter()
+                .map(|team| (team.name.clone(), team.buzzer_ids.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&copy), names(&exported));
+
+        let mut inconsistent = exported.clone();
+        inconsistent.game.playlist_song_order = Vec::new();
+        assert!(matches!(
+            import_game(&state, inconsistent, false).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        let mut inconsistent = exported;
+        inconsistent.game.playlist_song_order = vec![0, 2];
+        assert!(matches!(
+            import_game(&state, inconsistent, false).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn reordered_playlists_survive_an_import_with_preserved_ids() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (mut game, _, _) = game_with_two_teams();
+        for (id, key) in [(2, "album"), (0, "title"), (1, "artist")] {
+            game.playlist.songs.insert(
+                id,
+                Song {
+                    starts_at_ms: 0,
+                    guess_duration_ms: 30_000,
+                    url: "https://example.com/song".into(),
+                    point_fields: vec![field(key, false)],
+                    bonus_fields: Vec::new(),
+                    tags: Vec::new(),
+                },
+            );
+        }
+        game.playlist_song_order = vec![2, 0, 1];
+        let game_id = game.id;
+        store.save_game(game.to_entity()).await.unwrap();
+        store
+            .save_playlist(game.playlist.clone().into())
+            .await
+            .unwrap();
+        let exported = export_game(&state, game_id).await.unwrap();
+        assert_eq!(exported.playlist.song_order, [2, 0, 1]);
+
+        // The playlist left in storage is the exported one, not a conflicting copy.
+        store.delete_game(game_id).await.unwrap();
+        import_game(&state, exported.clone(), true).await.unwrap();
+        let stored = store.find_playlist(exported.playlist.id).await.unwrap();
+        assert_eq!(stored.as_ref(), Some(&exported.playlist));
+
+        let fresh = crate::state::AppState::new();
+        let fresh_store = InMemoryGameStore::new();
+        fresh.set_game_store(Arc::new(fresh_store.clone())).await;
+        import_game(&fresh, exported.clone(), true).await.unwrap();
+        let copy = export_game(&fresh, game_id).await.unwrap();
+        assert_eq!(copy.playlist, exported.playlist);
+        assert_eq!(copy.game.playlist_song_order, [2, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn team_names_are_trimmed_and_checked() {
+        let state = crate::state::AppState::with_config(AppConfig::from_json(
+            r#"{"max_team_name_len": 8}"#,
+        ));
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (game, buzzing, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state
+            .run_transition(GameEvent::StartGame, || async { Ok(()) })
+            .await
+            .unwrap();
+        let team = |name: &str| TeamInput {
+            name: name.into(),
+            buzzer_id: None,
+            buzzer_ids: None,
+            score: None,
+            color: None,
+        };
+
+        for name in ["Nine char", "Ow\nls", "Owls\u{7}"] {
+            assert!(matches!(
+                create_team(&state, CreateTeamRequest(team(name))).await,
+                Err(ServiceError::InvalidInput(_))
+            ));
+        }
+        let created = create_team(&state, CreateTeamRequest(team("  Eight ch  ")))
+            .await
+            .unwrap();
+        assert_eq!(created.name, "Eight ch");
+        assert!(matches!(
+            update_team(&state, buzzing, UpdateTeamRequest(team("Foxes\t"))).await,
+            Ok(summary) if summary.name == "Foxes"
+        ));
+        assert!(matches!(
+            update_team(&state, buzzing, UpdateTeamRequest(team("Fo\txes"))).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+
+        let colored = |h, v| TeamInput {
+            color: Some(TeamColorDto { h, s: 1.0, v }),
+            ..team("Owls")
+        };
+        assert!(matches!(
+            create_team(&state, CreateTeamRequest(colored(0.0, 2.0))).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        let recolored = update_team(&state, buzzing, UpdateTeamRequest(colored(-60.0, 0.5)))
+            .await
+            .unwrap();
+        assert_eq!((recolored.color.h, recolored.color.v), (300.0, 0.5));
+    }
+
+    #[tokio::test]
+    async fn bulk_team_creation_is_all_or_nothing() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (game, _, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state
+            .run_transition(GameEvent::StartGame, || async { Ok(()) })
+            .await
+            .unwrap();
+        let team = |name: &str, buzzer: &str| TeamInput {
+            name: name.into(),
+            buzzer_id: Some(Some(buzzer.into())),
+            buzzer_ids: None,
+            score: None,
+            color: None,
+        };
+        let team_count = || state.with_current_game(|game| Ok(game.teams.len()));
+
+        for batch in [
+            vec![team("Owls", "deadbeef0003"), team("Bats", "deadbeef0003")],
+            vec![team("Owls", "deadbeef0003"), team("Bats", "deadbeef0001")],
+            vec![team("Owls", "deadbeef0003"), team(" ", "deadbeef0004")],
+        ] {
+            assert!(matches!(
+                create_teams(&state, CreateTeamsRequest(batch)).await,
+                Err(ServiceError::InvalidInput(_))
+            ));
+        }
+        assert_eq!(team_count().await.unwrap(), 2);
+
+        let mut events = state.admin_sse().subscribe();
+        let created = create_teams(
+            &state,
+            CreateTeamsRequest(vec![
+                team("Owls", "deadbeef0003"),
+                team("Bats", "deadbeef0004"),
+            ]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            created
+                .iter()
+                .map(|team| team.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Owls", "Bats"]
+        );
+        assert_ne!(created[0].color.h, created[1].color.h);
+        assert_eq!(team_count().await.unwrap(), 4);
+        assert!(events.try_recv().is_ok());
+        assert!(events.try_recv().is_ok());
+    }
+
+    #[tokio::test]
+    async fn manual_buzz_pauses_on_the_given_team() {
+        let state = crate::state::AppState::new();
+        let (game, buzzing, other) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        assert!(matches!(
+            manual_buzz(&state, ManualBuzzRequest { team_id: other }).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+        manual_buzz(&state, ManualBuzzRequest { team_id: buzzing })
+            .await
+            .unwrap();
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz {
+                id: "deadbeef0001".into()
+            }))
+        );
+        assert!(matches!(
+            manual_buzz(&state, ManualBuzzRequest { team_id: buzzing }).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn expired_answer_countdown_resumes_playing() {
+        let state = paused_on_buzz().await;
+        let mut events = state.public_sse().subscribe();
+        schedule_answer_countdown(
+            &state,
+            Uuid::new_v4(),
+            Duration::from_millis(30),
+            CountdownExpiry::Resume,
+        );
+        assert_eq!(
+            events.recv().await.unwrap().event.as_deref(),
+            Some("answer.countdown")
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+    }
+
+    #[tokio::test]
+    async fn manual_resolution_cancels_answer_countdown() {
+        let state = paused_on_buzz().await;
+        schedule_answer_countdown(
+            &state,
+            Uuid::new_v4(),
+            Duration::from_millis(30),
+            CountdownExpiry::Resume,
+        );
+
+        resume_game(&state).await.unwrap();
+        assert_eq!(state.timers().remaining(TimerKind::AnswerCountdown), None);
+        pause_game(&state).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Manual))
+        );
+    }
+
+    #[tokio::test]
+    async fn manual_flush_persists_pending_writes() {
+        let state = crate::state::AppState::new();
+        let store = Arc::new(RecordingStore::default());
+        state.set_game_store(store.clone()).await;
+        let (game, team_id, _) = game_with_two_teams();
+        let (game_id, team) = (game.id, game.teams[&team_id].clone());
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        // The second write of each entity lands within the cooldown and stays pending.
+        for _ in 0..2 {
+            state.persist_current_game().await.unwrap();
+            state
+                .persist_team(game_id, team_id, team.clone())
+                .await
+                .unwrap();
+        }
+        assert_eq!(store.games.lock().unwrap().len(), 1);
+        assert_eq!(store.teams.lock().unwrap().len(), 1);
+
+        let response = flush_storage(&state).await.unwrap();
+        assert_eq!((response.flushed, response.failed), (2, 0));
+        assert_eq!(store.games.lock().unwrap().len(), 2);
+        assert_eq!(store.teams.lock().unwrap().len(), 2);
+
+        let response = flush_storage(&state).await.unwrap();
+        assert_eq!((response.flushed, response.failed), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn reset_scores_zeroes_every_team_and_stores_the_final_state() {
+        let state = crate::state::AppState::new();
+        let store = Arc::new(RecordingStore::default());
+        state.set_game_store(store.clone()).await;
+        let (mut game, buzzing, other) = game_with_two_teams();
+        game.teams.get_mut(&buzzing).unwrap().score = 7;
+        game.teams.get_mut(&other).unwrap().score = -2;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        assert!(matches!(
+            reset_scores(&state).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        let mut events = state.public_sse().subscribe();
+        reset_scores(&state).await.unwrap();
+        adjust_score(
+            &state,
+            buzzing,
+            ScoreAdjustmentRequest {
+                delta: 3,
+                force: false,
+            },
+        )
+        .await
+        .unwrap();
+        // The second reset lands within the persistence cooldown and stays pending.
+        let teams = reset_scores(&state).await.unwrap();
+        assert!(teams.iter().all(|team| team.score == Some(0)));
+        flush_storage(&state).await.unwrap();
+
+        let stored = store.teams.lock().unwrap();
+        for team_id in [buzzing, other] {
+            let last = stored.iter().rev().find(|team| team.id == team_id).unwrap();
+            assert_eq!(last.score, 0);
+        }
+        let mut adjustments = 0;
+        while let Ok(event) = events.try_recv() {
+            adjustments += usize::from(event.event.as_deref() == Some("score_adjustment"));
+        }
+        assert_eq!(adjustments, 5);
+    }
+
+    #[tokio::test]
+    async fn buzzer_within_reconnect_grace_still_counts_as_paired() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (mut game, _, other) = game_with_two_teams();
+        game.teams[&other].buzzer_ids = vec!["deadbeef0002".into()];
+        add_song(&mut game, vec![field("title", true)], Vec::new());
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state
+            .run_transition(GameEvent::StartGame, || async { Ok(()) })
+            .await
+            .unwrap();
+
+        for id in ["deadbeef0001", "deadbeef0002"] {
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+            state.connect_buzzer(crate::state::BuzzerConnection { id: id.into(), tx });
+        }
+        state.disconnect_buzzer("deadbeef0002");
+
+        assert!(state.buzzer_present("deadbeef0002"));
+        start_game(&state).await.unwrap();
+    }
+
+    /// Make a song with the given fields the only song of `game`'s playlist.
+    fn add_song(
+        game: &mut GameSession,
+        point_fields: Vec<PointField>,
+        bonus_fields: Vec<PointField>,
+    ) {
+        game.playlist.songs.insert(
+            1,
+            Song {
+                starts_at_ms: 0,
+                guess_duration_ms: 30_000,
+                url: "https://example.com/song".into(),
+                point_fields,
+                bonus_fields,
+                tags: Vec::new(),
+            },
+        );
+        game.playlist_song_order = vec![1];
+    }
+
+    #[tokio::test]
+    async fn partial_reveal_withholds_bonus_fields_until_full_reveal() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (mut game, _, _) = game_with_two_teams();
+        add_song(
+            &mut game,
+            vec![field("title", false), field("artist", false)],
+            vec![field("year", false)],
+        );
+        game.enter_song(Some(0));
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        let mut events = state.public_sse().subscribe();
+
+        let revealed = reveal_partial(&state).await.unwrap();
+        assert_eq!(revealed.point_fields, ["title", "artist"]);
+        assert!(revealed.bonus_fields.is_empty());
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.event.as_deref(), Some("fields_found"));
+        let payload: serde_json::Value = serde_json::from_str(&event.data).unwrap();
+        assert_eq!(payload["bonus_fields"], serde_json::json!([]));
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+
+        reveal(&state).await.unwrap();
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Reveal)
+        );
+        assert!(matches!(
+            reveal_partial(&state).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn buzzer_assignment_reports_team_and_connection() {
+        let state = crate::state::AppState::new();
+        let (game, buzzing, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        let assignment = buzzer_assignment(&state, "deadbeef0001")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(assignment.team.id, buzzing);
+        assert!(!assignment.connected);
+
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        state.connect_buzzer(crate::state::BuzzerConnection {
+            id: "deadbeef0001".into(),
+            tx,
+        });
+        let assignment = buzzer_assignment(&state, "deadbeef0001")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(assignment.connected);
+
+        assert!(
+            buzzer_assignment(&state, "deadbeef0002")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(matches!(
+            buzzer_assignment(&state, "not-a-buzzer").await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn score_delta_above_cap_requires_force() {
+        let state =
+            crate::state::AppState::with_config(AppConfig::from_json(r#"{"max_score_delta": 50}"#));
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (game, team_id, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state
+            .run_transition(GameEvent::StartGame, || async { Ok(()) })
+            .await
+            .unwrap();
+
+        let request = |delta, force| ScoreAdjustmentRequest { delta, force };
+        assert!(matches!(
+            adjust_score(&state, team_id, request(1000, false)).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        assert_eq!(
+            adjust_score(&state, team_id, request(-50, false))
+                .await
+                .unwrap()
+                .score,
+            -50
+        );
+        assert_eq!(
+            adjust_score(&state, team_id, request(1000, true))
+                .await
+                .unwrap()
+                .score,
+            950
+        );
+    }
+
+    #[tokio::test]
+    async fn score_adjustments_are_undone_last_first() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (game, team_id, other) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state
+            .run_transition(GameEvent::StartGame, || async { Ok(()) })
+            .await
+            .unwrap();
+
+        let undo = |team_id| undo_score_adjustment(&state, ScoreUndoRequest { team_id });
+        for delta in [5, -2] {
+            let request = ScoreAdjustmentRequest {
+                delta,
+                force: false,
+            };
+            adjust_score(&state, team_id, request).await.unwrap();
+        }
+        assert!(matches!(
+            undo(other).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+        assert_eq!(undo(team_id).await.unwrap().score, 5);
+        assert_eq!(undo(team_id).await.unwrap().score, 0);
+        assert!(matches!(
+            undo(team_id).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+
+        let request = ScoreAdjustmentRequest {
+            delta: 3,
+            force: false,
+        };
+        adjust_score(&state, team_id, request).await.unwrap();
+        state.clear_game_state().await;
+        assert!(matches!(
+            undo(team_id).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn renamed_active_game_is_broadcast_and_persisted() {
+        let state = crate::state::AppState::new();
+        let store = Arc::new(RecordingStore::default());
+        state.set_game_store(store.clone()).await;
+        let (game, _, _) = game_with_two_teams();
+        let game_id = game.id;
+        store
+            .playlists
+            .lock()
+            .unwrap()
+            .push(game.playlist.clone().into());
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        let rename = |name: &str| RenameGameRequest {
+            name: Some(name.into()),
+            playlist_name: None,
+        };
+
+        assert!(matches!(
+            rename_game(&state, rename("  ")).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        let mut events = state.public_sse().subscribe();
+        let summary = update_game(&state, game_id, rename(" Finals "))
+            .await
+            .unwrap();
+        assert_eq!(summary.name, "Finals");
+
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.event.as_deref(), Some("game.session"));
+        let payload: serde_json::Value = serde_json::from_str(&event.data).unwrap();
+        assert_eq!(payload["name"], "Finals");
+        assert_eq!(store.games.lock().unwrap().last().unwrap().name, "Finals");
+
+        assert!(matches!(
+            update_game(&state, Uuid::new_v4(), rename("Other")).await,
+            Err(ServiceError::NotFound(_))
+        ));
+
+        let rename_playlist = |name: &str| RenameGameRequest {
+            name: None,
+            playlist_name: Some(name.into()),
+        };
+        for request in [
+            rename_playlist(" "),
+            RenameGameRequest {
+                name: None,
+                playlist_name: None,
+            },
+        ] {
+            assert!(matches!(
+                update_game(&state, game_id, request).await,
+                Err(ServiceError::InvalidInput(_))
+            ));
+        }
+        let summary = update_game(&state, game_id, rename_playlist(" Hits "))
+            .await
+            .unwrap();
+        assert_eq!(
+            (summary.name.as_str(), summary.playlist.name.as_str()),
+            ("Finals", "Hits")
+        );
+        assert_eq!(store.playlists.lock().unwrap().last().unwrap().name, "Hits");
+    }
+
+    #[tokio::test]
+    async fn guess_timer_reveals_after_the_remaining_time_once_resumed() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (mut game, _, _) = game_with_two_teams();
+        add_song(&mut game, vec![field("title", false)], vec![]);
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        schedule_auto_reveal(&state, 80);
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        pause_game(&state).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Manual))
+        );
+
+        resume_game(&state).await.unwrap();
+        assert!(
+            state.timers().remaining(TimerKind::AutoReveal).unwrap() <= Duration::from_millis(40)
+        );
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Reveal)
+        );
+    }
+
+    #[tokio::test]
+    async fn auto_scored_fields_are_awarded_once_to_the_buzzing_team() {
+        let state = paused_on_buzz().await;
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let buzzing = state
+            .with_current_game_mut(|game| {
+                let mut bonus = field("year", false);
+                bonus.points = 3;
+                add_song(game, vec![field("title", false)], vec![bonus]);
+                game.current_song_index = Some(0);
+                Ok(game.team_id_by_buzzer("deadbeef0001").unwrap())
+            })
+            .await
+            .unwrap();
+
+        let mark = |field_key: &str, kind| MarkFieldRequest {
+            song_id: 1,
+            field_key: field_key.into(),
+            kind,
+            auto_score: true,
+            force: false,
+        };
+        mark_field_found(&state, mark("title", FieldKind::Point))
+            .await
+            .unwrap();
+        mark_field_found(&state, mark("title", FieldKind::Point))
+            .await
+            .unwrap();
+        mark_field_found(&state, mark("year", FieldKind::Bonus))
+            .await
+            .unwrap();
+
+        let score = || state.read_current_game(|game| game.unwrap().teams[&buzzing].score);
+        assert_eq!(score().await, 4);
+
+        // Validating the answer does not award the auto-scored fields a second time.
+        validate_answer(
+            &state,
+            AnswerValidationRequest {
+                valid: AnswerValidation::Correct,
+                team_id: None,
+                auto_score: true,
+                force: false,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(score().await, 4);
+    }
+
+    #[tokio::test]
+    async fn auto_scored_field_over_the_cap_needs_force() {
+        let state = paused_on_buzz_with(AppConfig::from_json(r#"{"max_score_delta": 2}"#)).await;
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let buzzing = state
+            .with_current_game_mut(|game| {
+                let mut title = field("title", false);
+                title.points = 3;
+                add_song(game, vec![title], vec![]);
+                game.current_song_index = Some(0);
+                Ok(game.team_id_by_buzzer("deadbeef0001").unwrap())
+            })
+            .await
+            .unwrap();
+        let mark = |force| {
+            mark_field_found(
+                &state,
+                MarkFieldRequest {
+                    song_id: 1,
+                    field_key: "title".into(),
+                    kind: FieldKind::Point,
+                    auto_score: true,
+                    force,
+                },
+            )
+        };
+        let score = || state.read_current_game(|game| game.unwrap().teams[&buzzing].score);
+
+        assert!(matches!(
+            mark(false).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        assert_eq!(score().await, 0);
+        mark(true).await.unwrap();
+        assert_eq!(score().await, 3);
+    }
+
+    #[tokio::test]
+    async fn manual_pause_freezes_timers_only_when_configured() {
+        for freeze in [true, false] {
+            let state = crate::state::AppState::with_config(AppConfig::from_json(&format!(
+                r#"{{"pause_freezes_timers": {freeze}}}"#
+            )));
+            let (game, _, _) = game_with_two_teams();
+            state
+                .with_current_game_slot_mut(|slot| {
+                    slot.replace(game);
+                })
+                .await;
+            for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+                state
+                    .run_transition(event, || async { Ok(()) })
+                    .await
+                    .unwrap();
+            }
+            state.timers().schedule(
+                TimerKind::AutoReveal,
+                Duration::from_secs(60),
+                Arc::new(|| Box::pin(async {})),
+            );
+
+            pause_game(&state).await.unwrap();
+            let paused_at = state.timers().remaining(TimerKind::AutoReveal).unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let later = state.timers().remaining(TimerKind::AutoReveal).unwrap();
+
+            assert_eq!(later == paused_at, freeze, "pause_freezes_timers: {freeze}");
+        }
+    }
+
+    #[tokio::test]
+    async fn skipping_the_last_song_finishes_the_game() {
+        let state = paused_on_buzz().await;
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        state
+            .with_current_game_mut(|game| {
+                add_song(game, vec![field("title", false)], vec![]);
+                game.current_song_index = Some(0);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let response = skip_song(&state).await.unwrap();
+
+        assert!(response.finished);
+        assert_eq!(state.state_machine_phase().await, GamePhase::ShowScores);
+        let revealed = state
+            .read_current_game(|game| game.unwrap().current_song_found)
+            .await;
+        assert!(!revealed);
+    }
+
+    #[tokio::test]
+    async fn current_song_fields_reflect_found_state() {
+        let state = crate::state::AppState::new();
+        let (mut game, _, _) = game_with_two_teams();
+        add_song(
+            &mut game,
+            vec![field("title", true), field("artist", false)],
+            vec![field("year", false)],
+        );
+        game.current_song_index = None;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        assert!(current_song_fields(&state).await.unwrap().is_none());
+
+        state
+            .with_current_game_mut(|game| {
+                game.enter_song(Some(0));
+                game.found_point_fields.push("artist".into());
+                game.found_bonus_fields.push("year".into());
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let sheet = current_song_fields(&state).await.unwrap().unwrap();
+
+        assert_eq!(sheet.song_id, 1);
+        fn found(fields: &[SongFieldStatus]) -> Vec<(&str, &str, bool)> {
+            fields
+                .iter()
+                .map(|field| (field.key.as_str(), field.value.as_str(), field.found))
+                .collect()
+        }
+        assert_eq!(
+            found(&sheet.point_fields),
+            [("title", "TITLE", false), ("artist", "ARTIST", true)]
+        );
+        assert!(sheet.point_fields[0].required);
+        assert_eq!(found(&sheet.bonus_fields), [("year", "YEAR", true)]);
+    }
+
+    #[tokio::test]
+    async fn previous_song_replays_the_song_before_the_current_one() {
+        let state =
+            crate::state::AppState::with_config(AppConfig::from_json(r#"{"min_song_gap_ms": 0}"#));
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (mut game, _, _) = game_with_two_teams();
+        add_song(&mut game, vec![field("title", false)], vec![]);
+        let second = game.playlist.songs[&1].clone();
+        game.playlist.songs.insert(2, second);
+        game.playlist_song_order = vec![1, 2];
+        game.enter_song(Some(1));
+        game.found_point_fields.push("title".into());
+        game.current_song_found = true;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [
+            GameEvent::StartGame,
+            GameEvent::GameConfigured,
+            GameEvent::Reveal,
+        ] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        let response = previous_song(&state).await.unwrap();
+
+        assert_eq!(response.song.id, "1");
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+        let (index, found, revealed) = state
+            .read_current_game(|game| {
+                let game = game.unwrap();
+                (
+                    game.current_song_index,
+                    game.found_point_fields.clone(),
+                    game.current_song_found,
+                )
+            })
+            .await;
+        assert_eq!(index, Some(0));
+        assert!(found.is_empty());
+        assert!(!revealed);
+        assert!(matches!(
+            previous_song(&state).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn ended_game_stays_loaded_for_a_replay() {
+        let state = crate::state::AppState::with_config(AppConfig::from_json(
+            r#"{"keep_game_after_end": true}"#,
+        ));
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (mut game, buzzing, _) = game_with_two_teams();
+        add_song(&mut game, vec![field("title", false)], vec![]);
+        game.teams[&buzzing].score = 5;
+        game.current_song_found = true;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [
+            GameEvent::StartGame,
+            GameEvent::GameConfigured,
+            GameEvent::Finish(FinishReason::PlaylistCompleted),
+        ] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        end_game(&state).await.unwrap();
+        assert_eq!(state.state_machine_phase().await, GamePhase::Idle);
+        assert!(state.read_current_game(|game| game.is_some()).await);
+
+        let summary = replay_game(&state).await.unwrap();
+        assert!(summary.teams.iter().all(|team| team.score == Some(0)));
+        assert!(matches!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Prep(_))
+        ));
+        let (index, revealed) = state
+            .read_current_game(|game| {
+                let game = game.unwrap();
+                (game.current_song_index, game.current_song_found)
+            })
+            .await;
+        assert_eq!(index, Some(0));
+        assert!(!revealed);
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn replay_is_stored_apart_from_the_finished_game() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::with_config(AppConfig::from_json(
+            r#"{"keep_game_after_end": true, "persist_cooldown_ms": 0}"#,
+        ));
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (mut game, buzzing, _) = game_with_two_teams();
+        add_song(&mut game, vec![field("title", false)], vec![]);
+        game.teams[&buzzing].score = 5;
+        game.finished = true;
+        let finished_id = game.id;
+        store.save_game(game.to_entity()).await.unwrap();
+        store
+            .save_playlist(game.playlist.clone().into())
+            .await
+            .unwrap();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [
+            GameEvent::StartGame,
+            GameEvent::GameConfigured,
+            GameEvent::Finish(FinishReason::PlaylistCompleted),
+        ] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        end_game(&state).await.unwrap();
+
+        let replay = replay_game(&state).await.unwrap();
+        assert_ne!(replay.id, finished_id.to_string());
+
+        let finished = store.find_game(finished_id).await.unwrap().unwrap();
+        assert!(finished.finished);
+        let score = finished
+            .teams
+            .iter()
+            .find(|team| team.id == buzzing)
+            .unwrap();
+        assert_eq!(score.score, 5);
+        let replayed = store
+            .find_game(replay.id.parse().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!replayed.finished);
+        assert!(replayed.teams.iter().all(|team| team.score == 0));
+        assert!(replayed.teams.iter().all(|team| team.id != buzzing));
+    }
+
+    #[tokio::test]
+    async fn unassigned_buzzers_exclude_paired_ones() {
+        let state = crate::state::AppState::new();
+        let (game, _, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for id in ["deadbeef0001", "deadbeef0002"] {
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+            state.connect_buzzer(crate::state::BuzzerConnection { id: id.into(), tx });
+        }
+
+        assert_eq!(unassigned_buzzers(&state).await, ["deadbeef0002"]);
+
+        state.disconnect_buzzer("deadbeef0002");
+        assert!(unassigned_buzzers(&state).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn connected_buzzers_show_their_team_and_last_pattern() {
+        let state = crate::state::AppState::new();
+        for id in ["deadbeef0002", "deadbeef0001"] {
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+            state.connect_buzzer(crate::state::BuzzerConnection { id: id.into(), tx });
+        }
+        state
+            .buzzer_last_patterns()
+            .insert("deadbeef0002", BuzzerPatternPreset::Waiting);
+        assert!(
+            connected_buzzers(&state)
+                .await
+                .iter()
+                .all(|buzzer| buzzer.team.is_none())
+        );
+
+        let (game, buzzing, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        let buzzers = connected_buzzers(&state).await;
+        assert_eq!(buzzers[0].id, "deadbeef0001");
+        assert_eq!(buzzers[0].team.as_ref().map(|team| team.id), Some(buzzing));
+        assert!(buzzers[0].last_pattern.is_none());
+        assert!(buzzers[1].team.is_none());
+        assert!(buzzers[1].last_pattern.is_some());
+    }
+
+    #[tokio::test]
+    async fn kicked_buzzer_is_closed_forgotten_and_unpaired() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (game, buzzing, _) = game_with_two_teams();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        state.connect_buzzer(crate::state::BuzzerConnection {
+            id: "deadbeef0001".into(),
+            tx,
+        });
+        state
+            .buzzer_last_patterns()
+            .insert("deadbeef0001", BuzzerPatternPreset::Waiting);
+
+        kick_buzzer(&state, "deadbeef0001", true).await.unwrap();
+
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(axum::extract::ws::Message::Close(None))
+        ));
+        assert!(!state.buzzers().contains_key("deadbeef0001"));
+        assert!(state.buzzer_last_patterns().get("deadbeef0001").is_none());
+        let buzzers = state
+            .with_current_game(|game| Ok(game.teams[&buzzing].buzzer_ids.clone()))
+            .await
+            .unwrap();
+        assert!(buzzers.is_empty());
+        assert!(matches!(
+            kick_buzzer(&state, "deadbeef0001", false).await,
+            Err(ServiceError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn correct_answer_flashes_the_applause_pattern_then_restores() {
+        let state = paused_on_buzz_with(AppConfig::from_json(r#"{"applause_ms": 30}"#)).await;
+        let (buzzing, team) = state
+            .with_current_game(|game| {
+                let buzzing = game.team_id_by_buzzer("deadbeef0001").unwrap();
+                Ok((buzzing, game.teams[&buzzing].clone()))
+            })
+            .await
+            .unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        state.connect_buzzer(crate::state::BuzzerConnection {
+            id: "deadbeef0001".into(),
+            tx,
+        });
+        let answering = BuzzerPatternPreset::Answering(team.color.clone());
+        send_pattern_to_team_buzzer(&state, &buzzing, &team, answering.clone());
+        let expected = |preset| {
+            let message = crate::dto::ws::BuzzerOutboundMessage::Pattern(
+                state.config().buzzer_pattern(preset),
+            );
+            serde_json::to_string(&message).unwrap()
+        };
+        let next = |rx: &mut tokio::sync::mpsc::UnboundedReceiver<_>| match rx.try_recv() {
+            Ok(axum::extract::ws::Message::Text(text)) => text.to_string(),
+            other => panic!("expected a text message, got {other:?}"),
+        };
+        assert_eq!(next(&mut rx), expected(answering.clone()));
+
+        validate_answer(
+            &state,
+            AnswerValidationRequest {
+                valid: AnswerValidation::Correct,
+                team_id: None,
+                auto_score: false,
+                force: false,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            next(&mut rx),
+            expected(BuzzerPatternPreset::Correct(team.color.clone()))
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(next(&mut rx), expected(answering));
+    }
+
+    #[tokio::test]
+    async fn separate_bonus_scoring_leaves_bonuses_to_their_own_award() {
+        let state =
+            paused_on_buzz_with(AppConfig::from_json(r#"{"bonus_scoring": "separate"}"#)).await;
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let (buzzing, other) = state
+            .with_current_game_mut(|game| {
+                add_song(
+                    game,
+                    vec![field("title", false)],
+                    vec![field("year", false), field("label", false)],
+                );
+                game.enter_song(Some(0));
+                game.found_point_fields.push("title".into());
+                game.found_bonus_fields.push("year".into());
+                let buzzing = game.team_id_by_buzzer("deadbeef0001").unwrap();
+                let other = *game.teams.keys().find(|id| **id != buzzing).unwrap();
+                Ok((buzzing, other))
+            })
+            .await
+            .unwrap();
+        let score = |team_id: Uuid| {
+            let state = state.clone();
+            async move {
+                state
+                    .read_current_game(|game| game.unwrap().teams[&team_id].score)
+                    .await
+            }
+        };
+
+        validate_answer(
+            &state,
+            AnswerValidationRequest {
+                valid: AnswerValidation::Correct,
+                team_id: None,
+                auto_score: true,
+                force: false,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(score(buzzing).await, 1);
+
+        let award = |team_id: Uuid, keys: &[&str]| {
+            award_bonus(
+                &state,
+                BonusAwardRequest {
+                    team_id,
+                    field_keys: keys.iter().map(|key| key.to_string()).collect(),
+                    force: false,
+                },
+            )
+        };
+        assert!(matches!(
+            award(other, &["title"]).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        let awarded = award(other, &["year", "label"]).await.unwrap();
+        assert_eq!(awarded.score, 2);
+        assert_eq!(score(buzzing).await, 1);
+
+        // A bonus is awarded once per song, whichever team claims it.
+        for team_id in [other, buzzing] {
+            assert!(matches!(
+                award(team_id, &["year"]).await,
+                Err(ServiceError::InvalidState(_))
+            ));
+        }
+        assert_eq!(score(other).await, 2);
+        assert_eq!(score(buzzing).await, 1);
+        assert_eq!(
+            state
+                .read_current_game(|game| game.unwrap().found_bonus_fields.clone())
+                .await,
+            ["year", "label"]
+        );
+    }
+
+    #[tokio::test]
+    async fn active_game_stays_readable_while_degraded() {
+        for fallback in [true, false] {
+            let state = crate::state::AppState::with_config(AppConfig::from_json(&format!(
+                r#"{{"degraded_read_fallback": {fallback}}}"#
+            )));
+            let (mut game, _, _) = game_with_two_teams();
+            add_song(&mut game, vec![field("title", false)], Vec::new());
+            game.enter_song(Some(0));
+            let game_id = game.id;
+            state
+                .with_current_game_slot_mut(|slot| {
+                    slot.replace(game);
+                })
+                .await;
+            for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+                state
+                    .run_transition(event, || async { Ok(()) })
+                    .await
+                    .unwrap();
+            }
+            assert!(state.is_degraded().await);
+
+            let song = crate::services::public_service::get_current_song(&state)
+                .await
+                .unwrap();
+            assert_eq!(song.song.id, "1");
+            let scoreboard = crate::services::public_service::get_scoreboard(&state).await;
+            assert_eq!(scoreboard.teams.len(), 2);
+            assert!(matches!(
+                list_games(&state, ListGamesQuery::default()).await,
+                Err(ServiceError::Degraded)
+            ));
+            let stored = get_game_by_id(&state, game_id).await;
+            if fallback {
+                assert_eq!(stored.unwrap().id, game_id.to_string());
+            } else {
+                assert!(matches!(stored, Err(ServiceError::Degraded)));
+            }
+            assert!(matches!(
+                get_game_by_id(&state, Uuid::new_v4()).await,
+                Err(ServiceError::Degraded)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn degraded_drill_overrides_the_flag_and_notifies_clients() {
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let mut degraded = state.degraded_watcher();
+        let health = || crate::services::health_service::health_status(&state);
+        assert_eq!(health().await.status, "ok");
+
+        override_degraded(&state, DegradedOverrideRequest { degraded: true })
+            .await
+            .unwrap();
+        assert!(degraded.has_changed().unwrap());
+        assert!(*degraded.borrow_and_update());
+        assert!(state.degraded_overridden());
+        assert_eq!(health().await.status, "degraded");
+
+        override_degraded(&state, DegradedOverrideRequest { degraded: false })
+            .await
+            .unwrap();
+        assert!(!*degraded.borrow_and_update());
+        assert_eq!(health().await.status, "ok");
+
+        state.end_degraded_override();
+        assert!(!state.degraded_overridden());
     }
 }
@@ -0,0 +1,104 @@
+//! Optional periodic autosave of the current game, a safety net on top of the debounced saves
+//! triggered by each change.
+//!
+//! Every mutation of the current game marks it dirty; each tick persists the game only when it
+//! changed since the previous autosave. Saves go through [`AppState::persist_current_game`], so
+//! they share its lock and throttling with change-triggered persistence.
+//!
+//! [`AppState::persist_current_game`]: crate::state::AppState::persist_current_game
+
+use std::time::Duration;
+
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::{debug, warn};
+
+use crate::{error::ServiceError, state::SharedState};
+
+/// Start the autosave task when an interval is configured.
+///
+/// Returns the handle of the background task, or `None` when autosave is disabled.
+pub fn spawn(state: &SharedState) -> Option<JoinHandle<()>> {
+    let period = state.config().autosave_interval()?;
+    let state = state.clone();
+    Some(tokio::spawn(run(state, period)))
+}
+
+async fn run(state: SharedState, period: Duration) {
+    let mut ticks = tokio::time::interval(period);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // The first tick completes immediately; nothing can have changed yet.
+    ticks.tick().await;
+    loop {
+        ticks.tick().await;
+        if let Err(err) = autosave(&state).await {
+            warn!(error = %err, "autosave failed");
+        }
+    }
+}
+
+/// Persist the current game if it changed since the last autosave.
+///
+/// Returns whether a save was issued. A failed save keeps the game dirty for the next tick.
+async fn autosave(state: &SharedState) -> Result<bool, ServiceError> {
+    if !state.take_game_dirty() {
+        return Ok(false);
+    }
+    if state.read_current_game(|game| game.is_none()).await {
+        return Ok(false);
+    }
+    if let Err(err) = state.persist_current_game().await {
+        state.mark_game_dirty();
+        return Err(err);
+    }
+    debug!("current game autosaved");
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use indexmap::IndexMap;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        dao::game_store::testing::RecordingStore,
+        state::{
+            AppState,
+            game::{GameSession, Playlist},
+        },
+    };
+
+    #[tokio::test]
+    async fn unchanged_game_is_not_saved_again() {
+        let state = AppState::new();
+        let store = Arc::new(RecordingStore::default());
+        state.set_game_store(store.clone()).await;
+        let game = GameSession::new(
+            Uuid::new_v4(),
+            "autosave".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        assert!(autosave(&state).await.unwrap());
+        assert!(!autosave(&state).await.unwrap());
+        assert_eq!(store.games.lock().unwrap().len(), 1);
+
+        state
+            .with_current_game_mut(|game| {
+                game.name = "renamed".into();
+                Ok(())
+            })
+            .await
+            .unwrap();
+        assert!(autosave(&state).await.unwrap());
+    }
+}
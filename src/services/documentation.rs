@@ -1,21 +1,80 @@
-use utoipa::OpenApi;
+use serde::Serialize;
+use utoipa::{
+    OpenApi, ToSchema,
+    openapi::{Components, Ref},
+};
+
+use crate::{
+    dto::{
+        game::{GameSummary, TeamSummary},
+        sse::{
+            AnswerCountdownEvent, AnswerValidationEvent, BatteryLevelEvent, BuzzCueEvent,
+            BuzzSpentEvent, FieldsFoundEvent, GameFinishedEvent, Handshake, LowBatteryEvent,
+            PairingAssignedEvent, PairingConflictEvent, PairingRestoredEvent, PairingWaitingEvent,
+            PhaseChangedEvent, ReactionEvent, ScoresVisibilityEvent, SnapshotEvent,
+            SpectatorsEvent, SystemStatus, TeamCreatedEvent, TeamDeletedEvent, TeamUpdatedEvent,
+            TestBuzzEvent,
+        },
+        ws::{
+            BuzzerInboundMessage, BuzzerOutboundMessage, PublicInboundMessage,
+            PublicOutboundMessage,
+        },
+    },
+    services::sse_events::*,
+};
 
 #[derive(OpenApi)]
 /// Aggregated OpenAPI specification for Neon Beat Back.
 #[openapi(
     paths(
         crate::routes::health::healthcheck,
+        crate::routes::health::liveness,
+        crate::routes::health::readiness,
         crate::routes::sse::public_stream,
         crate::routes::sse::admin_stream,
         crate::routes::websocket::ws_handler,
+        crate::routes::websocket::public_ws_handler,
         crate::routes::public::get_teams,
+        crate::routes::public::get_scoreboard,
         crate::routes::public::get_current_song,
+        crate::routes::public::get_public_song,
         crate::routes::public::get_game_phase,
         crate::routes::public::get_pairing_status,
+        crate::routes::public::get_spectators,
+        crate::routes::public::react,
         crate::routes::admin::list_games,
         crate::routes::admin::list_playlists,
         crate::routes::admin::create_playlist,
+        crate::routes::admin::update_playlist,
+        crate::routes::admin::add_song,
+        crate::routes::admin::remove_song,
+        crate::routes::admin::reorder_playlist,
+        crate::routes::admin::delete_playlist,
+        crate::routes::admin::import_playlist_csv,
+        crate::routes::admin::get_playlist_analytics,
+        crate::routes::admin::preview_pattern,
+        crate::routes::admin::get_config,
+        crate::routes::admin::flush_storage,
+        crate::routes::admin::override_degraded,
+        crate::routes::admin::list_buzzers,
+        crate::routes::admin::list_unassigned_buzzers,
+        crate::routes::admin::get_buzzer_team,
+        crate::routes::admin::identify_buzzer,
+        crate::routes::admin::kick_buzzer,
+        crate::routes::admin::set_required_fields,
+        crate::routes::admin::round_deltas,
+        crate::routes::admin::current_song_fields,
+        crate::routes::admin::set_scores_visibility,
+        crate::routes::admin::reset_scores,
+        crate::routes::admin::rename_game,
+        crate::routes::admin::update_game,
+        crate::routes::admin::undo_score_adjustment,
+        crate::routes::admin::reorder_teams,
         crate::routes::admin::get_game_by_id,
+        crate::routes::admin::export_game,
+        crate::routes::admin::import_game,
+        crate::routes::admin::duplicate_game,
+        crate::routes::admin::get_leaderboard,
         crate::routes::admin::delete_game,
         crate::routes::admin::load_game,
         crate::routes::admin::create_game,
@@ -23,17 +82,26 @@ use utoipa::OpenApi;
         crate::routes::admin::start_game,
         crate::routes::admin::pause_game,
         crate::routes::admin::resume_game,
+        crate::routes::admin::manual_buzz,
+        crate::routes::admin::next_queued_buzz,
         crate::routes::admin::reveal_song,
+        crate::routes::admin::reveal_partial,
         crate::routes::admin::next_song,
+        crate::routes::admin::skip_song,
+        crate::routes::admin::previous_song,
         crate::routes::admin::stop_game,
         crate::routes::admin::end_game,
+        crate::routes::admin::replay_game,
         crate::routes::admin::mark_field_found,
         crate::routes::admin::validate_answer,
+        crate::routes::admin::award_bonus,
         crate::routes::admin::adjust_score,
         crate::routes::admin::create_team,
+        crate::routes::admin::create_teams,
         crate::routes::admin::update_team,
         crate::routes::admin::delete_team,
         crate::routes::admin::start_pairing,
+        crate::routes::admin::assign_pairing_buzzer,
         crate::routes::admin::abort_pairing,
     ),
     components(
@@ -43,11 +111,17 @@ use utoipa::OpenApi;
             crate::dto::common::SongSnapshot,
             crate::dto::health::HealthResponse,
             crate::dto::ws::BuzzerInboundMessage,
+            crate::dto::ws::BuzzerOutboundMessage,
+            crate::dto::ws::PublicInboundMessage,
+            crate::dto::ws::PublicOutboundMessage,
+            crate::dto::ws::BuzzerPattern,
+            crate::dto::ws::BuzzerPatternDetails,
             crate::dto::game::CreateGameWithPlaylistRequest,
             crate::dto::game::TeamInput,
             crate::dto::game::PlaylistInput,
             crate::dto::game::SongInput,
             crate::dto::game::TeamSummary,
+            crate::dto::game::RankedTeamSummary,
             crate::dto::game::GameSummary,
             crate::dto::game::PlaylistSummary,
             crate::dto::game::SongSummary,
@@ -57,6 +131,7 @@ use utoipa::OpenApi;
             crate::dto::sse::FieldsFoundEvent,
             crate::dto::sse::AnswerValidationEvent,
             crate::dto::sse::PhaseChangedEvent,
+            crate::dto::sse::SnapshotEvent,
             crate::dto::sse::PairingWaitingEvent,
             crate::dto::sse::PairingAssignedEvent,
             crate::dto::sse::PairingRestoredEvent,
@@ -64,27 +139,80 @@ use utoipa::OpenApi;
             crate::dto::sse::TeamCreatedEvent,
             crate::dto::sse::TeamUpdatedEvent,
             crate::dto::sse::TeamDeletedEvent,
+            crate::dto::sse::ScoresVisibilityEvent,
+            crate::dto::sse::BuzzCueEvent,
+            crate::dto::sse::BuzzSpentEvent,
+            crate::dto::sse::ReactionEvent,
+            crate::dto::sse::GameFinishedEvent,
+            crate::dto::sse::AnswerCountdownEvent,
+            crate::dto::sse::BatteryLevelEvent,
+            crate::dto::sse::LowBatteryEvent,
+            crate::dto::sse::SpectatorsEvent,
+            crate::dto::admin::AnswerCountdownSettings,
+            crate::config::CountdownExpiry,
+            crate::config::ColorAssignment,
+            crate::config::NonAnsweringPattern,
+            crate::config::TeamNameCharset,
+            crate::config::LeaderboardKey,
+            crate::config::BonusScoring,
             crate::dto::admin::GameListItem,
+            crate::dto::admin::ExportedGame,
+            crate::dto::admin::LeaderboardEntry,
+            crate::dto::admin::GameFinishReason,
             crate::dto::admin::PlaylistListItem,
+            crate::dto::admin::PlaylistAnalyticsResponse,
+            crate::dto::admin::PatternPresetName,
+            crate::dto::admin::ConfigResponse,
+            crate::dto::admin::ConfigPatterns,
+            crate::dto::admin::DefaultTeamNames,
+            crate::dto::admin::EventRecordingSettings,
+            crate::dto::admin::RequiredFieldsRequest,
+            crate::dto::admin::RoundDelta,
+            crate::dto::admin::CurrentSongFieldsResponse,
+            crate::dto::admin::SongFieldStatus,
+            crate::dto::admin::ScoresVisibilityRequest,
+            crate::dto::admin::BonusAwardRequest,
+            crate::dto::admin::DegradedOverrideRequest,
+            crate::dto::admin::ReorderSongsRequest,
+            crate::dto::admin::ReorderTeamsRequest,
+            crate::dto::admin::FlushResponse,
+            crate::dto::admin::BuzzerAssignmentResponse,
+            crate::dto::admin::ConnectedBuzzer,
+            crate::dto::admin::CsvRowError,
+            crate::dto::admin::CsvImportErrorResponse,
+            crate::dto::admin::PairingAssignRequest,
+            crate::dto::admin::ManualBuzzRequest,
+            crate::dto::sse::PairingConflictEvent,
+            crate::dto::admin::SongAnalytics,
             crate::dto::admin::CreateGameRequest,
             crate::dto::admin::FieldKind,
             crate::dto::admin::MarkFieldRequest,
             crate::dto::admin::FieldsFoundResponse,
             crate::dto::admin::AnswerValidationRequest,
             crate::dto::admin::ScoreAdjustmentRequest,
+            crate::dto::admin::ScoreUndoRequest,
+            crate::dto::admin::RenameGameRequest,
             crate::dto::admin::ActionResponse,
             crate::dto::admin::ScoreUpdateResponse,
             crate::dto::admin::StartGameResponse,
             crate::dto::admin::NextSongResponse,
+            crate::dto::admin::PreviousSongResponse,
             crate::dto::admin::StopGameResponse,
             crate::dto::admin::CreateTeamRequest,
             crate::dto::admin::UpdateTeamRequest,
             crate::dto::admin::StartPairingRequest,
             crate::dto::phase::VisibleGamePhase,
+            crate::dto::phase::AdminAction,
             crate::dto::public::TeamsResponse,
+            crate::dto::public::ScoreboardEntry,
+            crate::dto::public::ScoreboardResponse,
             crate::dto::public::CurrentSongResponse,
+            crate::dto::public::PublicSongField,
+            crate::dto::public::PublicSongResponse,
             crate::dto::public::GamePhaseResponse,
+            crate::dto::public::ReactionRequest,
             crate::dto::public::PairingStatusResponse,
+            crate::dto::public::SpectatorsResponse,
         )
     ),
     tags(
@@ -97,3 +225,178 @@ use utoipa::OpenApi;
     )
 )]
 pub struct ApiDoc;
+
+/// Stream an SSE event is broadcast on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventStream {
+    /// `/sse/public`.
+    Public,
+    /// `/sse/admin`.
+    Admin,
+}
+
+/// Direction of a WebSocket message relative to the backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageDirection {
+    /// Sent by the client to the backend.
+    Inbound,
+    /// Sent by the backend to the client.
+    Outbound,
+}
+
+/// SSE event name together with the streams carrying it and its payload schema.
+#[derive(Serialize)]
+pub struct SseEventDoc {
+    /// Value of the SSE `event:` field.
+    pub event: &'static str,
+    /// Streams the event is broadcast on.
+    pub streams: &'static [EventStream],
+    /// Reference to the payload schema in `components`.
+    pub schema: Ref,
+}
+
+/// WebSocket message schema exchanged on a given endpoint.
+#[derive(Serialize)]
+pub struct WebSocketMessageDoc {
+    /// WebSocket endpoint path.
+    pub path: &'static str,
+    /// Whether the client or the backend sends the message.
+    pub direction: MessageDirection,
+    /// Reference to the message schema in `components`.
+    pub schema: Ref,
+}
+
+/// Catalog of the realtime messages, which OpenAPI paths cannot describe on their own.
+#[derive(Serialize)]
+pub struct EventsDoc {
+    /// Events sent on the SSE streams.
+    pub sse: Vec<SseEventDoc>,
+    /// Messages exchanged on the WebSocket endpoints.
+    pub websocket: Vec<WebSocketMessageDoc>,
+    /// Schemas referenced by the entries above.
+    pub components: Option<Components>,
+}
+
+const PUBLIC: &[EventStream] = &[EventStream::Public];
+const ADMIN: &[EventStream] = &[EventStream::Admin];
+const BOTH: &[EventStream] = &[EventStream::Public, EventStream::Admin];
+
+fn sse_event<T: ToSchema>(event: &'static str, streams: &'static [EventStream]) -> SseEventDoc {
+    SseEventDoc {
+        event,
+        streams,
+        schema: Ref::from_schema_name(T::name()),
+    }
+}
+
+fn ws_message<T: ToSchema>(path: &'static str, direction: MessageDirection) -> WebSocketMessageDoc {
+    WebSocketMessageDoc {
+        path,
+        direction,
+        schema: Ref::from_schema_name(T::name()),
+    }
+}
+
+/// Build the catalog of SSE events and WebSocket messages served at `/docs/events`.
+pub fn events_doc() -> EventsDoc {
+    let sse = vec![
+        sse_event::<Handshake>("handshake", BOTH),
+        sse_event::<SystemStatus>("system_status", BOTH),
+        sse_event::<PhaseChangedEvent>(EVENT_PHASE_CHANGED, BOTH),
+        sse_event::<SnapshotEvent>(EVENT_SNAPSHOT, PUBLIC),
+        sse_event::<GameSummary>(EVENT_GAME_SESSION, PUBLIC),
+        sse_event::<FieldsFoundEvent>(EVENT_FIELDS_FOUND, PUBLIC),
+        sse_event::<AnswerValidationEvent>(EVENT_ANSWER_VALIDATION, PUBLIC),
+        sse_event::<TeamSummary>(EVENT_SCORE_ADJUSTMENT, PUBLIC),
+        sse_event::<TeamCreatedEvent>(EVENT_TEAM_CREATED, BOTH),
+        sse_event::<TeamUpdatedEvent>(EVENT_TEAM_UPDATED, PUBLIC),
+        sse_event::<TeamDeletedEvent>(EVENT_TEAM_DELETED, PUBLIC),
+        sse_event::<PairingWaitingEvent>(EVENT_PAIRING_WAITING, BOTH),
+        sse_event::<PairingAssignedEvent>(EVENT_PAIRING_ASSIGNED, BOTH),
+        sse_event::<PairingRestoredEvent>(EVENT_PAIRING_RESTORED, PUBLIC),
+        sse_event::<PairingConflictEvent>(EVENT_PAIRING_CONFLICT, BOTH),
+        sse_event::<TestBuzzEvent>(EVENT_TEST_BUZZ, BOTH),
+        sse_event::<ScoresVisibilityEvent>(EVENT_SCORES_VISIBILITY, BOTH),
+        sse_event::<BuzzCueEvent>(EVENT_BUZZ_CUE, BOTH),
+        sse_event::<BuzzSpentEvent>(EVENT_BUZZ_SPENT, BOTH),
+        sse_event::<ReactionEvent>(EVENT_REACTION, PUBLIC),
+        sse_event::<SpectatorsEvent>(EVENT_SPECTATORS, BOTH),
+        sse_event::<GameFinishedEvent>(EVENT_GAME_FINISHED, BOTH),
+        sse_event::<AnswerCountdownEvent>(EVENT_ANSWER_COUNTDOWN, BOTH),
+        sse_event::<BatteryLevelEvent>(EVENT_BUZZER_BATTERY, ADMIN),
+        sse_event::<LowBatteryEvent>(EVENT_BUZZER_LOW_BATTERY, ADMIN),
+    ];
+    let websocket = vec![
+        ws_message::<BuzzerInboundMessage>("/ws", MessageDirection::Inbound),
+        ws_message::<BuzzerOutboundMessage>("/ws", MessageDirection::Outbound),
+        ws_message::<PublicInboundMessage>("/ws/public", MessageDirection::Inbound),
+        ws_message::<PublicOutboundMessage>("/ws/public", MessageDirection::Outbound),
+    ];
+
+    EventsDoc {
+        sse,
+        websocket,
+        components: ApiDoc::openapi().components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_doc_lists_every_event_with_a_known_schema() {
+        let doc = serde_json::to_value(events_doc()).unwrap();
+        let schemas = doc["components"]["schemas"].as_object().unwrap();
+        let documented: Vec<&str> = doc["sse"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["event"].as_str().unwrap())
+            .collect();
+
+        for event in [
+            "handshake",
+            "system_status",
+            EVENT_FIELDS_FOUND,
+            EVENT_ANSWER_VALIDATION,
+            EVENT_SCORE_ADJUSTMENT,
+            EVENT_PHASE_CHANGED,
+            EVENT_TEAM_CREATED,
+            EVENT_TEAM_UPDATED,
+            EVENT_PAIRING_WAITING,
+            EVENT_PAIRING_ASSIGNED,
+            EVENT_PAIRING_RESTORED,
+            EVENT_PAIRING_CONFLICT,
+            EVENT_TEST_BUZZ,
+            EVENT_TEAM_DELETED,
+            EVENT_GAME_SESSION,
+            EVENT_SCORES_VISIBILITY,
+            EVENT_BUZZ_CUE,
+            EVENT_BUZZ_SPENT,
+            EVENT_REACTION,
+            EVENT_GAME_FINISHED,
+            EVENT_ANSWER_COUNTDOWN,
+            EVENT_BUZZER_BATTERY,
+            EVENT_BUZZER_LOW_BATTERY,
+        ] {
+            assert!(documented.contains(&event), "{event} is not documented");
+        }
+
+        let entries = doc["sse"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .chain(doc["websocket"].as_array().unwrap());
+        for entry in entries {
+            let reference = entry["schema"]["$ref"].as_str().unwrap();
+            let name = reference.strip_prefix("#/components/schemas/").unwrap();
+            assert!(
+                schemas.contains_key(name),
+                "{name} is missing from components"
+            );
+        }
+    }
+}
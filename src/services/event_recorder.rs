@@ -0,0 +1,212 @@
+//! Optional recorder appending every broadcast SSE event to a newline-delimited JSON file, for
+//! post-event analysis and replay.
+//!
+//! Each line holds the stream name, the event type, its JSON payload and an RFC3339 timestamp.
+//! When the file would grow past the configured size it is rotated to `<path>.1`, replacing any
+//! previous rotation.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::Serialize;
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use tokio::{
+    fs::{self, File, OpenOptions},
+    io::AsyncWriteExt,
+    sync::broadcast::{Receiver, error::RecvError},
+    task::JoinHandle,
+};
+use tracing::{info, warn};
+
+use crate::{config::EventRecordingConfig, dto::sse::ServerEvent, state::SharedState};
+
+/// Single recorded event as written to the NDJSON file.
+#[derive(Debug, Serialize)]
+struct RecordedEvent<'a> {
+    timestamp: String,
+    stream: &'a str,
+    event: Option<&'a str>,
+    data: serde_json::Value,
+}
+
+/// Start recording the public and admin SSE streams when configured.
+///
+/// Returns the handle of the background task, or `None` when recording is disabled.
+pub fn spawn(state: &SharedState) -> Option<JoinHandle<()>> {
+    let config = state.config().event_recording()?.clone();
+    let public = state.public_sse().subscribe();
+    let admin = state.admin_sse().subscribe();
+    Some(tokio::spawn(async move {
+        if let Err(err) = record(config, public, admin).await {
+            warn!(error = %err, "SSE event recorder stopped");
+        }
+    }))
+}
+
+/// Append events from both streams until they are closed.
+async fn record(
+    config: EventRecordingConfig,
+    mut public: Receiver<ServerEvent>,
+    mut admin: Receiver<ServerEvent>,
+) -> io::Result<()> {
+    let mut writer = NdjsonWriter::open(config.path, config.max_bytes).await?;
+    info!(path = %writer.path.display(), "recording SSE events");
+
+    let mut public_open = true;
+    let mut admin_open = true;
+    while public_open || admin_open {
+        let (stream, received) = tokio::select! {
+            received = public.recv(), if public_open => ("public", received),
+            received = admin.recv(), if admin_open => ("admin", received),
+        };
+        match received {
+            Ok(event) => writer.write_event(stream, &event).await?,
+            Err(RecvError::Lagged(skipped)) => {
+                warn!(stream, skipped, "SSE event recorder lagged behind");
+            }
+            Err(RecvError::Closed) if stream == "public" => public_open = false,
+            Err(RecvError::Closed) => admin_open = false,
+        }
+    }
+    Ok(())
+}
+
+/// Append-only NDJSON file capped in size through a single-slot rotation.
+struct NdjsonWriter {
+    path: PathBuf,
+    max_bytes: u64,
+    file: File,
+    written: u64,
+}
+
+impl NdjsonWriter {
+    async fn open(path: PathBuf, max_bytes: u64) -> io::Result<Self> {
+        let file = open_append(&path).await?;
+        let written = file.metadata().await?.len();
+        Ok(Self {
+            path,
+            max_bytes,
+            file,
+            written,
+        })
+    }
+
+    async fn write_event(&mut self, stream: &str, event: &ServerEvent) -> io::Result<()> {
+        let record = RecordedEvent {
+            timestamp: OffsetDateTime::from(SystemTime::now())
+                .format(&Rfc3339)
+                .unwrap_or_default(),
+            stream,
+            event: event.event.as_deref(),
+            data: serde_json::from_str(&event.data)
+                .unwrap_or_else(|_| serde_json::Value::String(event.data.clone())),
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+
+        let len = line.len() as u64;
+        if self.written > 0 && self.written + len > self.max_bytes {
+            self.rotate().await?;
+        }
+        self.file.write_all(&line).await?;
+        self.file.flush().await?;
+        self.written += len;
+        Ok(())
+    }
+
+    async fn rotate(&mut self) -> io::Result<()> {
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        fs::rename(&self.path, &rotated).await?;
+        self.file = open_append(&self.path).await?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+async fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::broadcast;
+
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir().join(format!("neon-beat-events-{}.ndjson", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn recorded_events_parse_back_as_json_lines() {
+        let path = temp_path();
+        let (public_tx, public_rx) = broadcast::channel(8);
+        let (admin_tx, admin_rx) = broadcast::channel(8);
+        let config = EventRecordingConfig {
+            path: path.clone(),
+            max_bytes: 1024 * 1024,
+        };
+        let recorder = tokio::spawn(record(config, public_rx, admin_rx));
+
+        public_tx
+            .send(
+                ServerEvent::json(
+                    Some("game.session".to_string()),
+                    &serde_json::json!({"id": 1}),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        admin_tx
+            .send(ServerEvent::json(None::<String>, &serde_json::json!([1, 2])).unwrap())
+            .unwrap();
+        drop(public_tx);
+        drop(admin_tx);
+        recorder.await.unwrap().unwrap();
+
+        let contents = fs::read_to_string(&path).await.unwrap();
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(lines.len(), 2);
+        let public = lines
+            .iter()
+            .find(|line| line["stream"] == "public")
+            .unwrap();
+        assert_eq!(public["event"], "game.session");
+        assert_eq!(public["data"]["id"], 1);
+        assert!(public["timestamp"].is_string());
+        let admin = lines.iter().find(|line| line["stream"] == "admin").unwrap();
+        assert!(admin["event"].is_null());
+        assert_eq!(admin["data"], serde_json::json!([1, 2]));
+    }
+
+    #[tokio::test]
+    async fn oversized_file_is_rotated() {
+        let path = temp_path();
+        let mut writer = NdjsonWriter::open(path.clone(), 64).await.unwrap();
+        let event = ServerEvent::json(Some("tick".to_string()), &"x".repeat(40)).unwrap();
+        writer.write_event("public", &event).await.unwrap();
+        writer.write_event("public", &event).await.unwrap();
+
+        let mut rotated = path.clone().into_os_string();
+        rotated.push(".1");
+        let current = fs::read_to_string(&path).await.unwrap();
+        let previous = fs::read_to_string(&rotated).await.unwrap();
+        fs::remove_file(&path).await.unwrap();
+        fs::remove_file(&rotated).await.unwrap();
+
+        assert_eq!(current.lines().count(), 1);
+        assert_eq!(previous.lines().count(), 1);
+    }
+}
@@ -1,18 +1,29 @@
 use std::{collections::HashSet, time::SystemTime};
 
 use indexmap::IndexMap;
-use rand::{rng, seq::SliceRandom};
+use tracing::debug;
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
     config::AppConfig,
-    dao::models::{GameEntity, PlaylistEntity},
-    dto::game::{GameSummary, PlaylistInput, PlaylistSummary, SongInput, TeamInput},
+    dao::{
+        game_store::GameStore,
+        models::{GameEntity, PlaylistEntity, TeamEntity},
+    },
+    dto::{
+        admin::{ExportedGame, GAME_EXPORT_VERSION},
+        common::TeamColorDto,
+        game::{
+            GameSummary, PlaylistInput, PlaylistSummary, SongInput, TeamInput, check_complete_order,
+        },
+    },
     error::ServiceError,
     services::sse_events,
     state::{
         self, SharedState,
-        game::{GameSession, Playlist, PointField, Song, Team},
+        game::{GameSession, Playlist, PointField, Song, Team, TeamColor},
+        ids::IdGenerator,
     },
 };
 
@@ -22,7 +33,6 @@ pub async fn create_playlist(
     request: PlaylistInput,
 ) -> Result<(PlaylistSummary, Playlist), ServiceError> {
     let PlaylistInput { name, songs } = request;
-    tracing::warn!("SONGS: {:?}", songs);
 
     if songs.is_empty() {
         return Err(ServiceError::InvalidInput(
@@ -30,23 +40,226 @@ pub async fn create_playlist(
         ));
     }
 
-    let playlist = build_playlist(songs, name)?;
-    tracing::warn!("PLAYLIST: {:?}", playlist);
+    let playlist = build_playlist(state.ids().next_id(), songs, name)?;
+    if state.config().log_answers() {
+        debug!(?playlist, "playlist built");
+    } else {
+        debug!(playlist_id = %playlist.id, songs = playlist.songs.len(), "playlist built");
+    }
 
     // Preserve deterministic ordering based on the assigned song identifiers.
     let song_count = playlist.songs.len() as u32;
     let order: Vec<u32> = (0..song_count).collect();
     let summary: PlaylistSummary = (playlist.clone(), order).into();
-    tracing::warn!("SUMMARY: {:?}", playlist);
 
     let entity: PlaylistEntity = playlist.clone().into();
-    tracing::warn!("ENTITY: {:?}", playlist);
     let store = state.require_game_store().await?;
+    if store.find_playlist(entity.id).await?.is_some() {
+        return Err(ServiceError::Conflict(format!(
+            "playlist `{}` already exists",
+            entity.id
+        )));
+    }
     store.save_playlist(entity).await?;
 
     Ok((summary, playlist))
 }
 
+/// Replace the name and songs of a stored playlist, mirroring them into the active game.
+///
+/// Stored games reference songs by position, so a playlist used by a game must keep its number
+/// of songs.
+pub async fn update_playlist(
+    state: &SharedState,
+    id: Uuid,
+    request: PlaylistInput,
+) -> Result<PlaylistSummary, ServiceError> {
+    let PlaylistInput { name, songs } = request;
+
+    if songs.is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "playlist songs must not be empty".into(),
+        ));
+    }
+
+    let playlist = build_playlist(id, songs, name)?;
+    let store = state.require_game_store().await?;
+    let Some(previous) = store.find_playlist(id).await? else {
+        return Err(ServiceError::NotFound(format!("playlist `{id}` not found")));
+    };
+    if previous.songs.len() != playlist.songs.len() {
+        ensure_song_count_can_change(store.as_ref(), id).await?;
+    }
+    store.save_playlist(playlist.clone().into()).await?;
+
+    state
+        .with_current_game_slot_mut(|slot| {
+            if let Some(game) = slot.as_mut().filter(|game| game.playlist.id == id) {
+                game.playlist = playlist.clone();
+            }
+        })
+        .await;
+
+    let order = (0..playlist.songs.len() as u32).collect();
+    Ok((playlist, order).into())
+}
+
+/// Rename a stored playlist, and the playlist of the active game when it is the same one.
+pub async fn rename_playlist(
+    state: &SharedState,
+    id: Uuid,
+    name: String,
+) -> Result<(), ServiceError> {
+    let store = state.require_game_store().await?;
+    let Some(mut playlist) = store.find_playlist(id).await? else {
+        return Err(ServiceError::NotFound(format!("playlist `{id}` not found")));
+    };
+    playlist.name = name.clone();
+    store.save_playlist(playlist).await?;
+
+    state
+        .with_current_game_slot_mut(|slot| {
+            if let Some(game) = slot.as_mut().filter(|game| game.playlist.id == id) {
+                game.playlist.name = name;
+            }
+        })
+        .await;
+    Ok(())
+}
+
+/// Append a song to a stored playlist, after the songs of its default order.
+///
+/// Refused while a stored game references the playlist, as for any change to its number of
+/// songs.
+pub async fn add_song(
+    state: &SharedState,
+    id: Uuid,
+    song: SongInput,
+) -> Result<PlaylistSummary, ServiceError> {
+    let song = build_song(song)?;
+    let store = state.require_game_store().await?;
+    let Some(entity) = store.find_playlist(id).await? else {
+        return Err(ServiceError::NotFound(format!("playlist `{id}` not found")));
+    };
+    ensure_song_count_can_change(store.as_ref(), id).await?;
+
+    let mut playlist = Playlist::from(entity);
+    let song_id = playlist.songs.keys().max().map_or(0, |last| last + 1);
+    playlist.songs.insert(song_id, song);
+    store.save_playlist(playlist.clone().into()).await?;
+
+    let order = playlist.songs.keys().copied().collect();
+    Ok((playlist, order).into())
+}
+
+/// Remove a song from a stored playlist and from its default order.
+///
+/// Song ids are positions in the stored playlist, so the songs after the removed one move down
+/// by one id. Refused while a stored game references the playlist, as its song order would
+/// point at other songs.
+pub async fn remove_song(
+    state: &SharedState,
+    id: Uuid,
+    song_id: u32,
+) -> Result<PlaylistSummary, ServiceError> {
+    let store = state.require_game_store().await?;
+    let Some(entity) = store.find_playlist(id).await? else {
+        return Err(ServiceError::NotFound(format!("playlist `{id}` not found")));
+    };
+    let mut playlist = Playlist::from(entity);
+    if !playlist.songs.contains_key(&song_id) {
+        return Err(ServiceError::NotFound(format!(
+            "song `{song_id}` not found in playlist `{id}`"
+        )));
+    }
+    if playlist.songs.len() == 1 {
+        return Err(ServiceError::InvalidInput(
+            "playlist songs must not be empty".into(),
+        ));
+    }
+    ensure_song_count_can_change(store.as_ref(), id).await?;
+
+    playlist.songs.shift_remove(&song_id);
+    playlist.songs = playlist
+        .songs
+        .into_iter()
+        .map(|(key, song)| (if key > song_id { key - 1 } else { key }, song))
+        .collect();
+    store.save_playlist(playlist.clone().into()).await?;
+
+    let order = playlist.songs.keys().copied().collect();
+    Ok((playlist, order).into())
+}
+
+/// Refuse to add or remove songs of a playlist referenced by a stored game, whose song order
+/// relies on the song ids.
+async fn ensure_song_count_can_change(store: &dyn GameStore, id: Uuid) -> Result<(), ServiceError> {
+    if let Some(game) = store
+        .list_games()
+        .await?
+        .into_iter()
+        .find(|game| game.playlist_id == id)
+    {
+        return Err(ServiceError::InvalidState(format!(
+            "playlist `{id}` is used by game `{}`, its number of songs cannot change",
+            game.id
+        )));
+    }
+    Ok(())
+}
+
+/// Persist the order in which new games play the songs of a stored playlist.
+///
+/// Games already created keep their own order.
+pub async fn reorder_playlist(
+    state: &SharedState,
+    id: Uuid,
+    song_ids: Vec<u32>,
+) -> Result<PlaylistSummary, ServiceError> {
+    let store = state.require_game_store().await?;
+    let Some(entity) = store.find_playlist(id).await? else {
+        return Err(ServiceError::NotFound(format!("playlist `{id}` not found")));
+    };
+    let mut playlist = Playlist::from(entity);
+    apply_song_order(&mut playlist, &song_ids)?;
+    store.save_playlist(playlist.clone().into()).await?;
+
+    Ok((playlist, song_ids).into())
+}
+
+/// Delete a stored playlist, refusing while a stored game references it.
+pub async fn delete_playlist(state: &SharedState, id: Uuid) -> Result<(), ServiceError> {
+    let store = state.require_game_store().await?;
+    if let Some(game) = store
+        .list_games()
+        .await?
+        .into_iter()
+        .find(|game| game.playlist_id == id)
+    {
+        return Err(ServiceError::InvalidState(format!(
+            "playlist `{id}` is used by game `{}`",
+            game.id
+        )));
+    }
+
+    if store.delete_playlist(id).await? {
+        Ok(())
+    } else {
+        Err(ServiceError::NotFound(format!("playlist `{id}` not found")))
+    }
+}
+
+/// Gameplay options chosen when a game is created.
+#[derive(Debug, Default)]
+pub struct GameOptions {
+    /// Seed used to shuffle the playlist order, which is kept as-is when `None`.
+    pub shuffle_seed: Option<u64>,
+    /// Block reveal and next song until the required fields of the current song are found.
+    pub enforce_required_fields: bool,
+    /// Only play the songs carrying at least one of these tags (all songs when empty).
+    pub tags: Vec<String>,
+}
+
 /// Bootstrap a fresh game during the idle state (with or without a playlist).
 pub async fn create_game(
     state: &SharedState,
@@ -54,7 +267,7 @@ pub async fn create_game(
     teams: Vec<TeamInput>,
     playlist_id: Uuid,
     playlist: Option<Playlist>,
-    shuffle_playlist: bool,
+    options: GameOptions,
 ) -> Result<GameSummary, ServiceError> {
     ensure_idle(state).await?;
     let config = state.config();
@@ -65,7 +278,8 @@ pub async fn create_game(
         ));
     }
 
-    let teams = build_teams(teams, config.as_ref())?;
+    let ids = state.ids();
+    let teams = build_teams(teams, config.as_ref(), &ids)?;
 
     let playlist = match playlist {
         Some(p) => p,
@@ -84,11 +298,22 @@ pub async fn create_game(
         ));
     }
 
-    let game = GameSession::new(name, teams, playlist, shuffle_playlist);
+    let mut game = GameSession::new(ids.next_id(), name, teams, playlist, options.shuffle_seed);
+    game.enforce_required_fields = options.enforce_required_fields;
+    filter_song_order_by_tags(&mut game, &options.tags)?;
     if game.playlist_song_order.is_empty() {
         panic!("playlist_song_order should not be empty")
     };
 
+    // Never overwrite a stored game that happens to share the freshly minted id.
+    let store = state.require_game_store().await?;
+    if store.find_game(game.id).await?.is_some() {
+        return Err(ServiceError::Conflict(format!(
+            "game `{}` already exists",
+            game.id
+        )));
+    }
+
     state
         .with_current_game_slot_mut(|slot| {
             *slot = Some(game.clone());
@@ -105,19 +330,32 @@ pub async fn create_game(
     Ok(game.into())
 }
 
+/// Fetch a stored game, failing with `NotFound` when it does not exist.
+pub async fn find_game(state: &SharedState, id: Uuid) -> Result<GameEntity, ServiceError> {
+    let store = state.require_game_store().await?;
+    store
+        .find_game(id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("game `{id}` not found")))
+}
+
 /// Load an existing game from the database into the shared state.
+///
+/// Finished games are loaded as-is so their final standings can be displayed again.
 pub async fn load_game(
     state: &SharedState,
-    id: Uuid,
-    shuffle_playlist: bool,
-) -> Result<GameSummary, ServiceError> {
+    game: GameEntity,
+    shuffle_seed: Option<u64>,
+) -> Result<GameSession, ServiceError> {
     ensure_idle(state).await?;
 
     let store = state.require_game_store().await?;
 
-    let Some(game) = store.find_game(id).await? else {
-        return Err(ServiceError::NotFound(format!("game `{id}` not found")));
-    };
+    if shuffle_seed.is_some() && game.finished {
+        return Err(ServiceError::InvalidInput(
+            "shuffle parameter cannot be used: game is already finished".into(),
+        ));
+    }
 
     if game.playlist_song_order.is_empty() {
         panic!("playlist_song_order should not be empty")
@@ -140,7 +378,7 @@ pub async fn load_game(
         // Playlist was completed in the previous session
         false
     };
-    if shuffle_playlist && is_playlist_in_progress {
+    if shuffle_seed.is_some() && is_playlist_in_progress {
         return Err(ServiceError::InvalidInput(
             "shuffle parameter cannot be used: game is already in progress".into(),
         ));
@@ -163,9 +401,8 @@ pub async fn load_game(
 
     let mut game_session: GameSession = (game, playlist).into();
 
-    if shuffle_playlist {
-        let mut rng = rng();
-        game_session.playlist_song_order.shuffle(&mut rng);
+    if let Some(seed) = shuffle_seed {
+        game_session.shuffle_songs(seed);
         game_session.updated_at = SystemTime::now();
     };
 
@@ -178,13 +415,242 @@ pub async fn load_game(
     // Clear all game-scoped state from previous game
     state.clear_game_state().await;
 
-    if shuffle_playlist {
+    if shuffle_seed.is_some() {
         state.persist_current_game_without_teams().await?;
     }
 
     sse_events::broadcast_game_session(state, &game_session);
 
-    Ok(game_session.into())
+    Ok(game_session)
+}
+
+/// Persist a previously exported game and its playlist, without loading it.
+///
+/// The playlist goes through the same checks as a created one and the game through the ones
+/// applied when loading. Unless `preserve_ids` is set, the game, its teams and its playlist get
+/// fresh identifiers; otherwise an existing game with the same id is a conflict, and so is an
+/// existing playlist with the same id but different content.
+pub async fn import_game(
+    state: &SharedState,
+    exported: ExportedGame,
+    preserve_ids: bool,
+) -> Result<GameSummary, ServiceError> {
+    let ExportedGame {
+        version,
+        mut game,
+        playlist,
+    } = exported;
+
+    if version != GAME_EXPORT_VERSION {
+        return Err(ServiceError::InvalidInput(format!(
+            "unsupported export version {version} (expected {GAME_EXPORT_VERSION})"
+        )));
+    }
+    if game.playlist_id != playlist.id {
+        return Err(ServiceError::InvalidInput(format!(
+            "game references playlist `{}` but the export contains playlist `{}`",
+            game.playlist_id, playlist.id
+        )));
+    }
+    if game.name.trim().is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "game name must not be empty".into(),
+        ));
+    }
+    validate_imported_teams(&game.teams)?;
+
+    let input = PlaylistInput {
+        name: playlist.name.clone(),
+        songs: playlist.songs.iter().cloned().map(Into::into).collect(),
+    };
+    input
+        .validate()
+        .map_err(|err| ServiceError::InvalidInput(err.to_string()))?;
+    let mut checked = build_playlist(playlist.id, input.songs, input.name)?;
+    if !playlist.song_order.is_empty() {
+        apply_song_order(&mut checked, &playlist.song_order)?;
+    }
+    // Stored as exported so that re-importing with `preserve_ids` matches the original.
+    let ids = state.ids();
+    let playlist = PlaylistEntity {
+        id: if preserve_ids {
+            playlist.id
+        } else {
+            ids.next_id()
+        },
+        ..playlist
+    };
+    validate_persisted_game(&game, &playlist).map_err(|err| match err {
+        ServiceError::InvalidState(message) => ServiceError::InvalidInput(message),
+        other => other,
+    })?;
+
+    if !preserve_ids {
+        game.id = ids.next_id();
+        game.playlist_id = playlist.id;
+        for team in &mut game.teams {
+            team.id = ids.next_id();
+        }
+    }
+
+    let store = state.require_game_store().await?;
+    if store.find_game(game.id).await?.is_some() {
+        return Err(ServiceError::Conflict(format!(
+            "game `{}` already exists",
+            game.id
+        )));
+    }
+    match store.find_playlist(playlist.id).await? {
+        Some(existing) if existing != playlist => {
+            return Err(ServiceError::Conflict(format!(
+                "playlist `{}` already exists with different songs",
+                playlist.id
+            )));
+        }
+        Some(_) => {}
+        None => store.save_playlist(playlist.clone()).await?,
+    }
+    store.save_game(game.clone()).await?;
+
+    let session: GameSession = (game, playlist).into();
+    Ok(session.into())
+}
+
+/// Persist a pristine copy of a stored game, without loading it, to replay it with the same teams
+/// and songs.
+///
+/// The copy gets fresh game and team identifiers, zeroed scores and restarts from the first song
+/// of the same song order. It references the same playlist unless `copy_playlist` is set, in
+/// which case the playlist is stored again under a fresh identifier.
+pub async fn duplicate_game(
+    state: &SharedState,
+    id: Uuid,
+    copy_playlist: bool,
+) -> Result<GameSummary, ServiceError> {
+    let store = state.require_game_store().await?;
+    let Some(game) = store.find_game(id).await? else {
+        return Err(ServiceError::NotFound(format!("game `{id}` not found")));
+    };
+    let Some(mut playlist) = store.find_playlist(game.playlist_id).await? else {
+        return Err(ServiceError::NotFound(format!(
+            "playlist `{}` not found",
+            game.playlist_id
+        )));
+    };
+
+    let ids = state.ids();
+    if copy_playlist {
+        playlist.id = ids.next_id();
+        store.save_playlist(playlist.clone()).await?;
+    }
+    let now = SystemTime::now();
+    let duplicate = GameEntity {
+        id: ids.next_id(),
+        name: game.name,
+        created_at: now,
+        updated_at: now,
+        teams: game
+            .teams
+            .into_iter()
+            .map(|team| TeamEntity {
+                id: ids.next_id(),
+                score: 0,
+                updated_at: now,
+                ..team
+            })
+            .collect(),
+        playlist_id: playlist.id,
+        playlist_song_order: game.playlist_song_order,
+        current_song_index: Some(0),
+        current_song_found: false,
+        enforce_required_fields: game.enforce_required_fields,
+        finished: false,
+        finish_reason: None,
+    };
+    store.save_game(duplicate.clone()).await?;
+
+    let session: GameSession = (duplicate, playlist).into();
+    Ok(session.into())
+}
+
+/// Check the teams of an imported game: named, with distinct ids and buzzers.
+fn validate_imported_teams(teams: &[TeamEntity]) -> Result<(), ServiceError> {
+    let mut team_ids = HashSet::new();
+    let mut buzzer_ids = HashSet::new();
+    for team in teams {
+        if team.name.trim().is_empty() {
+            return Err(ServiceError::InvalidInput(
+                "team name must not be empty".into(),
+            ));
+        }
+        if !team_ids.insert(team.id) {
+            return Err(ServiceError::InvalidInput(format!(
+                "duplicate team id `{}` detected",
+                team.id
+            )));
+        }
+        if let Some(id) = team.buzzer_ids.iter().find(|id| !buzzer_ids.insert(*id)) {
+            return Err(ServiceError::InvalidInput(format!(
+                "duplicate buzzer id `{id}` detected"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check a team color supplied by a client: saturation and value must lie in `[0, 1]`, and the
+/// hue is wrapped into `[0, 360)`.
+pub fn checked_team_color(color: TeamColorDto) -> Result<TeamColor, ServiceError> {
+    color
+        .validate()
+        .map_err(|err| ServiceError::InvalidInput(format!("invalid team color: {err}")))?;
+    Ok(color.normalized().into())
+}
+
+/// Trim a team name and check it against the configured maximum length and charset.
+pub fn normalize_team_name(config: &AppConfig, name: &str) -> Result<String, ServiceError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "team name must not be empty".into(),
+        ));
+    }
+
+    let max_len = config.max_team_name_len();
+    let len = name.chars().count();
+    if len > max_len {
+        return Err(ServiceError::InvalidInput(format!(
+            "team name must be at most {max_len} characters long (got {len})"
+        )));
+    }
+
+    let charset = config.team_name_charset();
+    if let Some(c) = name.chars().find(|c| !charset.allows(*c)) {
+        return Err(ServiceError::InvalidInput(format!(
+            "team name must not contain {c:?}"
+        )));
+    }
+
+    Ok(name.to_string())
+}
+
+/// Keep only the songs carrying at least one of `tags` in the play order (no-op when empty).
+fn filter_song_order_by_tags(game: &mut GameSession, tags: &[String]) -> Result<(), ServiceError> {
+    if tags.is_empty() {
+        return Ok(());
+    }
+    let songs = &game.playlist.songs;
+    game.playlist_song_order.retain(|song_id| {
+        songs
+            .get(song_id)
+            .is_some_and(|song| song.has_any_tag(tags))
+    });
+    if game.playlist_song_order.is_empty() {
+        return Err(ServiceError::InvalidInput(format!(
+            "no song of the playlist matches the tags {tags:?}"
+        )));
+    }
+    Ok(())
 }
 
 async fn ensure_idle(state: &SharedState) -> Result<(), ServiceError> {
@@ -202,57 +668,57 @@ async fn ensure_idle(state: &SharedState) -> Result<(), ServiceError> {
 fn build_teams(
     teams: Vec<TeamInput>,
     config: &AppConfig,
+    ids: &IdGenerator,
 ) -> Result<IndexMap<Uuid, Team>, ServiceError> {
     let mut seen_ids = HashSet::new();
     let mut used_colors = Vec::new();
 
     teams
         .into_iter()
-        .map(|team| {
-            let buzzer_id = team
-                .buzzer_id
-                .unwrap_or_default()
-                .as_ref()
-                .map(|id| {
-                    if !seen_ids.insert(id.clone()) {
-                        Err(ServiceError::InvalidInput(format!(
-                            "duplicate buzzer id `{}` detected",
-                            id
-                        )))
-                    } else {
-                        Ok(id.clone())
-                    }
-                })
-                .transpose()?;
-
-            if team.name.trim().is_empty() {
-                return Err(ServiceError::InvalidInput(
-                    "team name must not be empty".into(),
-                ));
+        .enumerate()
+        .map(|(index, team)| {
+            let buzzer_ids = team.buzzer_update().unwrap_or_default();
+            if let Some(id) = buzzer_ids.iter().find(|id| !seen_ids.insert((*id).clone())) {
+                return Err(ServiceError::InvalidInput(format!(
+                    "duplicate buzzer id `{id}` detected"
+                )));
             }
 
-            // Pick the first free color; fall back to the colors set order if everything is taken.
-            let color = team
-                .color
-                .map(Into::into)
-                .unwrap_or_else(|| config.first_unused_color(&used_colors));
+            let name = normalize_team_name(config, &team.name)?;
+
+            // Pick a color following the configured strategy when none was provided.
+            let color = match team.color {
+                Some(color) => checked_team_color(color)?,
+                None => config.auto_color(index, &used_colors),
+            };
             used_colors.push(color.clone());
 
             let team = Team {
-                buzzer_id,
-                name: team.name,
+                buzzer_ids,
+                name,
                 score: team.score.unwrap_or_default(),
                 color,
                 updated_at: SystemTime::now(),
+                created_in_session: None,
             };
 
-            Ok((Uuid::new_v4(), team))
+            Ok((ids.next_id(), team))
         })
         .collect()
 }
 
+/// Reorder the songs of `playlist` after checking `order` lists each of them exactly once.
+fn apply_song_order(playlist: &mut Playlist, order: &[u32]) -> Result<(), ServiceError> {
+    check_complete_order(&playlist.songs, order)?;
+    playlist.songs = order
+        .iter()
+        .filter_map(|song_id| playlist.songs.shift_remove_entry(song_id))
+        .collect();
+    Ok(())
+}
+
 /// Construct a playlist from user-provided song metadata.
-fn build_playlist(songs: Vec<SongInput>, name: String) -> Result<Playlist, ServiceError> {
+fn build_playlist(id: Uuid, songs: Vec<SongInput>, name: String) -> Result<Playlist, ServiceError> {
     if name.trim().is_empty() {
         return Err(ServiceError::InvalidInput(
             "playlist name must not be empty".into(),
@@ -262,55 +728,58 @@ fn build_playlist(songs: Vec<SongInput>, name: String) -> Result<Playlist, Servi
     let songs = songs
         .into_iter()
         .enumerate()
-        .map(|(index, song)| {
-            if song.point_fields.is_empty() {
-                return Err(ServiceError::InvalidInput(
-                    "each song must declare at least one point field".into(),
-                ));
-            }
+        .map(|(index, song)| Ok((index as u32, build_song(song)?)))
+        .collect::<Result<IndexMap<u32, Song>, ServiceError>>()?;
 
-            if song.url.trim().is_empty() {
-                return Err(ServiceError::InvalidInput(
-                    "song url must not be empty".into(),
-                ));
-            }
+    Ok(Playlist::new(id, name, songs))
+}
 
-            if song.guess_duration_ms == 0 {
-                return Err(ServiceError::InvalidInput(
-                    "guess duration must be strictly positive".into(),
-                ));
-            }
+/// Check a song submitted by an admin and convert it into its runtime form.
+fn build_song(song: SongInput) -> Result<Song, ServiceError> {
+    if song.point_fields.is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "each song must declare at least one point field".into(),
+        ));
+    }
 
-            Ok((
-                (index as u32),
-                Song {
-                    starts_at_ms: song.starts_at_ms,
-                    guess_duration_ms: song.guess_duration_ms,
-                    url: song.url,
-                    point_fields: song
-                        .point_fields
-                        .into_iter()
-                        .map(|pf| PointField {
-                            key: pf.key,
-                            value: pf.value,
-                            points: pf.points,
-                        })
-                        .collect(),
-                    bonus_fields: song
-                        .bonus_fields
-                        .into_iter()
-                        .map(|pf| PointField {
-                            key: pf.key,
-                            value: pf.value,
-                            points: pf.points,
-                        })
-                        .collect(),
-                },
-            ))
-        })
-        .collect::<Result<IndexMap<u32, Song>, ServiceError>>()?;
+    if song.url.trim().is_empty() {
+        return Err(ServiceError::InvalidInput(
+            "song url must not be empty".into(),
+        ));
+    }
 
-    Ok(Playlist::new(name, songs))
+    if song.guess_duration_ms == 0 {
+        return Err(ServiceError::InvalidInput(
+            "guess duration must be strictly positive".into(),
+        ));
+    }
+
+    Ok(Song {
+        starts_at_ms: song.starts_at_ms,
+        guess_duration_ms: song.guess_duration_ms,
+        url: song.url,
+        point_fields: song
+            .point_fields
+            .into_iter()
+            .map(|pf| PointField {
+                key: pf.key,
+                value: pf.value,
+                points: pf.points,
+                required: pf.required,
+            })
+            .collect(),
+        bonus_fields: song
+            .bonus_fields
+            .into_iter()
+            .map(|pf| PointField {
+                key: pf.key,
+                value: pf.value,
+                points: pf.points,
+                required: pf.required,
+            })
+            .collect(),
+        tags: song.tags,
+    })
 }
 
 fn validate_persisted_game(
@@ -331,11 +800,12 @@ fn validate_persisted_game(
         )));
     }
 
+    // Tag-filtered games only order a subset of the playlist songs.
     let playlist_songs_nb = playlist.songs.len();
     let song_order = &game.playlist_song_order;
-    if song_order.len() != playlist_songs_nb {
+    if song_order.is_empty() || song_order.len() > playlist_songs_nb {
         return Err(ServiceError::InvalidState(format!(
-            "game `{}` song orger is inconsistent (expected {} entries, got {})",
+            "game `{}` song orger is inconsistent (expected 1 to {} entries, got {})",
             game.id,
             playlist_songs_nb,
             song_order.len()
@@ -343,6 +813,7 @@ fn validate_persisted_game(
     }
 
     let song_ids = (0..playlist_songs_nb as u32).collect::<HashSet<_>>();
+    let mut seen = HashSet::with_capacity(song_order.len());
     for song_id in song_order {
         if !song_ids.contains(song_id) {
             return Err(ServiceError::InvalidState(format!(
@@ -350,7 +821,338 @@ fn validate_persisted_game(
                 game.id, song_id
             )));
         }
+        if !seen.insert(*song_id) {
+            return Err(ServiceError::InvalidState(format!(
+                "game `{}` song orger lists song `{}` twice",
+                game.id, song_id
+            )));
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dao::game_store::testing::RecordingStore;
+    use std::sync::Arc;
+
+    fn song(tags: &[&str]) -> Song {
+        Song {
+            starts_at_ms: 0,
+            guess_duration_ms: 1_000,
+            url: "https://example.com/song.mp3".into(),
+            point_fields: Vec::new(),
+            bonus_fields: Vec::new(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    fn tagged_game() -> GameSession {
+        let songs = IndexMap::from([
+            (0, song(&["90s"])),
+            (1, song(&["Movies"])),
+            (2, song(&["90s", "Movies"])),
+            (3, song(&[])),
+        ]);
+        GameSession::new(
+            Uuid::new_v4(),
+            "tags".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), songs),
+            None,
+        )
+    }
+
+    #[test]
+    fn tag_filter_keeps_only_matching_songs() {
+        let mut game = tagged_game();
+        filter_song_order_by_tags(&mut game, &["movies".into()]).unwrap();
+        assert_eq!(game.playlist_song_order, [1, 2]);
+
+        let summary: GameSummary = game.into();
+        let ids: Vec<_> = summary
+            .playlist
+            .songs
+            .iter()
+            .map(|song| song.id.as_str())
+            .collect();
+        assert_eq!(ids, ["1", "2"]);
+    }
+
+    #[test]
+    fn tag_filter_matching_no_song_is_rejected() {
+        let mut game = tagged_game();
+        assert!(matches!(
+            filter_song_order_by_tags(&mut game, &["80s".into()]),
+            Err(ServiceError::InvalidInput(_))
+        ));
+    }
+
+    /// Song with a single one-point `title` field answered "Bohemian Rhapsody".
+    fn song_input(url: &str) -> SongInput {
+        SongInput {
+            starts_at_ms: 0,
+            guess_duration_ms: 1_000,
+            url: url.into(),
+            point_fields: vec![crate::dto::game::PointFieldInput {
+                key: "title".into(),
+                value: "Bohemian Rhapsody".into(),
+                points: 1,
+                required: false,
+            }],
+            bonus_fields: Vec::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn installed_generator_mints_predictable_ids() {
+        let state = crate::state::AppState::new();
+        state.set_id_generator(IdGenerator::sequential(1));
+        let config = AppConfig::default();
+        let ids = state.ids();
+
+        let song = song_input("https://example.com/song.mp3");
+        let playlist = build_playlist(ids.next_id(), vec![song], "Mix".into()).unwrap();
+        let team = |name: &str| TeamInput {
+            name: name.into(),
+            buzzer_id: None,
+            buzzer_ids: None,
+            score: None,
+            color: None,
+        };
+        let teams = build_teams(vec![team("Owls"), team("Foxes")], &config, &ids).unwrap();
+        let mut game = GameSession::new(ids.next_id(), "Quiz".into(), teams, playlist, None);
+        let (added, _) = game.add_team(state.ids().next_id(), &config, None, None, None, None);
+
+        assert_eq!(game.playlist.id, Uuid::from_u128(1));
+        assert_eq!(
+            game.teams.keys().copied().collect::<Vec<_>>(),
+            [Uuid::from_u128(2), Uuid::from_u128(3), Uuid::from_u128(5)]
+        );
+        assert_eq!(game.id, Uuid::from_u128(4));
+        assert_eq!(added, Uuid::from_u128(5));
+    }
+
+    #[tokio::test]
+    async fn playlist_id_collision_is_rejected() {
+        let state = crate::state::AppState::new();
+        let store = Arc::new(RecordingStore::default());
+        state.set_game_store(store.clone()).await;
+        // Mint the same id over and over to simulate a collision.
+        state.set_id_generator(IdGenerator::new(|| Uuid::from_u128(7)));
+        let request = || PlaylistInput {
+            name: "Mix".into(),
+            songs: vec![song_input("https://example.com/song.mp3")],
+        };
+
+        create_playlist(&state, request()).await.unwrap();
+        assert!(matches!(
+            create_playlist(&state, request()).await,
+            Err(ServiceError::Conflict(_))
+        ));
+        assert_eq!(store.playlists.lock().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn playlists_used_by_games_keep_their_songs_and_cannot_be_deleted() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let request = |name: &str, songs: usize| PlaylistInput {
+            name: name.into(),
+            songs: (0..songs)
+                .map(|_| song_input("https://example.com/song.mp3"))
+                .collect(),
+        };
+        let (_, playlist) = create_playlist(&state, request("Mix", 2)).await.unwrap();
+        let game = GameSession::new(
+            Uuid::new_v4(),
+            "game".into(),
+            IndexMap::new(),
+            playlist.clone(),
+            None,
+        );
+        store.save_game(game.to_entity()).await.unwrap();
+
+        assert!(matches!(
+            update_playlist(&state, playlist.id, request("Mix", 3)).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+        let updated = update_playlist(&state, playlist.id, request("Remix", 2))
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "Remix");
+        assert!(matches!(
+            delete_playlist(&state, playlist.id).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+
+        store.delete_game(game.id).await.unwrap();
+        update_playlist(&state, playlist.id, request("Remix", 3))
+            .await
+            .unwrap();
+        delete_playlist(&state, playlist.id).await.unwrap();
+        assert!(matches!(
+            delete_playlist(&state, playlist.id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        assert!(matches!(
+            update_playlist(&state, playlist.id, request("Mix", 1)).await,
+            Err(ServiceError::NotFound(_))
+        ));
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn reordered_playlists_set_the_order_of_new_games() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (_, playlist) = create_playlist(
+            &state,
+            PlaylistInput {
+                name: "Mix".into(),
+                songs: vec![
+                    song_input("https://a"),
+                    song_input("https://b"),
+                    song_input("https://c"),
+                ],
+            },
+        )
+        .await
+        .unwrap();
+
+        for invalid in [vec![2, 0], vec![2, 0, 1, 1], vec![2, 0, 3]] {
+            assert!(matches!(
+                reorder_playlist(&state, playlist.id, invalid).await,
+                Err(ServiceError::InvalidInput(_))
+            ));
+        }
+        reorder_playlist(&state, playlist.id, vec![2, 0, 1])
+            .await
+            .unwrap();
+
+        let stored = store.find_playlist(playlist.id).await.unwrap().unwrap();
+        assert_eq!(stored.song_order, vec![2, 0, 1]);
+        let game = GameSession::new(
+            Uuid::new_v4(),
+            "game".into(),
+            IndexMap::new(),
+            Playlist::from(stored),
+            None,
+        );
+        assert_eq!(game.playlist_song_order, vec![2, 0, 1]);
+    }
+
+    #[cfg(feature = "memory-store")]
+    #[tokio::test]
+    async fn songs_are_added_and_removed_around_the_default_order() {
+        use crate::dao::game_store::{GameStore, memory::InMemoryGameStore};
+
+        let state = crate::state::AppState::new();
+        let store = InMemoryGameStore::new();
+        state.set_game_store(Arc::new(store.clone())).await;
+        let (_, playlist) = create_playlist(
+            &state,
+            PlaylistInput {
+                name: "Mix".into(),
+                songs: vec![song_input("https://a"), song_input("https://b")],
+            },
+        )
+        .await
+        .unwrap();
+        reorder_playlist(&state, playlist.id, vec![1, 0])
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            add_song(&state, playlist.id, song_input(" ")).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        let summary = add_song(&state, playlist.id, song_input("https://c"))
+            .await
+            .unwrap();
+        assert_eq!(summary.songs.len(), 3);
+        let stored = store.find_playlist(playlist.id).await.unwrap().unwrap();
+        assert_eq!(stored.song_order, vec![1, 0, 2]);
+        assert_eq!(stored.songs[2].url, "https://c");
+
+        assert!(matches!(
+            remove_song(&state, playlist.id, 3).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        remove_song(&state, playlist.id, 0).await.unwrap();
+        let stored = store.find_playlist(playlist.id).await.unwrap().unwrap();
+        let urls: Vec<_> = stored.songs.iter().map(|song| song.url.as_str()).collect();
+        assert_eq!(urls, ["https://b", "https://c"]);
+        assert!(stored.song_order.is_empty());
+
+        let game = GameSession::new(
+            Uuid::new_v4(),
+            "game".into(),
+            IndexMap::new(),
+            Playlist::from(stored),
+            None,
+        );
+        store.save_game(game.to_entity()).await.unwrap();
+        assert!(matches!(
+            add_song(&state, playlist.id, song_input("https://d")).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+        assert!(matches!(
+            remove_song(&state, playlist.id, 0).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+    }
+
+    /// Log sink shared between a test subscriber and the assertions.
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn playlist_answers_stay_out_of_info_logs() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::INFO)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+        let state = crate::state::AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+
+        create_playlist(
+            &state,
+            PlaylistInput {
+                name: "Mix".into(),
+                songs: vec![song_input("https://example.com/song.mp3")],
+            },
+        )
+        .await
+        .unwrap();
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains("Bohemian Rhapsody"), "{output}");
+    }
+}
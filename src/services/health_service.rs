@@ -1,21 +1,64 @@
+use std::time::Instant;
+
 use tracing::warn;
 
 use crate::{dto::health::HealthResponse, state::SharedState};
 
-/// Respond with a static health payload while logging connectivity issues.
+/// Report whether the backend is ready to serve traffic: not in degraded mode, with a storage
+/// backend that answers its health check.
+///
+/// The response names the backend kind and, when ready, the round-trip time of the health check.
 pub async fn health_status(state: &SharedState) -> HealthResponse {
-    match state.require_game_store().await {
-        Ok(store) => {
-            if let Err(err) = store.health_check().await {
-                warn!(error = %err, "storage health check failed");
-            }
+    let Ok(store) = state.require_game_store().await else {
+        warn!("storage unavailable (degraded mode)");
+        return HealthResponse::degraded("no backend");
+    };
+
+    let started = Instant::now();
+    let checked = store.health_check().await;
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    let mut response = match checked {
+        Err(err) => {
+            warn!(error = %err, "storage health check failed");
+            HealthResponse::degraded("health check failed")
         }
-        Err(_) => warn!("storage unavailable (degraded mode)"),
-    }
+        Ok(()) if state.is_degraded().await => HealthResponse::degraded("degraded mode"),
+        Ok(()) => HealthResponse {
+            latency_ms: Some(latency_ms),
+            ..HealthResponse::ok()
+        },
+    };
+    response.backend = Some(store.kind().to_string());
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{dao::game_store::testing::RecordingStore, state::AppState};
+
+    #[tokio::test]
+    async fn readiness_requires_a_healthy_store_outside_degraded_mode() {
+        let state = AppState::new();
+        let status = health_status(&state).await;
+        assert!(!status.is_ok());
+        assert_eq!(status.detail.as_deref(), Some("no backend"));
+        assert!(status.backend.is_none() && status.latency_ms.is_none());
+
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let status = health_status(&state).await;
+        assert!(status.is_ok());
+        assert_eq!(status.backend.as_deref(), Some("recording"));
+        assert!(status.latency_ms.is_some());
 
-    if state.is_degraded().await {
-        HealthResponse::degraded()
-    } else {
-        HealthResponse::ok()
+        state.override_degraded(true).await;
+        let status = health_status(&state).await;
+        assert!(!status.is_ok());
+        assert!(status.latency_ms.is_none());
     }
 }
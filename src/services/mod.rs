@@ -1,20 +1,30 @@
 /// Admin service for game management operations.
 pub mod admin_service;
+/// Optional periodic autosave of the current game.
+pub mod autosave;
 /// OpenAPI documentation generation.
 pub mod documentation;
+/// Optional NDJSON recorder of broadcast SSE events.
+pub mod event_recorder;
 /// Core game logic and state management.
 pub mod game_service;
 /// Health check service.
 pub mod health_service;
 /// Team pairing logic and utilities.
 pub mod pairing;
+/// CSV song list parsing for playlist imports.
+pub mod playlist_csv;
 /// Public service for read-only game information.
 pub mod public_service;
+/// WebSocket transport for public displays.
+pub mod public_ws_service;
 /// Server-Sent Events message generation.
 pub mod sse_events;
 /// Server-Sent Events broadcasting service.
 pub mod sse_service;
 /// Storage persistence coordinator with debouncing.
 pub mod storage_supervisor;
+/// TypeScript definitions of the realtime payloads.
+pub mod typescript;
 /// WebSocket connection and message handling service.
 pub mod websocket_service;
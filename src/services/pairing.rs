@@ -44,7 +44,7 @@ pub enum PairingSessionUpdate {
 fn next_unassigned_team(teams: &IndexMap<Uuid, Team>) -> Option<Uuid> {
     teams
         .iter()
-        .find(|(_, team)| team.buzzer_id.is_none())
+        .find(|(_, team)| team.buzzer_ids.is_empty())
         .map(|(id, _)| *id)
 }
 
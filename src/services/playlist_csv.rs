@@ -0,0 +1,197 @@
+//! Conversion of spreadsheet-exported CSV song lists into playlist songs.
+//!
+//! Each record describes one song:
+//!
+//! ```text
+//! url,starts_at_ms,guess_duration_ms,key,value,points[,key,value,points...]
+//! ```
+//!
+//! The first three columns are mandatory, followed by at least one `key,value,points` group per
+//! point field. A key prefixed with `bonus:` declares a bonus field instead (the prefix is
+//! stripped). Fields may be wrapped in double quotes to contain commas or line breaks, with `""`
+//! standing for a literal quote. A leading header record whose first column is `url` is skipped,
+//! as are blank records and the empty trailing cells spreadsheets pad rows with.
+
+use validator::Validate;
+
+use crate::dto::{
+    admin::CsvRowError,
+    game::{PointFieldInput, SongInput},
+};
+
+/// Number of columns preceding the point field groups.
+const SONG_COLUMNS: usize = 3;
+/// Number of columns describing one point field.
+const FIELD_COLUMNS: usize = 3;
+/// Key prefix marking a bonus field.
+const BONUS_PREFIX: &str = "bonus:";
+
+/// Parse a CSV document into playlist songs, reporting every invalid row.
+pub fn parse_songs(csv: &str) -> Result<Vec<SongInput>, Vec<CsvRowError>> {
+    let records = split_records(csv).map_err(|err| vec![err])?;
+
+    let mut songs = Vec::with_capacity(records.len());
+    let mut errors = Vec::new();
+    for (index, (row, cells)) in records.into_iter().enumerate() {
+        if index == 0 && cells[0].trim().eq_ignore_ascii_case("url") {
+            continue;
+        }
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        match parse_song(cells) {
+            Ok(song) => songs.push(song),
+            Err(message) => errors.push(CsvRowError { row, message }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(songs)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Build a song from the cells of one record.
+fn parse_song(mut cells: Vec<String>) -> Result<SongInput, String> {
+    while cells.len() > SONG_COLUMNS && cells.last().is_some_and(|cell| cell.trim().is_empty()) {
+        cells.pop();
+    }
+    if cells.len() < SONG_COLUMNS + FIELD_COLUMNS {
+        return Err(format!(
+            "expected at least {} columns (url, starts_at_ms, guess_duration_ms, key, value, points), found {}",
+            SONG_COLUMNS + FIELD_COLUMNS,
+            cells.len()
+        ));
+    }
+    if !(cells.len() - SONG_COLUMNS).is_multiple_of(FIELD_COLUMNS) {
+        return Err("point fields must come in `key,value,points` groups".into());
+    }
+
+    let mut point_fields = Vec::new();
+    let mut bonus_fields = Vec::new();
+    for group in cells[SONG_COLUMNS..].chunks(FIELD_COLUMNS) {
+        let key = group[0].trim();
+        let (key, bonus) = match key.strip_prefix(BONUS_PREFIX) {
+            Some(key) => (key.trim(), true),
+            None => (key, false),
+        };
+        if key.is_empty() {
+            return Err("point field key must not be empty".into());
+        }
+        let field = PointFieldInput {
+            key: key.to_string(),
+            value: group[1].trim().to_string(),
+            points: parse_number(&group[2], "points")?,
+            required: false,
+        };
+        if bonus {
+            bonus_fields.push(field);
+        } else {
+            point_fields.push(field);
+        }
+    }
+
+    if point_fields.is_empty() {
+        return Err("at least one point field must not be a bonus field".into());
+    }
+
+    let song = SongInput {
+        url: cells[0].trim().to_string(),
+        starts_at_ms: parse_number(&cells[1], "starts_at_ms")?,
+        guess_duration_ms: parse_number(&cells[2], "guess_duration_ms")?,
+        point_fields,
+        bonus_fields,
+        tags: Vec::new(),
+    };
+    song.validate().map_err(|err| err.to_string())?;
+    Ok(song)
+}
+
+fn parse_number<T: std::str::FromStr>(cell: &str, column: &str) -> Result<T, String> {
+    cell.trim()
+        .parse()
+        .map_err(|_| format!("`{}` is not a valid {column}", cell.trim()))
+}
+
+/// Split a CSV document into records, each tagged with the (1-based) line it starts on.
+fn split_records(csv: &str) -> Result<Vec<(usize, Vec<String>)>, CsvRowError> {
+    let mut records = Vec::new();
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut in_quotes = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if cell.is_empty() => in_quotes = true,
+            (',', false) => cells.push(std::mem::take(&mut cell)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                cells.push(std::mem::take(&mut cell));
+                records.push((record_line, std::mem::take(&mut cells)));
+                line += 1;
+                record_line = line;
+            }
+            ('\n', true) => {
+                line += 1;
+                cell.push(c);
+            }
+            _ => cell.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(CsvRowError {
+            row: record_line,
+            message: "unterminated quoted field".into(),
+        });
+    }
+    if !cell.is_empty() || !cells.is_empty() {
+        cells.push(cell);
+        records.push((record_line, cells));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_fields_and_bonus_columns() {
+        let csv = "url,starts_at_ms,guess_duration_ms,key,value,points\n\
+            https://example.com/a.mp3,0,30000,title,\"Hello, \"\"World\"\"\",2,bonus:year,1999,1\r\n\
+            https://example.com/b.mp3,1500,20000,artist,\"Multi\nLine\",1,,,\n";
+
+        let songs = parse_songs(csv).unwrap();
+
+        assert_eq!(songs.len(), 2);
+        assert_eq!(songs[0].point_fields[0].value, "Hello, \"World\"");
+        assert_eq!(songs[0].point_fields[0].points, 2);
+        assert_eq!(songs[0].bonus_fields[0].key, "year");
+        assert_eq!(songs[1].starts_at_ms, 1500);
+        assert_eq!(songs[1].point_fields[0].value, "Multi\nLine");
+        assert!(songs[1].bonus_fields.is_empty());
+    }
+
+    #[test]
+    fn reports_malformed_rows_with_their_line() {
+        let csv = "https://example.com/a.mp3,0,30000,title,A,1\n\
+            https://example.com/b.mp3,soon,30000,title,B,1\n\
+            https://example.com/c.mp3,0,30000,title,C\n";
+
+        let errors = parse_songs(csv).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].row, 2);
+        assert!(errors[0].message.contains("starts_at_ms"));
+        assert_eq!(errors[1].row, 3);
+    }
+}
@@ -1,17 +1,30 @@
 //! Service helpers that expose read-only public projections of the current game.
 
+use std::{net::IpAddr, time::Duration};
+
 use crate::{
     dto::{
         game::TeamSummary,
-        public::{CurrentSongResponse, GamePhaseResponse, PairingStatusResponse, TeamsResponse},
+        public::{
+            CurrentSongResponse, GamePhaseResponse, PairingStatusResponse, PublicSongField,
+            PublicSongResponse, ReactionRequest, ScoreboardEntry, ScoreboardResponse,
+            SpectatorsResponse, TeamsResponse,
+        },
     },
     error::ServiceError,
+    services::sse_events,
     state::{
         SharedState,
+        game::PointField,
         state_machine::{GamePhase, GameRunningPhase, PrepStatus},
     },
 };
 
+/// Emojis spectators may send as reactions.
+pub const ALLOWED_REACTIONS: &[&str] = &["👏", "🔥", "😂", "😮", "🎉", "❤️"];
+/// Minimum delay between two reactions from the same client address.
+const REACTION_COOLDOWN: Duration = Duration::from_secs(1);
+
 /// Return the current teams exposed to the public UI.
 pub async fn get_teams(state: &SharedState) -> Result<TeamsResponse, ServiceError> {
     let teams = state
@@ -20,13 +33,31 @@ pub async fn get_teams(state: &SharedState) -> Result<TeamsResponse, ServiceErro
                 .teams
                 .clone()
                 .into_iter()
-                .map(TeamSummary::from)
+                .map(|team| sse_events::public_team(state, TeamSummary::from(team)))
                 .collect())
         })
         .await?;
     Ok(TeamsResponse { teams })
 }
 
+/// Return the scoreboard of the active game: team names, colors and scores only.
+///
+/// Unlike [`get_teams`], no game loaded is not an error: the scoreboard is just empty.
+pub async fn get_scoreboard(state: &SharedState) -> ScoreboardResponse {
+    let teams: Vec<TeamSummary> = state
+        .read_current_game(|game| {
+            game.map(|game| game.teams.clone().into_iter().map(Into::into).collect())
+                .unwrap_or_default()
+        })
+        .await;
+    ScoreboardResponse {
+        teams: teams
+            .into_iter()
+            .map(|team| ScoreboardEntry::from(sse_events::public_team(state, team)))
+            .collect(),
+    }
+}
+
 /// Return the song being played alongside any fields already discovered.
 pub async fn get_current_song(state: &SharedState) -> Result<CurrentSongResponse, ServiceError> {
     state
@@ -48,11 +79,85 @@ pub async fn get_current_song(state: &SharedState) -> Result<CurrentSongResponse
         .await
 }
 
+/// Return the media and playback window of the current song for public displays.
+///
+/// Only the keys of its fields are exposed, with their found state; the expected values are
+/// included while the song is revealed.
+pub async fn get_public_song(state: &SharedState) -> Result<PublicSongResponse, ServiceError> {
+    let revealed =
+        state.state_machine_phase().await == GamePhase::GameRunning(GameRunningPhase::Reveal);
+    state
+        .with_current_game(|game| {
+            let index = game
+                .current_song_index
+                .ok_or_else(|| ServiceError::NotFound("no active song: playlist is over".into()))?;
+            let (id, song) = game
+                .get_song(index)
+                .ok_or_else(|| ServiceError::InvalidState("song not found in playlist".into()))?;
+            let project = |fields: Vec<PointField>, found: &[String]| {
+                fields
+                    .into_iter()
+                    .map(|field| PublicSongField {
+                        found: found.contains(&field.key),
+                        key: field.key,
+                        value: revealed.then_some(field.value),
+                    })
+                    .collect()
+            };
+            Ok(PublicSongResponse {
+                id,
+                url: song.url,
+                starts_at_ms: song.starts_at_ms,
+                guess_duration_ms: song.guess_duration_ms,
+                point_fields: project(song.point_fields, &game.found_point_fields),
+                bonus_fields: project(song.bonus_fields, &game.found_bonus_fields),
+            })
+        })
+        .await
+}
+
 /// Return the current game phase (e.g. idle, playing, reveal) and degraded mode.
 pub async fn get_game_phase(state: &SharedState) -> Result<GamePhaseResponse, ServiceError> {
     let phase = state.state_machine_phase().await;
     let snapshot = state.game_phase_snapshot(&phase).await;
-    Ok(GamePhaseResponse(snapshot))
+    Ok(GamePhaseResponse(sse_events::public_phase_snapshot(
+        state, snapshot,
+    )))
+}
+
+/// Return the number of connected public SSE and WebSocket clients.
+pub fn get_spectators(state: &SharedState) -> SpectatorsResponse {
+    SpectatorsResponse {
+        count: state.sse().spectators(),
+    }
+}
+
+/// Relay a spectator reaction to the public displays.
+///
+/// Reactions are ephemeral: they are only broadcast, never persisted. Each client address may
+/// react at most once per [`REACTION_COOLDOWN`].
+pub fn react(
+    state: &SharedState,
+    client: IpAddr,
+    request: ReactionRequest,
+) -> Result<(), ServiceError> {
+    if !ALLOWED_REACTIONS.contains(&request.reaction.as_str()) {
+        return Err(ServiceError::InvalidInput(format!(
+            "unsupported reaction `{}` (allowed: {})",
+            request.reaction,
+            ALLOWED_REACTIONS.join(" ")
+        )));
+    }
+    state
+        .claim_reaction(client, REACTION_COOLDOWN)
+        .map_err(|remaining| {
+            ServiceError::RateLimited(format!(
+                "reacting too fast, retry in {} ms",
+                remaining.as_millis()
+            ))
+        })?;
+    sse_events::broadcast_reaction(state, &request.reaction);
+    Ok(())
 }
 
 /// Return the current pairing workflow status for public consumers.
@@ -78,3 +183,203 @@ pub async fn get_pairing_status(
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::AppConfig,
+        dto::admin::ScoresVisibilityRequest,
+        services::admin_service,
+        state::{
+            AppState,
+            game::{GameSession, Playlist, Song},
+            state_machine::GameEvent,
+        },
+    };
+    use indexmap::IndexMap;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn hidden_scores_are_masked_for_public_clients_only() {
+        let state = AppState::new();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "visibility".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (team_id, _) = game.add_team(
+            Uuid::new_v4(),
+            &AppConfig::default(),
+            None,
+            None,
+            None,
+            None,
+        );
+        game.teams.get_mut(&team_id).unwrap().score = 7;
+        let team = game.teams[&team_id].clone();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        let mut public_events = state.public_sse().subscribe();
+        let mut admin_events = state.admin_sse().subscribe();
+
+        admin_service::set_scores_visibility(&state, ScoresVisibilityRequest { visible: false })
+            .await
+            .unwrap();
+        let teams = get_teams(&state).await.unwrap().teams;
+        assert_eq!(teams[0].score, None);
+
+        sse_events::broadcast_team_created(&state, TeamSummary::from((team_id, team)));
+        for events in [&mut public_events, &mut admin_events] {
+            let visibility = events.recv().await.unwrap();
+            assert_eq!(visibility.event.as_deref(), Some("scores.visibility"));
+            assert_eq!(visibility.data, r#"{"visible":false}"#);
+        }
+        let public: serde_json::Value =
+            serde_json::from_str(&public_events.recv().await.unwrap().data).unwrap();
+        assert!(public["team"]["score"].is_null());
+        let admin: serde_json::Value =
+            serde_json::from_str(&admin_events.recv().await.unwrap().data).unwrap();
+        assert_eq!(admin["team"]["score"], 7);
+
+        admin_service::set_scores_visibility(&state, ScoresVisibilityRequest { visible: true })
+            .await
+            .unwrap();
+        let teams = get_teams(&state).await.unwrap().teams;
+        assert_eq!(teams[0].score, Some(7));
+    }
+
+    #[tokio::test]
+    async fn scoreboard_lists_standings_without_buzzer_ids() {
+        let state = AppState::new();
+        assert!(get_scoreboard(&state).await.teams.is_empty());
+
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "scoreboard".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        game.add_team(
+            Uuid::new_v4(),
+            &AppConfig::default(),
+            Some("Rockers".into()),
+            Some("deadbeef0001".into()),
+            Some(4),
+            None,
+        );
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        let scoreboard = serde_json::to_value(get_scoreboard(&state).await).unwrap();
+        let team = &scoreboard["teams"][0];
+        assert_eq!(team["name"], "Rockers");
+        assert_eq!(team["score"], 4);
+        assert!(team.get("buzzer_id").is_none());
+        assert!(team.get("buzzer_ids").is_none());
+    }
+
+    #[tokio::test]
+    async fn public_song_hides_answers_until_reveal() {
+        let state = AppState::new();
+        let field = |key: &str| PointField {
+            key: key.into(),
+            value: key.to_uppercase(),
+            points: 1,
+            required: false,
+        };
+        let mut songs = IndexMap::new();
+        songs.insert(
+            1,
+            Song {
+                starts_at_ms: 500,
+                guess_duration_ms: 30_000,
+                url: "https://example.com/song".into(),
+                point_fields: vec![field("title"), field("artist")],
+                bonus_fields: vec![field("year")],
+                tags: Vec::new(),
+            },
+        );
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "public song".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), songs),
+            None,
+        );
+        game.found_point_fields.push("title".into());
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        let song = serde_json::to_value(get_public_song(&state).await.unwrap()).unwrap();
+        assert_eq!(song["url"], "https://example.com/song");
+        assert_eq!(song["starts_at_ms"], 500);
+        assert_eq!(song["point_fields"][0]["key"], "title");
+        assert_eq!(song["point_fields"][0]["found"], true);
+        assert!(!song.to_string().contains("TITLE"));
+        assert!(!song.to_string().contains("YEAR"));
+
+        state
+            .run_transition(GameEvent::Reveal, || async { Ok(()) })
+            .await
+            .unwrap();
+        let song = get_public_song(&state).await.unwrap();
+        assert_eq!(song.point_fields[1].value.as_deref(), Some("ARTIST"));
+        assert_eq!(song.bonus_fields[0].value.as_deref(), Some("YEAR"));
+    }
+
+    fn reaction(emoji: &str) -> ReactionRequest {
+        ReactionRequest {
+            reaction: emoji.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn allowed_reaction_is_rebroadcast_once_per_cooldown() {
+        let state = AppState::new();
+        let mut public_events = state.public_sse().subscribe();
+        let client: IpAddr = [192, 168, 1, 20].into();
+
+        react(&state, client, reaction("🔥")).unwrap();
+        let event = public_events.recv().await.unwrap();
+        assert_eq!(event.event.as_deref(), Some("reaction"));
+        let payload: serde_json::Value = serde_json::from_str(&event.data).unwrap();
+        assert_eq!(payload["reaction"], "🔥");
+
+        assert!(matches!(
+            react(&state, client, reaction("👏")),
+            Err(ServiceError::RateLimited(_))
+        ));
+        react(&state, [192, 168, 1, 21].into(), reaction("👏")).unwrap();
+    }
+
+    #[tokio::test]
+    async fn disallowed_reaction_is_rejected() {
+        let state = AppState::new();
+        let mut public_events = state.public_sse().subscribe();
+
+        assert!(matches!(
+            react(&state, [10, 0, 0, 1].into(), reaction("💩")),
+            Err(ServiceError::InvalidInput(_))
+        ));
+        assert!(public_events.try_recv().is_err());
+    }
+}
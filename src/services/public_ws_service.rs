@@ -0,0 +1,199 @@
+//! WebSocket transport for public displays, mirroring the public SSE stream while
+//! allowing clients to request a full resynchronisation on demand.
+
+use axum::extract::ws::{Message, WebSocket};
+use futures::{SinkExt, StreamExt, stream::SplitSink};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+use crate::{
+    dto::{
+        sse::{Handshake, ServerEvent, SystemStatus},
+        ws::{PublicInboundMessage, PublicOutboundMessage},
+    },
+    services::sse_events,
+    state::SharedState,
+};
+
+type PublicSink = SplitSink<WebSocket, Message>;
+
+/// Handle the lifecycle of a public display WebSocket connection.
+///
+/// Every event broadcast on the public hub is forwarded as a JSON `{ "event", "data" }`
+/// envelope. A `{"type":"resync"}` message makes the server reply with the current
+/// `game.session` and `phase_changed` snapshots.
+pub async fn handle_public_socket(state: SharedState, socket: WebSocket) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut events = state.public_sse().subscribe();
+    let mut degraded_rx = state.degraded_watcher();
+
+    info!("New public WebSocket connection");
+
+    let handshake = ServerEvent::json(
+        Some("handshake".to_string()),
+        &Handshake {
+            stream: "public".to_string(),
+            message: "public websocket connected".to_string(),
+            degraded: state.is_degraded().await,
+            token: None,
+        },
+    );
+    if let Ok(event) = handshake
+        && !send_event(&mut sender, event).await
+    {
+        return;
+    }
+
+    sse_events::spectator_connected(&state);
+    loop {
+        tokio::select! {
+            recv_result = events.recv() => {
+                match recv_result {
+                    Ok(event) => {
+                        if !send_event(&mut sender, event).await {
+                            break;
+                        }
+                    }
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                }
+            }
+            changed = degraded_rx.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                let degraded = *degraded_rx.borrow();
+                if let Ok(event) = ServerEvent::json(
+                    Some("system_status".to_string()),
+                    &SystemStatus { degraded },
+                ) && !send_event(&mut sender, event).await
+                {
+                    break;
+                }
+            }
+            inbound = receiver.next() => {
+                match inbound {
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<PublicInboundMessage>(&text) {
+                            Ok(PublicInboundMessage::Resync) => {
+                                let mut open = true;
+                                for event in sse_events::public_resync_events(&state).await {
+                                    if !send_event(&mut sender, event).await {
+                                        open = false;
+                                        break;
+                                    }
+                                }
+                                if !open {
+                                    break;
+                                }
+                            }
+                            Err(err) => {
+                                warn!(error = %err, "failed to parse public websocket message");
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Ping(payload))) => {
+                        if sender.send(Message::Pong(payload)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => {
+                        warn!(error = %err, "public websocket error");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    sse_events::spectator_disconnected(&state);
+    info!("Public WebSocket disconnected");
+}
+
+/// Serialise a public event into a WebSocket text frame.
+///
+/// Returns `None` (after logging) when the payload cannot be re-encoded.
+pub fn to_message(event: ServerEvent) -> Option<Message> {
+    let envelope = match PublicOutboundMessage::try_from(event) {
+        Ok(envelope) => envelope,
+        Err(err) => {
+            warn!(error = %err, "failed to decode public event payload");
+            return None;
+        }
+    };
+    match serde_json::to_string(&envelope) {
+        Ok(text) => Some(Message::Text(text.into())),
+        Err(err) => {
+            warn!(error = %err, "failed to serialize public websocket message");
+            None
+        }
+    }
+}
+
+/// Push an event to the client, returning `false` once the connection is closed.
+async fn send_event(sender: &mut PublicSink, event: ServerEvent) -> bool {
+    match to_message(event) {
+        Some(message) => sender.send(message).await.is_ok(),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::AppConfig,
+        state::{
+            AppState,
+            game::{GameSession, Playlist},
+        },
+    };
+    use indexmap::IndexMap;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn resync_yields_session_and_phase_snapshot() {
+        let state = AppState::new();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "resync".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        game.add_team(
+            Uuid::new_v4(),
+            &AppConfig::default(),
+            None,
+            None,
+            None,
+            None,
+        );
+        let game_id = game.id;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+
+        let messages: Vec<String> = sse_events::public_resync_events(&state)
+            .await
+            .into_iter()
+            .filter_map(to_message)
+            .map(|message| match message {
+                Message::Text(text) => text.to_string(),
+                other => panic!("unexpected message {other:?}"),
+            })
+            .collect();
+
+        assert_eq!(messages.len(), 2);
+        let session: serde_json::Value = serde_json::from_str(&messages[0]).unwrap();
+        assert_eq!(session["event"], "game.session");
+        assert_eq!(session["data"]["id"], game_id.to_string());
+        let phase: serde_json::Value = serde_json::from_str(&messages[1]).unwrap();
+        assert_eq!(phase["event"], "phase_changed");
+        assert_eq!(phase["data"]["game_id"], game_id.to_string());
+    }
+}
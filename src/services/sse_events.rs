@@ -6,32 +6,47 @@ use uuid::Uuid;
 use crate::{
     dto::{
         admin::AnswerValidation,
+        common::{GamePhaseSnapshot, SongSnapshot},
         game::{GameSummary, TeamSummary},
+        phase::AdminAction,
         sse::{
-            AnswerValidationEvent, FieldsFoundEvent, PairingAssignedEvent, PairingRestoredEvent,
-            PairingWaitingEvent, PhaseChangedEvent, ServerEvent, TeamCreatedEvent,
-            TeamDeletedEvent, TeamUpdatedEvent, TestBuzzEvent,
+            AnswerCountdownEvent, AnswerValidationEvent, BatteryLevelEvent, BuzzCueEvent,
+            BuzzSpentEvent, FieldsFoundEvent, GameFinishedEvent, LowBatteryEvent,
+            PairingAssignedEvent, PairingConflictEvent, PairingRestoredEvent, PairingWaitingEvent,
+            PhaseChangedEvent, ReactionEvent, ScoresVisibilityEvent, ServerEvent, SnapshotEvent,
+            SpectatorsEvent, TeamCreatedEvent, TeamDeletedEvent, TeamUpdatedEvent, TestBuzzEvent,
         },
     },
     state::{
         SharedState,
-        game::{GameSession, Team},
+        game::{GameSession, Team, TeamColor},
         state_machine::GamePhase,
     },
 };
 
-const EVENT_FIELDS_FOUND: &str = "fields_found";
-const EVENT_ANSWER_VALIDATION: &str = "answer_validation";
-const EVENT_SCORE_ADJUSTMENT: &str = "score_adjustment";
-const EVENT_PHASE_CHANGED: &str = "phase_changed";
-const EVENT_TEAM_CREATED: &str = "team.created";
-const EVENT_TEAM_UPDATED: &str = "team.updated";
-const EVENT_PAIRING_WAITING: &str = "pairing.waiting";
-const EVENT_PAIRING_ASSIGNED: &str = "pairing.assigned";
-const EVENT_PAIRING_RESTORED: &str = "pairing.restored";
-const EVENT_TEST_BUZZ: &str = "test.buzz";
-const EVENT_TEAM_DELETED: &str = "team.deleted";
-const EVENT_GAME_SESSION: &str = "game.session";
+pub(crate) const EVENT_FIELDS_FOUND: &str = "fields_found";
+pub(crate) const EVENT_ANSWER_VALIDATION: &str = "answer_validation";
+pub(crate) const EVENT_SCORE_ADJUSTMENT: &str = "score_adjustment";
+pub(crate) const EVENT_PHASE_CHANGED: &str = "phase_changed";
+pub(crate) const EVENT_TEAM_CREATED: &str = "team.created";
+pub(crate) const EVENT_TEAM_UPDATED: &str = "team.updated";
+pub(crate) const EVENT_PAIRING_WAITING: &str = "pairing.waiting";
+pub(crate) const EVENT_PAIRING_ASSIGNED: &str = "pairing.assigned";
+pub(crate) const EVENT_PAIRING_RESTORED: &str = "pairing.restored";
+pub(crate) const EVENT_PAIRING_CONFLICT: &str = "pairing.conflict";
+pub(crate) const EVENT_TEST_BUZZ: &str = "test.buzz";
+pub(crate) const EVENT_TEAM_DELETED: &str = "team.deleted";
+pub(crate) const EVENT_GAME_SESSION: &str = "game.session";
+pub(crate) const EVENT_SCORES_VISIBILITY: &str = "scores.visibility";
+pub(crate) const EVENT_BUZZ_SPENT: &str = "buzz.spent";
+pub(crate) const EVENT_BUZZ_CUE: &str = "buzz.cue";
+pub(crate) const EVENT_REACTION: &str = "reaction";
+pub(crate) const EVENT_GAME_FINISHED: &str = "game.finished";
+pub(crate) const EVENT_ANSWER_COUNTDOWN: &str = "answer.countdown";
+pub(crate) const EVENT_BUZZER_BATTERY: &str = "buzzer.battery";
+pub(crate) const EVENT_BUZZER_LOW_BATTERY: &str = "buzzer.low_battery";
+pub(crate) const EVENT_SNAPSHOT: &str = "snapshot";
+pub(crate) const EVENT_SPECTATORS: &str = "spectators";
 
 /// Broadcast the list of fields found for the current song.
 pub fn broadcast_fields_found(
@@ -56,15 +71,18 @@ pub fn broadcast_answer_validation(state: &SharedState, valid: AnswerValidation)
 
 /// Broadcast a score adjustment for a specific team.
 pub fn broadcast_score_adjustment(state: &SharedState, team_id: Uuid, team: Team) {
-    let payload = TeamSummary::from((team_id, team));
+    let payload = public_team(state, TeamSummary::from((team_id, team)));
     send_public_event(state, EVENT_SCORE_ADJUSTMENT, &payload);
 }
 
 /// Broadcast the creation of a new team to admins.
 pub fn broadcast_team_created(state: &SharedState, team: TeamSummary) {
     let payload = TeamCreatedEvent { team };
-    send_public_event(state, EVENT_TEAM_CREATED, &payload);
     send_admin_event(state, EVENT_TEAM_CREATED, &payload);
+    let payload = TeamCreatedEvent {
+        team: public_team(state, payload.team),
+    };
+    send_public_event(state, EVENT_TEAM_CREATED, &payload);
 }
 
 /// Broadcast that a team has been deleted to public subscribers.
@@ -75,13 +93,15 @@ pub fn broadcast_team_deleted(state: &SharedState, team_id: Uuid) {
 
 /// Broadcast that a team has been updated to public subscribers.
 pub fn broadcast_team_updated(state: &SharedState, team: TeamSummary) {
-    let payload = TeamUpdatedEvent { team };
+    let payload = TeamUpdatedEvent {
+        team: public_team(state, team),
+    };
     send_public_event(state, EVENT_TEAM_UPDATED, &payload);
 }
 
 /// Broadcast a snapshot of the entire game session to public subscribers.
 pub fn broadcast_game_session(state: &SharedState, session: &GameSession) {
-    let summary: GameSummary = session.clone().into();
+    let summary = public_game_summary(state, session.clone().into());
     send_public_event(state, EVENT_GAME_SESSION, &summary);
 }
 
@@ -102,10 +122,29 @@ pub fn broadcast_pairing_assigned(state: &SharedState, team_id: Uuid, buzzer_id:
     send_admin_event(state, EVENT_PAIRING_ASSIGNED, &payload);
 }
 
+/// Broadcast that a buzzer paired with another team buzzed for the team currently pairing.
+pub fn broadcast_pairing_conflict(
+    state: &SharedState,
+    team_id: Uuid,
+    buzzer_id: &str,
+    assigned_team_id: Uuid,
+) {
+    let payload = PairingConflictEvent {
+        team_id,
+        buzzer_id: buzzer_id.to_string(),
+        assigned_team_id,
+    };
+    send_public_event(state, EVENT_PAIRING_CONFLICT, &payload);
+    send_admin_event(state, EVENT_PAIRING_CONFLICT, &payload);
+}
+
 /// Broadcast that pairing snapshot was restored.
 pub fn broadcast_pairing_restored(state: &SharedState, snapshot: IndexMap<Uuid, Team>) {
     let payload = PairingRestoredEvent {
-        snapshot: snapshot.into_iter().map(TeamSummary::from).collect(),
+        snapshot: snapshot
+            .into_iter()
+            .map(|team| public_team(state, TeamSummary::from(team)))
+            .collect(),
     };
     send_public_event(state, EVENT_PAIRING_RESTORED, &payload);
 }
@@ -117,11 +156,225 @@ pub fn broadcast_test_buzz(state: &SharedState, team_id: Uuid) {
     send_admin_event(state, EVENT_TEST_BUZZ, &payload);
 }
 
+/// Broadcast the final standings of a finished game that was loaded again.
+pub fn broadcast_game_finished(state: &SharedState, session: &GameSession) {
+    let teams: Vec<TeamSummary> = session
+        .teams
+        .iter()
+        .map(|(id, team)| TeamSummary::from((*id, team.clone())))
+        .collect();
+    let payload = GameFinishedEvent {
+        game_id: session.id,
+        teams: teams.clone(),
+    };
+    send_admin_event(state, EVENT_GAME_FINISHED, &payload);
+    let payload = GameFinishedEvent {
+        game_id: session.id,
+        teams: teams
+            .into_iter()
+            .map(|team| public_team(state, team))
+            .collect(),
+    };
+    send_public_event(state, EVENT_GAME_FINISHED, &payload);
+}
+
+/// Broadcast the answer countdown started for the team that just buzzed.
+pub fn broadcast_answer_countdown(state: &SharedState, payload: &AnswerCountdownEvent) {
+    send_public_event(state, EVENT_ANSWER_COUNTDOWN, payload);
+    send_admin_event(state, EVENT_ANSWER_COUNTDOWN, payload);
+}
+
+/// Broadcast the sound cue of an accepted buzz.
+pub fn broadcast_buzz_cue(state: &SharedState, team_id: Uuid, color: TeamColor) {
+    let payload = BuzzCueEvent {
+        team_id,
+        color: color.into(),
+    };
+    send_public_event(state, EVENT_BUZZ_CUE, &payload);
+    send_admin_event(state, EVENT_BUZZ_CUE, &payload);
+}
+
+/// Broadcast that a team buzzed again after using its single buzz for the current song.
+pub fn broadcast_buzz_spent(state: &SharedState, team_id: Uuid, buzzer_id: &str) {
+    let payload = BuzzSpentEvent {
+        team_id,
+        buzzer_id: buzzer_id.to_string(),
+    };
+    send_public_event(state, EVENT_BUZZ_SPENT, &payload);
+    send_admin_event(state, EVENT_BUZZ_SPENT, &payload);
+}
+
+/// Relay a spectator reaction to public displays.
+pub fn broadcast_reaction(state: &SharedState, reaction: &str) {
+    let payload = ReactionEvent {
+        reaction: reaction.to_string(),
+    };
+    send_public_event(state, EVENT_REACTION, &payload);
+}
+
+/// Account for a newly connected public client.
+pub fn spectator_connected(state: &SharedState) {
+    state.sse().add_spectator();
+    schedule_spectators_broadcast(state);
+}
+
+/// Account for a disconnected public client.
+pub fn spectator_disconnected(state: &SharedState) {
+    state.sse().remove_spectator();
+    schedule_spectators_broadcast(state);
+}
+
+/// Broadcast the spectator count once `spectators_debounce_ms` elapsed, so a burst of
+/// connections yields a single `spectators` event. Does nothing when the broadcast is disabled.
+fn schedule_spectators_broadcast(state: &SharedState) {
+    let Some(debounce) = state.config().spectators_debounce() else {
+        return;
+    };
+    if !state.sse().schedule_spectators_broadcast() {
+        return;
+    }
+    let state = state.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(debounce).await;
+        state.sse().clear_spectators_broadcast();
+        let payload = SpectatorsEvent {
+            count: state.sse().spectators(),
+        };
+        send_public_event(&state, EVENT_SPECTATORS, &payload);
+        send_admin_event(&state, EVENT_SPECTATORS, &payload);
+    });
+}
+
+/// Tell the admin the battery level a buzzer just reported.
+pub fn broadcast_battery_level(state: &SharedState, buzzer_id: &str, level: u8) {
+    let payload = BatteryLevelEvent {
+        buzzer_id: buzzer_id.to_string(),
+        level,
+    };
+    send_admin_event(state, EVENT_BUZZER_BATTERY, &payload);
+}
+
+/// Warn the admin that a buzzer's battery dropped below `threshold`.
+pub fn broadcast_low_battery(state: &SharedState, buzzer_id: &str, level: u8, threshold: u8) {
+    let payload = LowBatteryEvent {
+        buzzer_id: buzzer_id.to_string(),
+        level,
+        threshold,
+    };
+    send_admin_event(state, EVENT_BUZZER_LOW_BATTERY, &payload);
+}
+
 /// Broadcast a gameplay phase change notification.
 pub async fn broadcast_phase_changed(state: &SharedState, phase: &GamePhase) {
     if let Some(snapshot) = build_phase_changed_event(state, phase).await {
+        let admin_snapshot = PhaseChangedEvent(GamePhaseSnapshot {
+            allowed_actions: Some(AdminAction::available_in(phase)),
+            ..snapshot.0.clone()
+        });
+        send_admin_event(state, EVENT_PHASE_CHANGED, &admin_snapshot);
+        let snapshot = PhaseChangedEvent(public_phase_snapshot(state, snapshot.0));
         send_public_event(state, EVENT_PHASE_CHANGED, &snapshot);
-        send_admin_event(state, EVENT_PHASE_CHANGED, &snapshot);
+    }
+}
+
+/// Broadcast whether team scores are shown to public clients.
+pub fn broadcast_scores_visibility(state: &SharedState, visible: bool) {
+    let payload = ScoresVisibilityEvent { visible };
+    send_public_event(state, EVENT_SCORES_VISIBILITY, &payload);
+    send_admin_event(state, EVENT_SCORES_VISIBILITY, &payload);
+}
+
+/// Project a team for public clients, hiding its score when scores are hidden.
+pub fn public_team(state: &SharedState, team: TeamSummary) -> TeamSummary {
+    if state.scores_visible() {
+        team
+    } else {
+        team.without_score()
+    }
+}
+
+/// Project a game summary for public clients, hiding scores when scores are hidden.
+pub fn public_game_summary(state: &SharedState, summary: GameSummary) -> GameSummary {
+    if state.scores_visible() {
+        summary
+    } else {
+        summary.without_scores()
+    }
+}
+
+/// Project a phase snapshot for public clients, hiding scores when scores are hidden.
+pub fn public_phase_snapshot(
+    state: &SharedState,
+    snapshot: GamePhaseSnapshot,
+) -> GamePhaseSnapshot {
+    if state.scores_visible() {
+        snapshot
+    } else {
+        snapshot.without_scores()
+    }
+}
+
+/// Build the events replaying the current game session and phase, used to resynchronise a
+/// public client that may have missed broadcasts.
+pub async fn public_resync_events(state: &SharedState) -> Vec<ServerEvent> {
+    let mut events = Vec::with_capacity(2);
+
+    let summary: Option<GameSummary> = state
+        .read_current_game(|game| game.cloned().map(Into::into))
+        .await;
+    if let Some(summary) = summary {
+        push_event(
+            &mut events,
+            EVENT_GAME_SESSION,
+            &public_game_summary(state, summary),
+        );
+    }
+
+    let phase = state.state_machine_phase().await;
+    if let Some(snapshot) = build_phase_changed_event(state, &phase).await {
+        let snapshot = PhaseChangedEvent(public_phase_snapshot(state, snapshot.0));
+        push_event(&mut events, EVENT_PHASE_CHANGED, &snapshot);
+    }
+
+    events
+}
+
+/// Build the `snapshot` event greeting a newly connected public SSE client: the phase snapshot,
+/// the teams and the current song. It is sent to that client only, never broadcast.
+pub async fn public_snapshot_event(state: &SharedState) -> Option<ServerEvent> {
+    let phase = state.state_machine_phase().await;
+    let phase = public_phase_snapshot(state, state.game_phase_snapshot(&phase).await);
+    let (teams, song) = state
+        .read_current_game(|game| match game {
+            Some(game) => (
+                game.teams
+                    .iter()
+                    .map(|(id, team)| TeamSummary::from((*id, team.clone())))
+                    .collect(),
+                game.current_song_index
+                    .and_then(|index| game.get_song(index))
+                    .map(|(id, song)| SongSnapshot::from_game_song(id, &song)),
+            ),
+            None => (Vec::new(), None),
+        })
+        .await;
+    let payload = SnapshotEvent {
+        phase,
+        teams: teams
+            .into_iter()
+            .map(|team| public_team(state, team))
+            .collect(),
+        song,
+    };
+    let mut events = Vec::with_capacity(1);
+    push_event(&mut events, EVENT_SNAPSHOT, &payload);
+    events.pop()
+}
+
+fn push_event(events: &mut Vec<ServerEvent>, event: &str, payload: &impl Serialize) {
+    match ServerEvent::json(Some(event.to_string()), payload) {
+        Ok(event) => events.push(event),
+        Err(err) => warn!(event, error = %err, "failed to serialize resync payload"),
     }
 }
 
@@ -147,3 +400,83 @@ async fn build_phase_changed_event(
     let snapshot = state.game_phase_snapshot(phase).await;
     Some(PhaseChangedEvent(snapshot))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        AppState,
+        state_machine::{GameRunningPhase, PrepStatus},
+    };
+
+    async fn phase_changed_payloads(phase: GamePhase) -> (serde_json::Value, serde_json::Value) {
+        let state = AppState::new();
+        let mut admin = state.admin_sse().subscribe();
+        let mut public = state.public_sse().subscribe();
+        broadcast_phase_changed(&state, &phase).await;
+        let admin = serde_json::from_str(&admin.recv().await.unwrap().data).unwrap();
+        let public = serde_json::from_str(&public.recv().await.unwrap().data).unwrap();
+        (admin, public)
+    }
+
+    #[tokio::test]
+    async fn phase_changed_lists_allowed_actions_for_admin_only() {
+        let (admin, public) =
+            phase_changed_payloads(GamePhase::GameRunning(GameRunningPhase::Playing)).await;
+        let actions = admin["allowed_actions"].as_array().unwrap();
+        assert!(actions.contains(&"reveal".into()));
+        assert!(actions.contains(&"pause".into()));
+        assert!(public.get("allowed_actions").is_none());
+
+        let (admin, _) = phase_changed_payloads(GamePhase::Idle).await;
+        assert_eq!(admin["allowed_actions"], serde_json::json!(["load_game"]));
+
+        let (admin, _) = phase_changed_payloads(GamePhase::GameRunning(GameRunningPhase::Prep(
+            PrepStatus::Ready,
+        )))
+        .await;
+        assert_eq!(
+            admin["allowed_actions"],
+            serde_json::json!(["start_pairing", "start", "stop"])
+        );
+    }
+
+    #[tokio::test]
+    async fn snapshot_event_is_built_without_broadcasting() {
+        use crate::state::game::Playlist;
+
+        let state = AppState::new();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "snapshot".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (team_id, _) = game.add_team(
+            Uuid::new_v4(),
+            &state.config(),
+            Some("Owls".into()),
+            None,
+            None,
+            None,
+        );
+        game.teams[&team_id].score = 7;
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state.set_scores_visible(false);
+        let mut public = state.public_sse().subscribe();
+
+        let event = public_snapshot_event(&state).await.unwrap();
+
+        assert_eq!(event.event.as_deref(), Some(EVENT_SNAPSHOT));
+        let payload: serde_json::Value = serde_json::from_str(&event.data).unwrap();
+        assert_eq!(payload["phase"]["phase"], "idle");
+        assert_eq!(payload["teams"][0]["name"], "Owls");
+        assert!(payload["teams"][0]["score"].is_null());
+        assert!(public.try_recv().is_err());
+    }
+}
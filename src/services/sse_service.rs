@@ -14,7 +14,8 @@ use tokio_stream::wrappers::ReceiverStream;
 use crate::{
     dto::sse::{Handshake, ServerEvent, SystemStatus},
     error::ServiceError,
-    state::{SharedState, SseHub},
+    services::sse_events,
+    state::SharedState,
 };
 
 /// Subscribe to the shared public SSE stream.
@@ -22,7 +23,7 @@ pub fn subscribe_public(state: &SharedState) -> broadcast::Receiver<ServerEvent>
     state.public_sse().subscribe()
 }
 
-/// Subscribe to the admin-only SSE stream.
+/// Subscribe to the admin-only SSE stream, returning the token issued to this connection.
 pub async fn subscribe_admin(
     state: &SharedState,
 ) -> Result<(broadcast::Receiver<ServerEvent>, String), ServiceError> {
@@ -35,27 +36,39 @@ pub async fn subscribe_admin(
 /// bookkeeping when the connection is torn down.
 #[derive(Clone)]
 pub enum StreamKind {
-    /// Public SSE stream without special privileges.
-    Public,
-    /// Admin SSE stream with state reference for cleanup.
+    /// Public SSE stream without special privileges, carrying the state so teardown can
+    /// update the spectator count.
+    Public(SharedState),
+    /// Admin SSE stream with state reference and connection token for cleanup.
     /// Carries a clone of the shared application state so teardown logic can
-    /// reset the admin token after the spawned task completes. Cloning
+    /// revoke the connection's token after the spawned task completes. Cloning
     /// `SharedState` is cheap because it is just bumping the inner `Arc`.
-    Admin(SharedState),
+    Admin(SharedState, String),
 }
 
 /// Convert a broadcast receiver into an SSE response, forwarding events and
 /// cleaning up once the client disconnects.
+///
+/// `initial` events are sent to this client only, before any broadcast event. While no event
+/// flows, a `:keepalive` comment is sent every `keepalive` so proxies keep the connection open.
 pub fn to_sse_stream(
     mut receiver: broadcast::Receiver<ServerEvent>,
     kind: StreamKind,
     mut degraded_rx: watch::Receiver<bool>,
+    initial: Vec<ServerEvent>,
+    keepalive: Duration,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
     // small bounded channel between forwarder and response
     let (tx, rx) = mpsc::channel::<Result<Event, Infallible>>(8);
 
     // forwarder task: reads from broadcast and pushes into mpsc
     tokio::spawn(async move {
+        // A client gone meanwhile is caught by `tx.closed()` below.
+        for event in initial {
+            if !forward_broadcast(Ok(event), &tx).await {
+                break;
+            }
+        }
         loop {
             // Forward either broadcast events or degraded-mode changes to the
             // client until the channel closes or the SSE sender drops.
@@ -88,11 +101,14 @@ pub fn to_sse_stream(
         }
 
         match kind {
-            StreamKind::Public => tracing::info!("Public SSE stream disconnected"),
-            StreamKind::Admin(state) => {
+            StreamKind::Public(state) => {
+                sse_events::spectator_disconnected(&state);
+                tracing::info!("Public SSE stream disconnected")
+            }
+            StreamKind::Admin(state, token) => {
                 // Own the necessary state inside the spawned task so we can
                 // clean up even if the request context has already dropped.
-                reset_admin_token(state).await;
+                revoke_admin_token(state, &token).await;
                 tracing::info!("Admin SSE stream disconnected")
             }
         }
@@ -100,33 +116,33 @@ pub fn to_sse_stream(
 
     // response stream reads from mpsc; when client disconnects axum drops this stream
     let stream = ReceiverStream::new(rx);
-    Sse::new(stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
-    )
+    Sse::new(stream).keep_alive(KeepAlive::new().interval(keepalive).text("keepalive"))
 }
 
-/// Reserve the admin token for a new stream, generating one when none exists
-/// and failing if another connection already holds it.
+/// Issue a fresh token for a new admin stream, failing once `admin_max_subscribers`
+/// connections already hold one. The first connection opens a new admin session.
 async fn claim_admin_token(state: &SharedState) -> Result<String, ServiceError> {
-    let mut guard = state.admin_token().lock().await;
-    match &mut *guard {
-        slot @ None => {
-            let token = Uuid::new_v4().simple().to_string();
-            slot.replace(token.clone());
-            Ok(token)
-        }
-        Some(_) => Err(ServiceError::Unauthorized(
-            "Another admin SSE stream is already active".into(),
-        )),
+    let mut tokens = state.admin_tokens().lock().await;
+    let max = state.config().admin_max_subscribers();
+    if tokens.len() >= max {
+        return Err(ServiceError::Unauthorized(if max == 1 {
+            "Another admin SSE stream is already active".into()
+        } else {
+            format!("{max} admin SSE streams are already active")
+        }));
+    }
+    if tokens.is_empty() {
+        state.set_admin_session(Some(Uuid::new_v4()));
     }
+    let token = Uuid::new_v4().simple().to_string();
+    tokens.insert(token.clone());
+    Ok(token)
 }
 
-/// Broadcast the initial handshake payload (including token) to a connecting
-/// admin SSE client.
-pub fn broadcast_admin_handshake(hub: &SseHub, token: &str, degraded: bool) {
-    if let Ok(event) = ServerEvent::json(
+/// Build the initial handshake payload (including its token) sent to a connecting
+/// admin SSE client only, so other admin connections never see it.
+pub fn admin_handshake(token: &str, degraded: bool) -> Option<ServerEvent> {
+    ServerEvent::json(
         Some("handshake".to_string()),
         &Handshake {
             stream: "admin".to_string(),
@@ -134,14 +150,13 @@ pub fn broadcast_admin_handshake(hub: &SseHub, token: &str, degraded: bool) {
             degraded,
             token: Some(token.to_string()),
         },
-    ) {
-        hub.broadcast(event);
-    }
+    )
+    .ok()
 }
 
-/// Broadcast the initial handshake payload to a connecting public SSE client.
-pub fn broadcast_public_handshake(hub: &SseHub, degraded: bool) {
-    if let Ok(event) = ServerEvent::json(
+/// Build the initial handshake payload sent to a connecting public SSE client only.
+pub fn public_handshake(degraded: bool) -> Option<ServerEvent> {
+    ServerEvent::json(
         Some("handshake".to_string()),
         &Handshake {
             stream: "public".to_string(),
@@ -149,16 +164,18 @@ pub fn broadcast_public_handshake(hub: &SseHub, degraded: bool) {
             degraded,
             token: None,
         },
-    ) {
-        hub.broadcast(event);
-    }
+    )
+    .ok()
 }
 
-/// Clear any stored admin token so the next admin connection negotiates a
-/// fresh credential.
-async fn reset_admin_token(state: SharedState) {
-    let mut guard = state.admin_token().lock().await;
-    guard.take();
+/// Revoke the token of a disconnected admin stream, closing the admin session once the last
+/// admin stream is gone.
+async fn revoke_admin_token(state: SharedState, token: &str) {
+    let mut tokens = state.admin_tokens().lock().await;
+    tokens.remove(token);
+    if tokens.is_empty() {
+        state.set_admin_session(None);
+    }
 }
 
 /// Forward a broadcast payload to the SSE mpsc channel, handling lag and
@@ -204,3 +221,51 @@ async fn forward_system_status(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::AppConfig, state::AppState};
+
+    #[tokio::test]
+    async fn admin_tokens_are_issued_per_connection_up_to_the_limit() {
+        let state = AppState::with_config(AppConfig::from_json(r#"{"admin_max_subscribers": 2}"#));
+
+        let (_gm, first) = subscribe_admin(&state).await.unwrap();
+        let session = state.admin_session();
+        let (_scoreboard, second) = subscribe_admin(&state).await.unwrap();
+        assert_ne!(first, second);
+        assert_eq!(state.admin_session(), session);
+        assert!(matches!(
+            subscribe_admin(&state).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        revoke_admin_token(state.clone(), &first).await;
+        assert!(!state.admin_tokens().lock().await.contains(&first));
+        assert!(state.admin_tokens().lock().await.contains(&second));
+        assert_eq!(state.admin_session(), session);
+        let (_replacement, _) = subscribe_admin(&state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn public_connections_update_the_spectator_count() {
+        let state =
+            AppState::with_config(AppConfig::from_json(r#"{"spectators_debounce_ms": 20}"#));
+        let mut observer = state.public_sse().subscribe();
+
+        let stream = crate::routes::sse::public_stream(axum::extract::State(state.clone())).await;
+        assert_eq!(state.sse().spectators(), 1);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let event = observer.try_recv().unwrap();
+        assert_eq!(event.event.as_deref(), Some("spectators"));
+        assert_eq!(event.data, r#"{"count":1}"#);
+
+        drop(stream);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(state.sse().spectators(), 0);
+        let event = observer.try_recv().unwrap();
+        assert_eq!(event.data, r#"{"count":0}"#);
+        assert!(observer.try_recv().is_err());
+    }
+}
@@ -31,7 +31,8 @@ where
                 loop {
                     match store.health_check().await {
                         Ok(()) => {
-                            if state.is_degraded().await {
+                            // A manual drill keeps its flag while the storage stays healthy.
+                            if state.is_degraded().await && !state.degraded_overridden() {
                                 info!("storage healthy again; leaving degraded mode");
                                 state.update_degraded(false).await;
                             }
@@ -57,6 +58,7 @@ where
                                                 attempt, error = %reconnect_err,
                                                 "storage reconnect first attempt failed; entering in degraded mode"
                                             );
+                                            state.end_degraded_override();
                                             state.update_degraded(true).await;
                                         } else {
                                             warn!(attempt, error = %reconnect_err, "storage reconnect attempt failed");
@@ -69,6 +71,7 @@ where
                             }
 
                             if reconnected {
+                                state.end_degraded_override();
                                 state.update_degraded(false).await;
                                 sleep(HEALTH_POLL_INTERVAL).await;
                                 continue;
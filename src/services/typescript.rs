@@ -0,0 +1,274 @@
+//! TypeScript definitions of the realtime payloads, generated from their `ToSchema` derives.
+//!
+//! The JSON schemas collected by [`events_doc`] are translated into `.d.ts` declarations: one
+//! `export interface` (or `export type` for enums and unions) per component, plus an `SseEvents`
+//! map from SSE event names to their payload and a `WebSocketMessages` map per endpoint, so the
+//! frontends can type their `EventSource` and WebSocket handlers.
+
+use std::fmt::Write;
+
+use serde_json::{Map, Value};
+
+use super::documentation::events_doc;
+
+/// Render the TypeScript declarations of every SSE event payload and WebSocket message.
+pub fn render_definitions() -> String {
+    let doc = serde_json::to_value(events_doc()).expect("events catalog serializes to JSON");
+    let mut out = String::from(
+        "// Generated by `ts-defs-generator` from the backend's DTOs; do not edit by hand.\n",
+    );
+
+    if let Some(schemas) = doc["components"]["schemas"].as_object() {
+        for (name, schema) in schemas {
+            out.push('\n');
+            write_declaration(&mut out, name, schema);
+        }
+    }
+
+    out.push_str("\n/** Payload of each SSE event, keyed by the `event:` field. */\n");
+    out.push_str("export interface SseEvents {\n");
+    for entry in doc["sse"].as_array().into_iter().flatten() {
+        let _ = writeln!(
+            out,
+            "  {}: {};",
+            property_key(entry["event"].as_str().unwrap_or_default()),
+            ts_type(&entry["schema"], "")
+        );
+    }
+    out.push_str("}\n");
+
+    out.push_str("\n/** Messages exchanged on each WebSocket endpoint. */\n");
+    out.push_str("export interface WebSocketMessages {\n");
+    let mut endpoints: Vec<&str> = Vec::new();
+    for entry in doc["websocket"].as_array().into_iter().flatten() {
+        let path = entry["path"].as_str().unwrap_or_default();
+        if !endpoints.contains(&path) {
+            endpoints.push(path);
+        }
+    }
+    for path in endpoints {
+        let _ = writeln!(out, "  {}: {{", property_key(path));
+        for entry in doc["websocket"].as_array().into_iter().flatten() {
+            if entry["path"] == path {
+                let _ = writeln!(
+                    out,
+                    "    {}: {};",
+                    entry["direction"].as_str().unwrap_or_default(),
+                    ts_type(&entry["schema"], "")
+                );
+            }
+        }
+        out.push_str("  };\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Write the declaration of the component `name`: an interface for plain objects, a type alias
+/// otherwise.
+fn write_declaration(out: &mut String, name: &str, schema: &Value) {
+    write_doc(out, "", schema);
+    match schema.as_object() {
+        Some(object) if is_plain_object(object) => {
+            let _ = writeln!(out, "export interface {name} {{");
+            write_properties(out, "  ", object);
+            out.push_str("}\n");
+        }
+        _ => {
+            let _ = writeln!(out, "export type {name} = {};", ts_type(schema, ""));
+        }
+    }
+}
+
+fn is_plain_object(schema: &Map<String, Value>) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("object")
+        && schema.contains_key("properties")
+}
+
+fn write_properties(out: &mut String, indent: &str, schema: &Map<String, Value>) {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+    for (key, property) in properties {
+        write_doc(out, indent, property);
+        let optional = if required.contains(&key.as_str()) {
+            ""
+        } else {
+            "?"
+        };
+        let _ = writeln!(
+            out,
+            "{indent}{}{optional}: {};",
+            property_key(key),
+            ts_type(property, indent)
+        );
+    }
+}
+
+fn write_doc(out: &mut String, indent: &str, schema: &Value) {
+    let Some(description) = schema.get("description").and_then(Value::as_str) else {
+        return;
+    };
+    let _ = writeln!(out, "{indent}/**");
+    for line in description.lines() {
+        let _ = writeln!(out, "{indent} * {}", line.replace("*/", "*\\/"));
+    }
+    let _ = writeln!(out, "{indent} */");
+}
+
+/// TypeScript type expression of a JSON schema, with inline object literals closed at `indent`.
+fn ts_type(schema: &Value, indent: &str) -> String {
+    let Some(object) = schema.as_object() else {
+        return "unknown".to_string();
+    };
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    if let Some(constant) = object.get("const") {
+        return constant.to_string();
+    }
+    if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        return union(values.iter().map(Value::to_string));
+    }
+    if let Some(variants) = object
+        .get("oneOf")
+        .or_else(|| object.get("anyOf"))
+        .and_then(Value::as_array)
+    {
+        return union(variants.iter().map(|variant| ts_type(variant, indent)));
+    }
+    if let Some(parts) = object.get("allOf").and_then(Value::as_array) {
+        let parts: Vec<String> = parts
+            .iter()
+            .map(|part| wrap(ts_type(part, indent)))
+            .collect();
+        return parts.join(" & ");
+    }
+    match object.get("type") {
+        Some(Value::Array(types)) => union(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|kind| primitive_type(kind, object, indent)),
+        ),
+        Some(Value::String(kind)) => primitive_type(kind, object, indent),
+        _ => "unknown".to_string(),
+    }
+}
+
+fn primitive_type(kind: &str, schema: &Map<String, Value>, indent: &str) -> String {
+    match kind {
+        "string" => "string".to_string(),
+        "integer" | "number" => "number".to_string(),
+        "boolean" => "boolean".to_string(),
+        "null" => "null".to_string(),
+        "array" => {
+            let items = schema
+                .get("items")
+                .map_or("unknown".to_string(), |items| ts_type(items, indent));
+            format!("{}[]", wrap(items))
+        }
+        "object" => object_type(schema, indent),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Inline type of an object schema: a record for maps, an object literal otherwise.
+fn object_type(schema: &Map<String, Value>, indent: &str) -> String {
+    if !schema.contains_key("properties") {
+        let value = match schema.get("additionalProperties") {
+            Some(values @ Value::Object(_)) => ts_type(values, indent),
+            _ => "unknown".to_string(),
+        };
+        return format!("Record<string, {value}>");
+    }
+    let mut out = String::from("{\n");
+    write_properties(&mut out, &format!("{indent}  "), schema);
+    out.push_str(indent);
+    out.push('}');
+    out
+}
+
+fn union(variants: impl Iterator<Item = String>) -> String {
+    let mut distinct: Vec<String> = Vec::new();
+    for variant in variants {
+        if !distinct.contains(&variant) {
+            distinct.push(variant);
+        }
+    }
+    match distinct.len() {
+        0 => "never".to_string(),
+        1 => distinct.remove(0),
+        _ => distinct
+            .into_iter()
+            .map(wrap)
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+/// Parenthesize composite types so they can be combined or suffixed with `[]`.
+fn wrap(ty: String) -> String {
+    let mut depth = 0usize;
+    let mut composite = false;
+    let mut previous = ' ';
+    for c in ty.chars() {
+        match c {
+            '{' | '(' | '[' | '<' => depth += 1,
+            '}' | ')' | ']' | '>' => depth = depth.saturating_sub(1),
+            '|' | '&' if depth == 0 && previous == ' ' => composite = true,
+            _ => {}
+        }
+        previous = c;
+    }
+    if composite { format!("({ty})") } else { ty }
+}
+
+fn property_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let identifier = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if identifier {
+        key.to_string()
+    } else {
+        Value::from(key).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_events_get_typescript_definitions() {
+        let defs = render_definitions();
+
+        for declaration in [
+            "export type PhaseChangedEvent = ",
+            "export interface FieldsFoundEvent {",
+            "export interface AnswerValidationEvent {",
+            "export interface TeamCreatedEvent {",
+            "export type BuzzerInboundMessage = ",
+            "export type BuzzerOutboundMessage = ",
+        ] {
+            assert!(defs.contains(declaration), "missing `{declaration}`");
+        }
+        assert!(defs.contains("  phase_changed: PhaseChangedEvent;\n"));
+        assert!(defs.contains("  \"buzz.cue\": BuzzCueEvent;\n"));
+        assert!(defs.contains("    inbound: BuzzerInboundMessage;\n"));
+        assert!(!defs.contains("unknown;"), "untranslated schema:\n{defs}");
+    }
+}
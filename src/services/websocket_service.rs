@@ -1,10 +1,14 @@
 use std::time::Duration;
 
-use axum::extract::ws::{Message, WebSocket};
+use axum::extract::ws::{CloseFrame, Message, WebSocket, close_code};
 use futures::{SinkExt, StreamExt};
 use thiserror::Error;
-use tokio::{sync::mpsc, task::JoinHandle};
-use tracing::{info, warn};
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+    time::{Interval, MissedTickBehavior},
+};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::{
@@ -15,19 +19,19 @@ use crate::{
     },
     error::ServiceError,
     services::{
+        admin_service,
         pairing::{PairingSessionUpdate, apply_pairing_update, handle_pairing_progress},
         sse_events,
     },
     state::{
         BuzzerConnection, SharedState,
         game::Team,
+        metrics::Counter,
         state_machine::{GameEvent, GamePhase, GameRunningPhase, PauseKind, PrepStatus},
-        transitions::run_transition_with_broadcast,
+        timers::TimerKind,
     },
 };
 
-const IDENT_TIMEOUT: Duration = Duration::from_secs(10);
-
 /// Internal error type for buzz handling operations.
 ///
 /// This type represents errors that occur during WebSocket buzz processing,
@@ -46,12 +50,23 @@ enum BuzzError {
     /// Pairing session state was lost.
     #[error("pairing workflow lost session state")]
     PairingSessionLost,
+    /// Buzzer is paired with another team and the reassignment was not confirmed.
+    #[error(
+        "pairing ignored: buzzer `{buzzer_id}` is already paired with team `{assigned_team_id}`"
+    )]
+    PairingConflict {
+        buzzer_id: String,
+        assigned_team_id: Uuid,
+    },
     /// Pairing target changed during update operation.
     #[error("pairing target changed during update")]
     PairingTargetChanged,
     /// Buzzer ID is not associated with any team.
     #[error("buzz ignored: unknown buzzer ID `{0}`")]
     UnknownBuzzerId(String),
+    /// The team already used its single buzz for the current song.
+    #[error("buzz ignored: buzzer `{0}` already buzzed during this song")]
+    BuzzSpent(String),
     /// Error from persistence or state management operations.
     #[error("service error: {0}")]
     Service(#[from] ServiceError),
@@ -71,7 +86,8 @@ pub async fn handle_socket(state: SharedState, socket: WebSocket) {
         }
     });
 
-    let initial_message = match tokio::time::timeout(IDENT_TIMEOUT, receiver.next()).await {
+    let ident_timeout = state.config().buzzer_ident_timeout();
+    let initial_message = match tokio::time::timeout(ident_timeout, receiver.next()).await {
         Ok(Some(Ok(Message::Text(text)))) => text,
         Ok(Some(Ok(Message::Close(_)))) => {
             finalize(writer_task, outbound_tx).await;
@@ -84,6 +100,7 @@ pub async fn handle_socket(state: SharedState, socket: WebSocket) {
         }
         Ok(Some(Err(err))) => {
             warn!(error = %err, "websocket receive error");
+            close_if_oversized(err, &outbound_tx);
             finalize(writer_task, outbound_tx).await;
             return;
         }
@@ -104,49 +121,75 @@ pub async fn handle_socket(state: SharedState, socket: WebSocket) {
         }
     };
 
-    let BuzzerInboundMessage::Identification { id: buzzer_id } = inbound else {
+    let BuzzerInboundMessage::Identification {
+        id: buzzer_id,
+        battery,
+    } = inbound
+    else {
         warn!("first message was not identification");
         let _ = outbound_tx.send(Message::Close(None));
         finalize(writer_task, outbound_tx).await;
         return;
     };
 
-    state.buzzers().insert(
-        buzzer_id.clone(),
-        BuzzerConnection {
-            id: buzzer_id.clone(),
-            tx: outbound_tx.clone(),
-        },
-    );
-
-    info!(id = %buzzer_id, "buzzer connected");
+    state.connect_buzzer(BuzzerConnection {
+        id: buzzer_id.clone(),
+        tx: outbound_tx.clone(),
+    });
+    state.metrics().increment(Counter::BuzzerConnects);
 
-    // Determine which pattern to send on connection
-    let initial_pattern = state
-        .buzzer_last_patterns()
-        .get(&buzzer_id)
-        .map(|entry| {
-            let pattern = entry.value().clone();
-            info!(id = %buzzer_id, preset = ?pattern, "restoring last known pattern on reconnection");
-            pattern
-        })
-        .unwrap_or(BuzzerPatternPreset::WaitingForPairing);
+    info!(id = %buzzer_id, ?battery, "buzzer connected");
+    if let Some(level) = battery {
+        report_battery_level(
+            &state,
+            &buzzer_id,
+            level,
+            state.config().low_battery_threshold(),
+        );
+    }
 
-    // Send initial pattern - terminate on failure
-    if send_pattern_to_buzzer_tx(&state, &buzzer_id, &outbound_tx, initial_pattern).is_err() {
+    // Acknowledge the identification and send the initial pattern - terminate on failure
+    if greet_buzzer(&state, &buzzer_id, &outbound_tx)
+        .await
+        .is_err()
+    {
         info!(id = %buzzer_id, "connection closed during initial pattern send, terminating");
+        state.metrics().increment(Counter::BuzzerDisconnects);
         finalize(writer_task, outbound_tx).await;
         return;
     }
 
-    while let Some(message) = receiver.next().await {
+    let mut pings = state.config().buzzer_ping_interval().map(|period| {
+        let mut pings = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        pings.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        pings
+    });
+    let mut awaiting_pong = false;
+
+    loop {
+        let message = tokio::select! {
+            message = receiver.next() => message,
+            () = next_ping(&mut pings) => {
+                if awaiting_pong {
+                    warn!(id = %buzzer_id, "buzzer did not answer the last ping, dropping it");
+                    let _ = outbound_tx.send(Message::Close(None));
+                    break;
+                }
+                awaiting_pong = outbound_tx.send(Message::Ping(Default::default())).is_ok();
+                continue;
+            }
+        };
+        let Some(message) = message else {
+            break;
+        };
         match message {
             Ok(Message::Text(text)) => {
-                info!(id = %buzzer_id, payload = %text, "received buzzer message");
+                debug!(id = %buzzer_id, payload = %text, "received buzzer message");
 
                 match BuzzerInboundMessage::from_json_str(&text) {
                     Ok(msg) => match msg {
                         BuzzerInboundMessage::Buzz { id } => {
+                            state.metrics().increment(Counter::Buzzes);
                             let res = if id == buzzer_id {
                                 handle_buzz(&state, &id, &outbound_tx).await
                             } else {
@@ -170,6 +213,22 @@ pub async fn handle_socket(state: SharedState, socket: WebSocket) {
                         BuzzerInboundMessage::Identification { .. } => {
                             warn!(id = %buzzer_id, "ignoring duplicate identification message");
                         }
+                        BuzzerInboundMessage::Battery { id, percent } => {
+                            if id == buzzer_id {
+                                report_battery_level(
+                                    &state,
+                                    &buzzer_id,
+                                    percent,
+                                    state.config().low_battery_threshold(),
+                                );
+                            } else {
+                                warn!(
+                                    expected = %buzzer_id,
+                                    got = %id,
+                                    "ignoring battery report with a mismatched buzzer ID"
+                                );
+                            }
+                        }
                     },
                     Err(err) => {
                         warn!(id = %buzzer_id, error = %err, "failed to parse or validate buzzer message");
@@ -185,20 +244,50 @@ pub async fn handle_socket(state: SharedState, socket: WebSocket) {
                 break;
             }
             Ok(Message::Binary(_)) => {}
-            Ok(Message::Pong(_)) => {}
+            Ok(Message::Pong(_)) => awaiting_pong = false,
             Err(err) => {
                 warn!(id = %buzzer_id, error = %err, "websocket error");
+                close_if_oversized(err, &outbound_tx);
                 break;
             }
         }
     }
 
-    state.buzzers().remove(&buzzer_id);
+    state.disconnect_buzzer(&buzzer_id);
+    state.metrics().increment(Counter::BuzzerDisconnects);
     info!(id = %buzzer_id, "buzzer disconnected");
 
     finalize(writer_task, outbound_tx).await;
 }
 
+/// Wait for the next keepalive ping, or forever when pings are disabled.
+async fn next_ping(pings: &mut Option<Interval>) {
+    match pings {
+        Some(pings) => {
+            pings.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Record a buzzer's battery level, publish it to the GM and warn when it drops below
+/// `threshold`.
+///
+/// The warning is sent once per crossing: a buzzer staying low is not reported again until its
+/// level has recovered to the threshold or above.
+fn report_battery_level(state: &SharedState, buzzer_id: &str, level: u8, threshold: Option<u8>) {
+    let previous = state.record_battery_level(buzzer_id, level);
+    sse_events::broadcast_battery_level(state, buzzer_id, level);
+    let Some(threshold) = threshold else {
+        return;
+    };
+    let was_low = previous.is_some_and(|previous| previous < threshold);
+    if level < threshold && !was_low {
+        warn!(id = %buzzer_id, level, threshold, "buzzer battery is low");
+        sse_events::broadcast_low_battery(state, buzzer_id, level, threshold);
+    }
+}
+
 /// Serialize a payload and push it onto the provided WebSocket sender.
 ///
 /// Returns `Ok(())` if the message was successfully queued for sending or if
@@ -227,21 +316,155 @@ where
         .map_err(|_| BuzzError::ConnectionClosed)
 }
 
-/// Send a pattern update to the buzzer associated with `team`.
+/// Send a pattern update to every buzzer associated with `team`.
 ///
-/// If the team has no paired buzzer or the buzzer is not connected,
-/// logs a warning instead of returning an error.
+/// If the team has no paired buzzer, logs a warning instead of returning an error. Patterns
+/// for disconnected buzzers are stored and sent when they reconnect.
 pub fn send_pattern_to_team_buzzer(
     state: &SharedState,
     team_id: &Uuid,
     team: &Team,
     preset: BuzzerPatternPreset,
 ) {
-    let Some(buzzer_id) = team.buzzer_id.as_ref() else {
+    if team.buzzer_ids.is_empty() {
         warn!(team_id = %team_id, "cannot send pattern: team has no paired buzzer");
         return;
+    }
+    for buzzer_id in &team.buzzer_ids {
+        send_pattern_to_buzzer(state, buzzer_id, preset.clone());
+    }
+}
+
+/// Flash the `correct` pattern on every buzzer of `team` for `duration`, then restore the
+/// pattern each buzzer showed before.
+///
+/// A buzzer that received another pattern in the meantime (e.g. after a reveal) keeps it.
+pub fn celebrate_team_buzzers(state: &SharedState, team: &Team, duration: Duration) {
+    let previous: Vec<(String, BuzzerPatternPreset)> = team
+        .buzzer_ids
+        .iter()
+        .filter_map(|id| {
+            let preset = state.buzzer_last_patterns().get(id)?;
+            Some((id.clone(), preset))
+        })
+        .collect();
+    for buzzer_id in &team.buzzer_ids {
+        send_pattern_to_buzzer(
+            state,
+            buzzer_id,
+            BuzzerPatternPreset::Correct(team.color.clone()),
+        );
+    }
+
+    let state = state.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(duration).await;
+        for (buzzer_id, preset) in previous {
+            let still_celebrating = state
+                .buzzer_last_patterns()
+                .get(&buzzer_id)
+                .is_some_and(|preset| matches!(preset, BuzzerPatternPreset::Correct(_)));
+            if still_celebrating {
+                send_pattern_to_buzzer(&state, &buzzer_id, preset);
+            }
+        }
+    });
+}
+
+/// Flash the `identify` pattern on a connected buzzer for `duration`, then restore the pattern it
+/// showed before, so the GM can spot it physically. Game state is left untouched.
+///
+/// A buzzer that received another pattern in the meantime keeps it.
+pub fn identify_buzzer(
+    state: &SharedState,
+    buzzer_id: &str,
+    duration: Duration,
+) -> Result<(), ServiceError> {
+    let tx = state
+        .buzzers()
+        .get(buzzer_id)
+        .map(|conn| conn.tx.clone())
+        .ok_or_else(|| ServiceError::NotFound(format!("buzzer `{buzzer_id}` is not connected")))?;
+    let previous = state
+        .buzzer_last_patterns()
+        .get(buzzer_id)
+        .unwrap_or(BuzzerPatternPreset::WaitingForPairing);
+    send_pattern_to_buzzer_tx(state, buzzer_id, &tx, BuzzerPatternPreset::Identify)
+        .map_err(|_| ServiceError::NotFound(format!("buzzer `{buzzer_id}` is not connected")))?;
+
+    let state = state.clone();
+    let buzzer_id = buzzer_id.to_string();
+    tokio::spawn(async move {
+        tokio::time::sleep(duration).await;
+        let still_identifying = state
+            .buzzer_last_patterns()
+            .get(&buzzer_id)
+            .is_some_and(|preset| matches!(preset, BuzzerPatternPreset::Identify));
+        if still_identifying {
+            send_pattern_to_buzzer(&state, &buzzer_id, previous);
+        }
+    });
+    Ok(())
+}
+
+/// Greet a freshly identified buzzer: a `welcome` acknowledgement (when enabled), then the
+/// pattern it should display, restoring the last known one on reconnection.
+async fn greet_buzzer(
+    state: &SharedState,
+    buzzer_id: &str,
+    tx: &mpsc::UnboundedSender<Message>,
+) -> Result<(), BuzzError> {
+    if state.config().buzzer_welcome() {
+        send_message_to_websocket(
+            tx,
+            &BuzzerOutboundMessage::Welcome {
+                id: buzzer_id.to_string(),
+                reconnect_token: None,
+            },
+        )?;
+    }
+
+    let pairing = pairing_resume(state, buzzer_id).await;
+    let initial_pattern = match &pairing {
+        Some((_, pattern)) => pattern.clone(),
+        None => state
+            .buzzer_last_patterns()
+            .get(buzzer_id)
+            .map(|pattern| {
+                info!(id = %buzzer_id, preset = ?pattern, "restoring last known pattern on reconnection");
+                pattern
+            })
+            .unwrap_or(BuzzerPatternPreset::WaitingForPairing),
     };
-    send_pattern_to_buzzer(state, buzzer_id, preset);
+    send_pattern_to_buzzer_tx(state, buzzer_id, tx, initial_pattern)?;
+
+    if let Some((pairing_team_id, _)) = pairing {
+        sse_events::broadcast_pairing_waiting(state, pairing_team_id);
+    }
+    Ok(())
+}
+
+/// Resolve the pairing target and the pattern a buzzer connecting mid-pairing should display.
+///
+/// The cached pattern may predate the pairing session, so it is derived from the roster instead:
+/// the team's standby pattern when the buzzer is already paired, the pairing one otherwise.
+async fn pairing_resume(
+    state: &SharedState,
+    buzzer_id: &str,
+) -> Option<(Uuid, BuzzerPatternPreset)> {
+    let session = state.pairing_session().await?;
+    let pattern = state
+        .read_current_game(|game| {
+            let game = game?;
+            let team_id = game.team_id_by_buzzer(buzzer_id)?;
+            Some(BuzzerPatternPreset::Standby(
+                game.teams[&team_id].color.clone(),
+            ))
+        })
+        .await
+        .unwrap_or(BuzzerPatternPreset::WaitingForPairing);
+    info!(id = %buzzer_id, team_id = %session.pairing_team_id, preset = ?pattern, "resuming pairing on connection");
+    Some((session.pairing_team_id, pattern))
 }
 
 /// Send a pattern update to a buzzer using its connection channel.
@@ -259,22 +482,18 @@ fn send_pattern_to_buzzer_tx(
     tx: &mpsc::UnboundedSender<Message>,
     preset: BuzzerPatternPreset,
 ) -> Result<(), BuzzError> {
-    let message = BuzzerOutboundMessage {
-        pattern: state.config().buzzer_pattern(preset.clone()),
-    };
+    let message = BuzzerOutboundMessage::Pattern(state.config().buzzer_pattern(preset.clone()));
 
     let res = send_message_to_websocket(tx, &message);
 
     if res.is_err() {
         // Send failed (writer closed)
         warn!(buzzer_id = %buzzer_id, preset = ?preset, "send failed (writer closed), removing buzzer connection");
-        state.buzzers().remove(buzzer_id);
+        state.disconnect_buzzer(buzzer_id);
     }
 
     // Store as last known pattern (if it was successful or not)
-    state
-        .buzzer_last_patterns()
-        .insert(buzzer_id.to_string(), preset);
+    state.buzzer_last_patterns().insert(buzzer_id, preset);
     res
 }
 
@@ -294,7 +513,7 @@ fn send_pattern_to_buzzer(state: &SharedState, buzzer_id: &String, preset: Buzze
             warn!(buzzer_id = %buzzer_id, preset = ?preset, "buzzer disconnected, storing pattern for reconnection");
             state
                 .buzzer_last_patterns()
-                .insert(buzzer_id.clone(), preset);
+                .insert(buzzer_id.as_str(), preset);
         }
     }
 }
@@ -314,6 +533,9 @@ async fn handle_buzz(
         GamePhase::GameRunning(GameRunningPhase::Playing) => {
             handle_playing_buzz(state, buzzer_id).await
         }
+        GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz { id })) => {
+            queue_buzz(state, buzzer_id, &id).await
+        }
         _ => Err(BuzzError::NotRunningPhase),
     }
 }
@@ -324,24 +546,29 @@ async fn handle_prep_ready_buzz(
     outbound_tx: &mpsc::UnboundedSender<Message>,
 ) -> Result<(), BuzzError> {
     let config = state.config();
+    let ids = state.ids();
+    let session = state.admin_session();
     let maybe_result = state
         .with_current_game_mut(|game| {
             if let Some((&team_id, _)) = game
                 .teams
                 .iter()
-                .find(|(_, team)| team.buzzer_id.as_deref() == Some(buzzer_id))
+                .find(|(_, team)| team.has_buzzer(buzzer_id))
             {
                 sse_events::broadcast_test_buzz(state, team_id);
                 Ok(None)
             } else if state.all_teams_paired(&game.teams) {
-                let (team_id, new_team) = game.add_team(
+                let (team_id, _) = game.add_team(
+                    ids.next_id(),
                     config.as_ref(),
                     None,
                     Some(buzzer_id.to_string()),
                     None,
                     None,
                 );
-                Ok(Some((game.id, team_id, new_team)))
+                let new_team = &mut game.teams[&team_id];
+                new_team.created_in_session = session;
+                Ok(Some((game.id, team_id, new_team.clone())))
             } else {
                 Ok(None)
             }
@@ -349,6 +576,12 @@ async fn handle_prep_ready_buzz(
         .await?;
 
     if let Some((game_id, team_id, team)) = maybe_result {
+        info!(
+            team_id = %team_id,
+            buzzer_id,
+            admin_session = ?session,
+            "team created automatically from a buzzer"
+        );
         // If we can't notify the buzzer, abort - connection is dead
         send_pattern_to_buzzer_tx(
             state,
@@ -371,6 +604,43 @@ async fn handle_prep_pairing_buzz(
     state: &SharedState,
     buzzer_id: &str,
     outbound_tx: &mpsc::UnboundedSender<Message>,
+) -> Result<(), BuzzError> {
+    let allow_reassign = !state.config().confirm_pairing_reassignment();
+    assign_pairing_buzzer(state, buzzer_id, outbound_tx, allow_reassign).await
+}
+
+/// Pair a connected buzzer with the team currently pairing on behalf of the GM.
+///
+/// `force` confirms taking the buzzer away from the team it is paired with when the
+/// configuration requires such reassignments to be confirmed.
+pub async fn confirm_pairing_buzzer(
+    state: &SharedState,
+    buzzer_id: &str,
+    force: bool,
+) -> Result<(), ServiceError> {
+    let outbound_tx = state
+        .buzzers()
+        .get(buzzer_id)
+        .map(|connection| connection.tx.clone())
+        .ok_or_else(|| ServiceError::NotFound(format!("buzzer `{buzzer_id}` is not connected")))?;
+    let allow_reassign = force || !state.config().confirm_pairing_reassignment();
+    assign_pairing_buzzer(state, buzzer_id, &outbound_tx, allow_reassign)
+        .await
+        .map_err(|err| match err {
+            BuzzError::Service(err) => err,
+            other => ServiceError::InvalidState(other.to_string()),
+        })
+}
+
+/// Assign `buzzer_id` to the team currently pairing and advance the pairing workflow.
+///
+/// A buzzer already paired with another team is moved over when `allow_reassign` is set;
+/// otherwise the conflict is broadcast and the assignment rejected.
+async fn assign_pairing_buzzer(
+    state: &SharedState,
+    buzzer_id: &str,
+    outbound_tx: &mpsc::UnboundedSender<Message>,
+    allow_reassign: bool,
 ) -> Result<(), BuzzError> {
     let pairing_session = state
         .pairing_session()
@@ -378,6 +648,20 @@ async fn handle_prep_pairing_buzz(
         .ok_or(BuzzError::PairingSessionLost)?;
     let team_id = pairing_session.pairing_team_id;
 
+    if !allow_reassign {
+        let assigned_team_id = state
+            .read_current_game(|game| game.and_then(|game| game.team_id_by_buzzer(buzzer_id)))
+            .await
+            .filter(|assigned| *assigned != team_id);
+        if let Some(assigned_team_id) = assigned_team_id {
+            sse_events::broadcast_pairing_conflict(state, team_id, buzzer_id, assigned_team_id);
+            return Err(BuzzError::PairingConflict {
+                buzzer_id: buzzer_id.to_string(),
+                assigned_team_id,
+            });
+        }
+    }
+
     let (game_id, roster, team_color, modified_teams) = state
         .with_current_game_mut(|game| {
             let mut modified_teams = Vec::new();
@@ -387,14 +671,14 @@ async fn handle_prep_pairing_buzz(
                     .teams
                     .get_mut(&team_id)
                     .ok_or_else(|| ServiceError::NotFound(format!("team `{team_id}` not found")))?;
-                team.buzzer_id = Some(buzzer_id.to_string());
+                team.buzzer_ids = vec![buzzer_id.to_string()];
                 modified_teams.push((team_id, team.clone()));
                 team.color.clone()
             };
 
             for (id, team) in game.teams.iter_mut() {
-                if *id != team_id && team.buzzer_id.as_deref() == Some(buzzer_id) {
-                    team.buzzer_id = None;
+                if *id != team_id && team.has_buzzer(buzzer_id) {
+                    team.buzzer_ids.retain(|id| id != buzzer_id);
                     modified_teams.push((*id, team.clone()));
                 }
             }
@@ -428,46 +712,136 @@ async fn handle_prep_pairing_buzz(
     Ok(())
 }
 
+/// Buzz on behalf of a team, as if its first buzzer had been pressed while the song plays.
+///
+/// Used by the GM to test the buzz flow or to stand in for a broken buzzer, which does not need
+/// to be connected.
+pub async fn simulate_team_buzz(state: &SharedState, team_id: Uuid) -> Result<(), ServiceError> {
+    let buzzer_id = state
+        .with_current_game(|game| {
+            let team = game
+                .teams
+                .get(&team_id)
+                .ok_or_else(|| ServiceError::NotFound(format!("team `{team_id}` not found")))?;
+            team.buzzer_ids.first().cloned().ok_or_else(|| {
+                ServiceError::InvalidState(format!("team `{team_id}` has no paired buzzer"))
+            })
+        })
+        .await?;
+    handle_playing_buzz(state, &buzzer_id)
+        .await
+        .map_err(|err| match err {
+            BuzzError::Service(err) => err,
+            other => ServiceError::InvalidState(other.to_string()),
+        })
+}
+
+/// Queue a buzz pressed while `answering_buzzer` has the turn, so the GM can hand the turn over
+/// with [`give_turn_to_next_buzz`]. Each team is queued once, and never while answering.
+async fn queue_buzz(
+    state: &SharedState,
+    buzzer_id: &str,
+    answering_buzzer: &str,
+) -> Result<(), BuzzError> {
+    let one_buzz_per_song = state.config().one_buzz_per_song();
+    let queue = state.buzz_queue();
+    let (team_id, spent, waiting) = state
+        .read_current_game(|maybe| {
+            maybe.and_then(|game| {
+                let team_id = game.team_id_by_buzzer(buzzer_id)?;
+                let waiting = std::iter::once(answering_buzzer)
+                    .chain(queue.iter().map(String::as_str))
+                    .any(|queued| game.team_id_by_buzzer(queued) == Some(team_id));
+                Some((team_id, game.buzzed_teams.contains(&team_id), waiting))
+            })
+        })
+        .await
+        .ok_or_else(|| BuzzError::UnknownBuzzerId(buzzer_id.to_string()))?;
+
+    if waiting {
+        return Ok(());
+    }
+    if one_buzz_per_song && spent {
+        sse_events::broadcast_buzz_spent(state, team_id, buzzer_id);
+        return Err(BuzzError::BuzzSpent(buzzer_id.to_string()));
+    }
+
+    state.enqueue_buzz(buzzer_id);
+    info!(buzzer_id, team_id = %team_id, "buzz queued behind the answering team");
+    let phase = state.state_machine_phase().await;
+    sse_events::broadcast_phase_changed(state, &phase).await;
+    Ok(())
+}
+
+/// Hand the turn to the first queued buzzer, as if it had just buzzed: the game pauses on it and
+/// the buzzer patterns are updated.
+pub async fn give_turn_to_next_buzz(state: &SharedState) -> Result<String, ServiceError> {
+    while let Some(buzzer_id) = state.pop_queued_buzz() {
+        match handle_playing_buzz(state, &buzzer_id).await {
+            // The buzzer was unpaired since it buzzed.
+            Err(BuzzError::UnknownBuzzerId(_)) => continue,
+            Err(BuzzError::Service(err)) => return Err(err),
+            Err(other) => return Err(ServiceError::InvalidState(other.to_string())),
+            Ok(()) => return Ok(buzzer_id),
+        }
+    }
+    Err(ServiceError::InvalidState("no buzz is queued".into()))
+}
+
 async fn handle_playing_buzz(state: &SharedState, buzzer_id: &str) -> Result<(), BuzzError> {
-    let team_known = state
+    let one_buzz_per_song = state.config().one_buzz_per_song();
+    let (team_id, color, spent) = state
         .read_current_game(|maybe| {
-            maybe.is_some_and(|game| {
-                game.teams
-                    .iter()
-                    .any(|(_, team)| team.buzzer_id.as_deref() == Some(buzzer_id))
+            maybe.and_then(|game| {
+                let team_id = game.team_id_by_buzzer(buzzer_id)?;
+                let color = game.teams.get(&team_id)?.color.clone();
+                Some((team_id, color, game.buzzed_teams.contains(&team_id)))
             })
         })
-        .await;
+        .await
+        .ok_or_else(|| BuzzError::UnknownBuzzerId(buzzer_id.to_string()))?;
 
-    if !team_known {
-        return Err(BuzzError::UnknownBuzzerId(buzzer_id.to_string()));
+    if one_buzz_per_song && spent {
+        sse_events::broadcast_buzz_spent(state, team_id, buzzer_id);
+        return Err(BuzzError::BuzzSpent(buzzer_id.to_string()));
     }
 
-    run_transition_with_broadcast(
-        state,
-        GameEvent::Pause(PauseKind::Buzz {
-            id: buzzer_id.into(),
-        }),
-        move || async move { Ok(()) },
-    )
-    .await?;
+    let (_, next) = state
+        .run_transition(
+            GameEvent::Pause(PauseKind::Buzz {
+                id: buzzer_id.into(),
+            }),
+            move || async move { Ok(()) },
+        )
+        .await?;
+    let config = state.config();
+    // The cue goes out before the phase snapshot so audio fires with minimal latency.
+    if config.buzz_cue() {
+        sse_events::broadcast_buzz_cue(state, team_id, color);
+    }
+    sse_events::broadcast_phase_changed(state, &next).await;
+    // Freeze the guess time until the GM resumes the song.
+    state.timers().suspend(TimerKind::AutoReveal);
     let patterns_to_send = state
-        .with_current_game(|game| {
+        .with_current_game_mut(|game| {
+            game.song_stats.record_buzz();
+            game.register_buzz(team_id);
+            // Every buzzer of the buzzing team answers, the other teams wait as configured.
             Ok(game
                 .teams
                 .iter()
-                .filter_map(|(team_id, team)| {
-                    if let Some(team_buzzer_id) = team.buzzer_id.as_ref() {
-                        let preset = if team_buzzer_id == buzzer_id {
-                            BuzzerPatternPreset::Answering(team.color.clone())
-                        } else {
-                            BuzzerPatternPreset::Waiting
-                        };
-                        Some((team_buzzer_id.clone(), preset))
-                    } else {
-                        warn!(team_id = %team_id, "cannot send pattern: team has no paired buzzer");
-                        None
+                .flat_map(|(id, team)| {
+                    if team.buzzer_ids.is_empty() {
+                        warn!(team_id = %id, "cannot send pattern: team has no paired buzzer");
                     }
+                    let preset = if *id == team_id {
+                        BuzzerPatternPreset::Answering(team.color.clone())
+                    } else {
+                        config.non_answering_preset(team.color.clone())
+                    };
+                    team.buzzer_ids
+                        .iter()
+                        .map(move |buzzer_id| (buzzer_id.clone(), preset.clone()))
                 })
                 .collect::<Vec<_>>())
         })
@@ -475,11 +849,521 @@ async fn handle_playing_buzz(state: &SharedState, buzzer_id: &str) -> Result<(),
     patterns_to_send
         .into_iter()
         .for_each(|(buzzer_id, preset)| send_pattern_to_buzzer(state, &buzzer_id, preset));
+    admin_service::start_answer_countdown(state, team_id);
     Ok(())
 }
 
+/// Queue a `1009 Message Too Big` close frame when `err` reports a frame or message exceeding
+/// the configured size limit; the receiver stays open after such errors otherwise.
+fn close_if_oversized(err: axum::Error, tx: &mpsc::UnboundedSender<Message>) {
+    let oversized = err
+        .into_inner()
+        .downcast::<tungstenite::Error>()
+        .is_ok_and(|err| matches!(*err, tungstenite::Error::Capacity(_)));
+    if oversized {
+        let _ = tx.send(Message::Close(Some(CloseFrame {
+            code: close_code::SIZE,
+            reason: "message too big".into(),
+        })));
+    }
+}
+
 /// Ensure the writer task winds down before we return from the socket handler.
 async fn finalize(writer_task: JoinHandle<()>, outbound_tx: mpsc::UnboundedSender<Message>) {
     drop(outbound_tx);
     let _ = writer_task.await;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::AppConfig,
+        dao::game_store::testing::RecordingStore,
+        state::{
+            AppState,
+            game::{GameSession, Playlist},
+            state_machine::PairingSession,
+        },
+    };
+    use indexmap::IndexMap;
+    use std::sync::Arc;
+
+    const BUZZER: &str = "deadbeef0001";
+
+    /// State pairing the second of two teams, the first one holding [`BUZZER`].
+    async fn pairing_second_team() -> (SharedState, Uuid, Uuid) {
+        let state = AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let config = AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "pairing".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (paired, _) = game.add_team(
+            Uuid::new_v4(),
+            &config,
+            None,
+            Some(BUZZER.into()),
+            None,
+            None,
+        );
+        let (pairing, _) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        let snapshot = game.teams.clone();
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [
+            GameEvent::StartGame,
+            GameEvent::PairingStarted(PairingSession {
+                pairing_team_id: pairing,
+                snapshot,
+            }),
+        ] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        (state, paired, pairing)
+    }
+
+    async fn buzzer_of(state: &SharedState, team_id: Uuid) -> Option<String> {
+        state
+            .read_current_game(|game| game.unwrap().teams[&team_id].buzzer_ids.first().cloned())
+            .await
+    }
+
+    #[tokio::test]
+    async fn unconfirmed_reassignment_is_rejected() {
+        let (state, paired, pairing) = pairing_second_team().await;
+        let mut events = state.admin_sse().subscribe();
+        let (tx, _rx) = mpsc::unbounded_channel();
+
+        let err = assign_pairing_buzzer(&state, BUZZER, &tx, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BuzzError::PairingConflict { assigned_team_id, .. } if assigned_team_id == paired
+        ));
+        assert_eq!(
+            events.recv().await.unwrap().event.as_deref(),
+            Some("pairing.conflict")
+        );
+        assert_eq!(buzzer_of(&state, paired).await.as_deref(), Some(BUZZER));
+        assert_eq!(buzzer_of(&state, pairing).await, None);
+    }
+
+    #[tokio::test]
+    async fn forced_reassignment_moves_the_buzzer() {
+        let (state, paired, pairing) = pairing_second_team().await;
+        let (tx, _rx) = mpsc::unbounded_channel();
+
+        assign_pairing_buzzer(&state, BUZZER, &tx, true)
+            .await
+            .unwrap();
+        assert_eq!(buzzer_of(&state, paired).await, None);
+        assert_eq!(buzzer_of(&state, pairing).await.as_deref(), Some(BUZZER));
+        assert_eq!(
+            state.pairing_session().await.unwrap().pairing_team_id,
+            paired
+        );
+    }
+
+    #[test]
+    fn low_battery_is_reported_once_per_crossing() {
+        let state = AppState::new();
+        let mut events = state.admin_sse().subscribe();
+        let mut reports = Vec::new();
+        let mut warnings = Vec::new();
+        for level in [80, 15, 10, 12, 40, 5] {
+            report_battery_level(&state, BUZZER, level, Some(20));
+            while let Ok(event) = events.try_recv() {
+                match event.event.as_deref() {
+                    Some("buzzer.battery") => reports.push(level),
+                    Some("buzzer.low_battery") => warnings.push(level),
+                    other => panic!("unexpected event {other:?}"),
+                }
+            }
+        }
+        assert_eq!(reports, [80, 15, 10, 12, 40, 5]);
+        assert_eq!(warnings, [15, 5]);
+    }
+
+    #[tokio::test]
+    async fn auto_created_team_records_the_admin_session() {
+        let state = AppState::new();
+        state
+            .set_game_store(Arc::new(RecordingStore::default()))
+            .await;
+        let game = GameSession::new(
+            Uuid::new_v4(),
+            "ready".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        state
+            .run_transition(GameEvent::StartGame, || async { Ok(()) })
+            .await
+            .unwrap();
+        crate::services::sse_service::subscribe_admin(&state)
+            .await
+            .unwrap();
+        let session = state.admin_session();
+        assert!(session.is_some());
+        let (tx, _rx) = mpsc::unbounded_channel();
+
+        handle_prep_ready_buzz(&state, BUZZER, &tx).await.unwrap();
+
+        let created = state
+            .read_current_game(|game| {
+                game.unwrap()
+                    .teams
+                    .values()
+                    .map(|team| team.created_in_session)
+                    .collect::<Vec<_>>()
+            })
+            .await;
+        assert_eq!(created, [session]);
+    }
+
+    #[tokio::test]
+    async fn welcome_precedes_the_initial_pattern() {
+        let state = AppState::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        greet_buzzer(&state, BUZZER, &tx).await.unwrap();
+
+        let mut next = || match rx.try_recv().unwrap() {
+            Message::Text(text) => serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+            other => panic!("unexpected frame {other:?}"),
+        };
+        assert_eq!(next(), serde_json::json!({ "welcome": { "id": BUZZER } }));
+        assert!(next().get("pattern").is_some());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn identified_buzzer_gets_its_previous_pattern_back() {
+        let state = AppState::new();
+        assert!(matches!(
+            identify_buzzer(&state, BUZZER, Duration::from_millis(20)),
+            Err(ServiceError::NotFound(_))
+        ));
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        state.connect_buzzer(BuzzerConnection {
+            id: BUZZER.into(),
+            tx,
+        });
+        state
+            .buzzer_last_patterns()
+            .insert(BUZZER, BuzzerPatternPreset::Waiting);
+
+        identify_buzzer(&state, BUZZER, Duration::from_millis(20)).unwrap();
+        assert!(matches!(
+            state.buzzer_last_patterns().get(BUZZER),
+            Some(BuzzerPatternPreset::Identify)
+        ));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(matches!(
+            state.buzzer_last_patterns().get(BUZZER),
+            Some(BuzzerPatternPreset::Waiting)
+        ));
+        let mut patterns = 0;
+        while rx.try_recv().is_ok() {
+            patterns += 1;
+        }
+        assert_eq!(patterns, 2);
+    }
+
+    #[tokio::test]
+    async fn reconnecting_during_pairing_resumes_it() {
+        let (state, _, pairing) = pairing_second_team().await;
+        let newcomer = "deadbeef0002";
+        state
+            .buzzer_last_patterns()
+            .insert(newcomer, BuzzerPatternPreset::Waiting);
+        let mut admin_events = state.admin_sse().subscribe();
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        greet_buzzer(&state, newcomer, &tx).await.unwrap();
+        greet_buzzer(&state, BUZZER, &tx).await.unwrap();
+
+        let pattern = |id: &str| state.buzzer_last_patterns().get(id).unwrap();
+        assert!(matches!(
+            pattern(newcomer),
+            BuzzerPatternPreset::WaitingForPairing
+        ));
+        assert!(matches!(pattern(BUZZER), BuzzerPatternPreset::Standby(_)));
+        for _ in 0..2 {
+            let event = admin_events.try_recv().unwrap();
+            assert_eq!(event.event.as_deref(), Some("pairing.waiting"));
+            assert!(event.data.contains(&pairing.to_string()));
+        }
+
+        handle_prep_pairing_buzz(&state, newcomer, &tx)
+            .await
+            .unwrap();
+        assert_eq!(buzzer_of(&state, pairing).await.as_deref(), Some(newcomer));
+        assert!(matches!(pattern(newcomer), BuzzerPatternPreset::Standby(_)));
+    }
+
+    #[tokio::test]
+    async fn any_team_buzzer_buzzes_and_all_of_them_answer() {
+        let state = AppState::new();
+        let config = AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "playing".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (buzzing, _) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        game.teams[&buzzing].buzzer_ids = vec![BUZZER.into(), "deadbeef0002".into()];
+        game.add_team(
+            Uuid::new_v4(),
+            &config,
+            None,
+            Some("deadbeef0003".into()),
+            None,
+            None,
+        );
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        handle_playing_buzz(&state, "deadbeef0002").await.unwrap();
+
+        let pattern = |id: &str| state.buzzer_last_patterns().get(id).unwrap().clone();
+        assert!(matches!(pattern(BUZZER), BuzzerPatternPreset::Answering(_)));
+        assert!(matches!(
+            pattern("deadbeef0002"),
+            BuzzerPatternPreset::Answering(_)
+        ));
+        assert!(matches!(
+            pattern("deadbeef0003"),
+            BuzzerPatternPreset::Waiting
+        ));
+        assert!(
+            state
+                .read_current_game(|game| game.unwrap().buzzed_teams.contains(&buzzing))
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn accepted_buzzes_send_a_cue_before_the_phase_change() {
+        let config = AppConfig::from_json(r#"{"one_buzz_per_song": true}"#);
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "playing".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (buzzing, _) = game.add_team(
+            Uuid::new_v4(),
+            &config,
+            None,
+            Some(BUZZER.into()),
+            None,
+            None,
+        );
+        let state = AppState::with_config(config);
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        let mut events = state.public_sse().subscribe();
+
+        handle_playing_buzz(&state, BUZZER).await.unwrap();
+
+        let cue = events.try_recv().unwrap();
+        assert_eq!(cue.event.as_deref(), Some("buzz.cue"));
+        assert!(cue.data.contains(&buzzing.to_string()));
+        assert_eq!(
+            events.try_recv().unwrap().event.as_deref(),
+            Some("phase_changed")
+        );
+
+        state
+            .run_transition(GameEvent::ContinuePlaying, || async { Ok(()) })
+            .await
+            .unwrap();
+        while events.try_recv().is_ok() {}
+        assert!(matches!(
+            handle_playing_buzz(&state, BUZZER).await,
+            Err(BuzzError::BuzzSpent(_))
+        ));
+        while let Ok(event) = events.try_recv() {
+            assert_ne!(event.event.as_deref(), Some("buzz.cue"));
+        }
+    }
+
+    #[tokio::test]
+    async fn buzzes_while_answering_queue_up_for_the_next_turn() {
+        let config = AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "playing".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        for buzzer in [BUZZER, "deadbeef0002", "deadbeef0003"] {
+            game.add_team(
+                Uuid::new_v4(),
+                &config,
+                None,
+                Some(buzzer.into()),
+                None,
+                None,
+            );
+        }
+        let state = AppState::with_config(config);
+        state
+            .with_current_game_slot_mut(|slot| {
+                slot.replace(game);
+            })
+            .await;
+        for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+            state
+                .run_transition(event, || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        for buzzer in [
+            BUZZER,
+            "deadbeef0003",
+            "deadbeef0002",
+            "deadbeef0003",
+            BUZZER,
+        ] {
+            handle_buzz(&state, buzzer, &tx).await.unwrap();
+        }
+        let phase = state.state_machine_phase().await;
+        let snapshot = state.game_phase_snapshot(&phase).await;
+        assert_eq!(snapshot.paused_buzzer.as_deref(), Some(BUZZER));
+        assert_eq!(
+            snapshot.buzz_queue,
+            Some(vec!["deadbeef0003".to_string(), "deadbeef0002".to_string()])
+        );
+
+        assert_eq!(
+            give_turn_to_next_buzz(&state).await.unwrap(),
+            "deadbeef0003"
+        );
+        assert_eq!(
+            state.state_machine_phase().await,
+            GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz {
+                id: "deadbeef0003".into()
+            }))
+        );
+        let pattern = |id: &str| state.buzzer_last_patterns().get(id).unwrap();
+        assert!(matches!(
+            pattern("deadbeef0003"),
+            BuzzerPatternPreset::Answering(_)
+        ));
+        assert!(matches!(pattern(BUZZER), BuzzerPatternPreset::Waiting));
+        assert_eq!(state.buzz_queue(), vec!["deadbeef0002".to_string()]);
+
+        state
+            .run_transition(GameEvent::ContinuePlaying, || async { Ok(()) })
+            .await
+            .unwrap();
+        assert!(state.buzz_queue().is_empty());
+        assert!(matches!(
+            give_turn_to_next_buzz(&state).await,
+            Err(ServiceError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn non_answering_teams_get_the_configured_pattern() {
+        for (setting, standby) in [("waiting", false), ("standby", true)] {
+            let config =
+                AppConfig::from_json(&format!(r#"{{"non_answering_pattern": "{setting}"}}"#));
+            let mut game = GameSession::new(
+                Uuid::new_v4(),
+                "playing".into(),
+                IndexMap::new(),
+                Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+                None,
+            );
+            game.add_team(
+                Uuid::new_v4(),
+                &config,
+                None,
+                Some(BUZZER.into()),
+                None,
+                None,
+            );
+            game.add_team(
+                Uuid::new_v4(),
+                &config,
+                None,
+                Some("deadbeef0002".into()),
+                None,
+                None,
+            );
+            let state = AppState::with_config(config);
+            state
+                .with_current_game_slot_mut(|slot| {
+                    slot.replace(game);
+                })
+                .await;
+            for event in [GameEvent::StartGame, GameEvent::GameConfigured] {
+                state
+                    .run_transition(event, || async { Ok(()) })
+                    .await
+                    .unwrap();
+            }
+
+            handle_playing_buzz(&state, BUZZER).await.unwrap();
+
+            let pattern = state
+                .buzzer_last_patterns()
+                .get("deadbeef0002")
+                .unwrap()
+                .clone();
+            if standby {
+                assert!(matches!(pattern, BuzzerPatternPreset::Standby(_)));
+            } else {
+                assert!(matches!(pattern, BuzzerPatternPreset::Waiting));
+            }
+        }
+    }
+}
@@ -1,18 +1,23 @@
 use indexmap::IndexMap;
-use rand::{rng, seq::SliceRandom};
-use std::time::SystemTime;
+use rand::{Rng, SeedableRng, rng, rngs::StdRng, seq::SliceRandom};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant, SystemTime},
+};
 use uuid::Uuid;
 
 use crate::{
     dao::models::{
-        GameEntity, PlaylistEntity, PointFieldEntity, SongEntity, TeamColorEntity, TeamEntity,
-        TeamSummaryEntity,
+        FinishReasonEntity, GameEntity, PlaylistEntity, PointFieldEntity, SongAnalyticsEntity,
+        SongEntity, TeamColorEntity, TeamEntity, TeamSummaryEntity,
     },
-    dto::game::TeamBriefSummary,
+    dto::{admin::AnswerValidation, game::TeamBriefSummary},
+    state::state_machine::FinishReason,
 };
 
 /// Runtime representation of a playlist with its songs keyed by identifier.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
+#[cfg_attr(not(test), derive(Clone))]
 pub struct Playlist {
     /// Stable identifier for the playlist.
     pub id: Uuid,
@@ -22,6 +27,25 @@ pub struct Playlist {
     pub songs: IndexMap<u32, Song>,
 }
 
+#[cfg(test)]
+thread_local! {
+    /// Number of playlist deep copies made by the current test thread.
+    static PLAYLIST_CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Counts deep copies so tests can check that hot paths leave the playlist alone.
+#[cfg(test)]
+impl Clone for Playlist {
+    fn clone(&self) -> Self {
+        PLAYLIST_CLONES.with(|count| count.set(count.get() + 1));
+        Self {
+            id: self.id,
+            name: self.name.clone(),
+            songs: self.songs.clone(),
+        }
+    }
+}
+
 /// Metadata for a song of a playlist.
 #[derive(Debug, Clone)]
 pub struct Song {
@@ -35,6 +59,17 @@ pub struct Song {
     pub point_fields: Vec<PointField>,
     /// Optional extra fields that can yield bonus points.
     pub bonus_fields: Vec<PointField>,
+    /// Categories the song belongs to (e.g. "90s", "Movies").
+    pub tags: Vec<String>,
+}
+
+impl Song {
+    /// Whether the song carries at least one of the given tags (case-insensitive).
+    pub fn has_any_tag(&self, tags: &[String]) -> bool {
+        self.tags
+            .iter()
+            .any(|tag| tags.iter().any(|wanted| wanted.eq_ignore_ascii_case(tag)))
+    }
 }
 
 /// Data for a point field associated to a song of a playlist.
@@ -46,6 +81,8 @@ pub struct PointField {
     pub value: String,
     /// The number of points given if this field is found.
     pub points: u8,
+    /// Whether the field must be found before the game can progress past the song.
+    pub required: bool,
 }
 
 /// HSV color assigned to a team.
@@ -69,11 +106,74 @@ impl PartialEq for TeamColor {
 
 impl Eq for TeamColor {}
 
+impl TeamColor {
+    /// Euclidean distance between two colors in the perceptual OKLab space.
+    pub fn oklab_distance(&self, other: &TeamColor) -> f32 {
+        let [l1, a1, b1] = self.to_oklab();
+        let [l2, a2, b2] = other.to_oklab();
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Convert the HSV color into OKLab coordinates (`[L, a, b]`).
+    fn to_oklab(&self) -> [f32; 3] {
+        let [r, g, b] = self.to_srgb().map(srgb_to_linear);
+
+        let l = (0.412_221_46 * r + 0.536_332_54 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        [
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        ]
+    }
+
+    /// Convert the HSV color into gamma-encoded sRGB components in `0.0..=1.0`.
+    fn to_srgb(&self) -> [f32; 3] {
+        let h = self.h.rem_euclid(360.0) / 60.0;
+        let s = self.s.clamp(0.0, 1.0);
+        let v = self.v.clamp(0.0, 1.0);
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = v - c;
+        [r + m, g + m, b + m]
+    }
+}
+
+/// Sum of the points of `fields`.
+fn total_points(fields: &[PointField]) -> i32 {
+    fields.iter().map(|field| i32::from(field.points)).sum()
+}
+
+/// Current score of every team keyed by team identifier.
+fn scores_of(teams: &IndexMap<Uuid, Team>) -> HashMap<Uuid, i32> {
+    teams.iter().map(|(id, team)| (*id, team.score)).collect()
+}
+
+/// Remove the sRGB transfer function from a gamma-encoded component.
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
 /// Team info tracked during a game session.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Team {
-    /// Unique buzzer identifier (12 lowercase hexadecimal characters).
-    pub buzzer_id: Option<String>,
+    /// Buzzers paired with the team (12 lowercase hexadecimal characters each); any of them
+    /// buzzes for the team and all of them display its patterns.
+    pub buzzer_ids: Vec<String>,
     /// Display name chosen for the team.
     pub name: String,
     /// Current score for the team.
@@ -82,6 +182,15 @@ pub struct Team {
     pub color: TeamColor,
     /// Timestamp of the last update to this team.
     pub updated_at: SystemTime,
+    /// Admin session active when a buzzer created this team automatically (runtime only).
+    pub created_in_session: Option<Uuid>,
+}
+
+impl Team {
+    /// Whether `buzzer_id` is one of the buzzers paired with the team.
+    pub fn has_buzzer(&self, buzzer_id: &str) -> bool {
+        self.buzzer_ids.iter().any(|id| id == buzzer_id)
+    }
 }
 
 /// Aggregated state for an in-progress or persisted game session.
@@ -109,26 +218,113 @@ pub struct GameSession {
     pub found_point_fields: Vec<String>,
     /// Bonus field names (key) found for the current song.
     pub found_bonus_fields: Vec<String>,
+    /// Point field names (key) of the current song whose points were already awarded.
+    pub scored_point_fields: HashSet<String>,
+    /// Bonus field names (key) of the current song whose points were already awarded.
+    pub scored_bonus_fields: HashSet<String>,
+    /// Timing and outcome statistics gathered while playing the current song.
+    pub song_stats: SongRoundStats,
+    /// Whether revealing a song or moving on to the next one is blocked until its required
+    /// fields are found. Skipping a song bypasses the gate.
+    pub enforce_required_fields: bool,
+    /// Team scores captured when the current song became current, used for round deltas.
+    pub round_start_scores: HashMap<Uuid, i32>,
+    /// Teams that already buzzed during the current song.
+    pub buzzed_teams: HashSet<Uuid>,
+    /// Whether the game reached its final scoreboard; finished games reload into `ShowScores`.
+    pub finished: bool,
+    /// Why the game reached its final scoreboard, once it did.
+    pub finish_reason: Option<FinishReason>,
+    /// Seed of the shuffle applied to the playlist order in this session (runtime only).
+    pub shuffle_seed: Option<u64>,
+}
+
+/// Draw a fresh shuffle seed, kept below 2^53 so JavaScript clients can echo it back exactly.
+pub fn random_shuffle_seed() -> u64 {
+    rng().random_range(0..1 << 53)
+}
+
+/// Timing and outcome statistics gathered while a song is being played, kept in memory until the
+/// song is resolved and folded into the playlist analytics.
+#[derive(Debug, Clone, Default)]
+pub struct SongRoundStats {
+    /// Whether the song has entered the playing phase at least once.
+    started: bool,
+    /// Instant at which the song last (re-)entered the playing phase.
+    playing_since: Option<Instant>,
+    /// Playing time accumulated before the latest pause.
+    played: Duration,
+    /// Playing time (milliseconds) elapsed at each buzz.
+    buzz_times_ms: Vec<u64>,
+    /// Number of answers validated as correct.
+    correct_count: u32,
+    /// Number of answers validated as wrong.
+    wrong_count: u32,
+}
+
+impl SongRoundStats {
+    /// Mark the song as playing, starting (or resuming) the playing clock.
+    pub fn start_playing(&mut self) {
+        self.started = true;
+        self.playing_since.get_or_insert_with(Instant::now);
+    }
+
+    /// Stop the playing clock, accumulating the time spent playing so far.
+    pub fn stop_playing(&mut self) {
+        if let Some(since) = self.playing_since.take() {
+            self.played += since.elapsed();
+        }
+    }
+
+    /// Record a buzz, capturing the playing time elapsed since the song started.
+    pub fn record_buzz(&mut self) {
+        self.stop_playing();
+        self.buzz_times_ms
+            .push(u64::try_from(self.played.as_millis()).unwrap_or(u64::MAX));
+    }
+
+    /// Record the outcome of an answer validation.
+    pub fn record_validation(&mut self, valid: &AnswerValidation) {
+        match valid {
+            AnswerValidation::Correct => self.correct_count += 1,
+            AnswerValidation::Wrong => self.wrong_count += 1,
+            AnswerValidation::Incomplete => {}
+        }
+    }
+
+    /// Convert the gathered statistics into analytics counters for `song_id`, or `None` when the
+    /// song was never played.
+    pub fn into_analytics(self, song_id: u32) -> Option<SongAnalyticsEntity> {
+        if !self.started {
+            return None;
+        }
+        Some(SongAnalyticsEntity {
+            song_id,
+            plays: 1,
+            buzzes: self.buzz_times_ms.len() as u32,
+            total_time_to_buzz_ms: self.buzz_times_ms.iter().sum(),
+            correct_count: self.correct_count,
+            wrong_count: self.wrong_count,
+        })
+    }
 }
 
 impl GameSession {
     /// Build a new in-memory session with the provided metadata.
     pub fn new(
+        id: Uuid,
         name: String,
         teams: IndexMap<Uuid, Team>,
         playlist: Playlist,
-        shuffle_playlist: bool,
+        shuffle_seed: Option<u64>,
     ) -> Self {
         let timestamp = SystemTime::now();
 
-        let mut playlist_song_order: Vec<u32> = playlist.songs.keys().cloned().collect();
-        if shuffle_playlist {
-            let mut rng = rng();
-            playlist_song_order.shuffle(&mut rng);
-        }
+        let playlist_song_order: Vec<u32> = playlist.songs.keys().cloned().collect();
 
-        Self {
-            id: Uuid::new_v4(),
+        let round_start_scores = scores_of(&teams);
+        let mut game = Self {
+            id,
             name,
             created_at: timestamp,
             updated_at: timestamp,
@@ -139,7 +335,28 @@ impl GameSession {
             current_song_found: false,
             found_point_fields: Vec::new(),
             found_bonus_fields: Vec::new(),
+            scored_point_fields: HashSet::new(),
+            scored_bonus_fields: HashSet::new(),
+            song_stats: SongRoundStats::default(),
+            enforce_required_fields: false,
+            round_start_scores,
+            buzzed_teams: HashSet::new(),
+            finished: false,
+            finish_reason: None,
+            shuffle_seed: None,
+        };
+        if let Some(seed) = shuffle_seed {
+            game.shuffle_songs(seed);
         }
+        game
+    }
+
+    /// Shuffle the playlist order with a generator seeded from `seed`, so the same seed always
+    /// yields the same order for the same playlist.
+    pub fn shuffle_songs(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.playlist_song_order.shuffle(&mut rng);
+        self.shuffle_seed = Some(seed);
     }
 
     /// Return the song at the requested playlist index together with its identifier.
@@ -152,22 +369,165 @@ impl GameSession {
         })
     }
 
+    /// Return the identifier of the team currently paired with the given buzzer.
+    pub fn team_id_by_buzzer(&self, buzzer_id: &str) -> Option<Uuid> {
+        self.teams
+            .iter()
+            .find(|(_, team)| team.has_buzzer(buzzer_id))
+            .map(|(id, _)| *id)
+    }
+
+    /// Sum the points of the fields found so far for the current song that were not awarded
+    /// yet, leaving out the bonus fields unless `with_bonus`.
+    pub fn unscored_points(&self, with_bonus: bool) -> i32 {
+        let (point_fields, bonus_fields) = self.unscored_fields(with_bonus);
+        total_points(&point_fields) + total_points(&bonus_fields)
+    }
+
+    /// Mark the fields found so far for the current song that were not awarded yet as scored,
+    /// leaving out the bonus fields unless `with_bonus`, and return the sum of their points.
+    pub fn claim_unscored_points(&mut self, with_bonus: bool) -> i32 {
+        let (point_fields, bonus_fields) = self.unscored_fields(with_bonus);
+        let points = total_points(&point_fields) + total_points(&bonus_fields);
+        self.scored_point_fields
+            .extend(point_fields.into_iter().map(|field| field.key));
+        self.scored_bonus_fields
+            .extend(bonus_fields.into_iter().map(|field| field.key));
+        points
+    }
+
+    fn unscored_fields(&self, with_bonus: bool) -> (Vec<PointField>, Vec<PointField>) {
+        let Some((_, song)) = self.current_song_index.and_then(|idx| self.get_song(idx)) else {
+            return (Vec::new(), Vec::new());
+        };
+        let unscored = |fields: Vec<PointField>, found: &[String], scored: &HashSet<String>| {
+            fields
+                .into_iter()
+                .filter(|field| found.contains(&field.key) && !scored.contains(&field.key))
+                .collect::<Vec<_>>()
+        };
+        let bonus_fields = if with_bonus {
+            unscored(
+                song.bonus_fields,
+                &self.found_bonus_fields,
+                &self.scored_bonus_fields,
+            )
+        } else {
+            Vec::new()
+        };
+        (
+            unscored(
+                song.point_fields,
+                &self.found_point_fields,
+                &self.scored_point_fields,
+            ),
+            bonus_fields,
+        )
+    }
+
+    /// Make the song at `index` current, resetting the per-song state (found fields when the song
+    /// changes, statistics, score baseline and buzzed teams).
+    pub fn enter_song(&mut self, index: Option<usize>) {
+        if self.current_song_index != index {
+            self.found_point_fields.clear();
+            self.found_bonus_fields.clear();
+            self.scored_point_fields.clear();
+            self.scored_bonus_fields.clear();
+        }
+        self.current_song_index = index;
+        self.current_song_found = false;
+        self.updated_at = SystemTime::now();
+        self.song_stats = SongRoundStats::default();
+        self.snapshot_round_scores();
+        self.buzzed_teams.clear();
+    }
+
+    /// Give the game and its teams fresh identifiers, so the session is stored as a new game
+    /// instead of overwriting the one it was copied from.
+    pub fn renew_ids(&mut self, mut next_id: impl FnMut() -> Uuid) {
+        self.id = next_id();
+        self.teams = std::mem::take(&mut self.teams)
+            .into_values()
+            .map(|team| (next_id(), team))
+            .collect();
+        let now = SystemTime::now();
+        self.created_at = now;
+        self.updated_at = now;
+    }
+
+    /// Rewind the game to its first song with every score reset, for another run of the playlist.
+    pub fn reset_progress(&mut self) {
+        for team in self.teams.values_mut() {
+            team.score = 0;
+        }
+        self.found_point_fields.clear();
+        self.found_bonus_fields.clear();
+        self.scored_point_fields.clear();
+        self.scored_bonus_fields.clear();
+        self.finished = false;
+        self.finish_reason = None;
+        self.enter_song(Some(0));
+    }
+
+    /// Record that `team_id` buzzed during the current song.
+    ///
+    /// Returns `false` when the team had already buzzed during this song.
+    pub fn register_buzz(&mut self, team_id: Uuid) -> bool {
+        self.buzzed_teams.insert(team_id)
+    }
+
+    /// Capture the current team scores as the baseline for the song that just became current.
+    pub fn snapshot_round_scores(&mut self) {
+        self.round_start_scores = scores_of(&self.teams);
+    }
+
+    /// Score change of each team since the current song became current, in team order.
+    ///
+    /// Teams created after the baseline was captured are measured from zero.
+    pub fn round_deltas(&self) -> Vec<(Uuid, i32)> {
+        self.teams
+            .iter()
+            .map(|(id, team)| {
+                let baseline = self.round_start_scores.get(id).copied().unwrap_or(0);
+                (*id, team.score - baseline)
+            })
+            .collect()
+    }
+
+    /// Keys of the current song's required point and bonus fields that have not been found yet.
+    pub fn outstanding_required_fields(&self) -> Vec<String> {
+        let Some((_, song)) = self.current_song_index.and_then(|idx| self.get_song(idx)) else {
+            return Vec::new();
+        };
+        let outstanding = |fields: Vec<PointField>, found: &[String]| {
+            fields
+                .into_iter()
+                .filter(|field| field.required && !found.contains(&field.key))
+                .map(|field| field.key)
+                .collect::<Vec<_>>()
+        };
+        let mut keys = outstanding(song.point_fields, &self.found_point_fields);
+        keys.extend(outstanding(song.bonus_fields, &self.found_bonus_fields));
+        keys
+    }
+
     /// Insert a new team into the session, generating default values when they are omitted.
     ///
     /// The color is selected from the configured colors set when not specified and the team name
     /// falls back to `Team X` (with X starting at 1) to keep the UI human-friendly.
     pub fn add_team(
         &mut self,
+        team_id: Uuid,
         config: &crate::config::AppConfig,
         name: Option<String>,
         buzzer_id: Option<String>,
         score: Option<i32>,
         color: Option<TeamColor>,
     ) -> (Uuid, Team) {
-        let team_id = Uuid::new_v4();
-        // Reuse provided color when present, otherwise pick the next free colors set slot.
+        // Reuse provided color when present, otherwise follow the configured strategy.
         let color = color.unwrap_or_else(|| {
-            config.first_unused_color(
+            config.auto_color(
+                self.teams.len(),
                 &self
                     .teams
                     .values()
@@ -176,11 +536,18 @@ impl GameSession {
             )
         });
         let team = Team {
-            buzzer_id,
-            name: name.unwrap_or_else(|| format!("Team {}", self.teams.len() + 1)),
+            buzzer_ids: buzzer_id.into_iter().collect(),
+            name: name.unwrap_or_else(|| {
+                config.default_team_name(self.teams.len(), |candidate| {
+                    self.teams
+                        .values()
+                        .any(|existing| existing.name == candidate)
+                })
+            }),
             score: score.unwrap_or(0),
             color,
             updated_at: SystemTime::now(),
+            created_in_session: None,
         };
         self.teams.insert(team_id, team.clone());
         (team_id, team)
@@ -188,14 +555,9 @@ impl GameSession {
 }
 
 impl Playlist {
-    /// Build a new in-memory playlist with the provided metadata, allocating a
-    /// fresh unique identifier for runtime usage.
-    pub fn new(name: String, songs: IndexMap<u32, Song>) -> Self {
-        Self {
-            id: Uuid::new_v4(),
-            name,
-            songs,
-        }
+    /// Build a new in-memory playlist with the provided identifier and metadata.
+    pub fn new(id: Uuid, name: String, songs: IndexMap<u32, Song>) -> Self {
+        Self { id, name, songs }
     }
 }
 
@@ -205,6 +567,7 @@ impl From<PointFieldEntity> for PointField {
             key: value.key,
             value: value.value,
             points: value.points,
+            required: value.required,
         }
     }
 }
@@ -215,6 +578,7 @@ impl From<PointField> for PointFieldEntity {
             key: value.key,
             value: value.value,
             points: value.points,
+            required: value.required,
         }
     }
 }
@@ -227,6 +591,7 @@ impl From<SongEntity> for Song {
             url: value.url,
             point_fields: value.point_fields.into_iter().map(Into::into).collect(),
             bonus_fields: value.bonus_fields.into_iter().map(Into::into).collect(),
+            tags: value.tags,
         }
     }
 }
@@ -239,31 +604,53 @@ impl From<Song> for SongEntity {
             url: value.url,
             point_fields: value.point_fields.into_iter().map(Into::into).collect(),
             bonus_fields: value.bonus_fields.into_iter().map(Into::into).collect(),
+            tags: value.tags,
         }
     }
 }
 
 impl From<PlaylistEntity> for Playlist {
+    /// Songs are iterated in the stored default order; ids it misses keep their insertion order,
+    /// after the ordered ones.
     fn from(value: PlaylistEntity) -> Self {
+        let mut songs: IndexMap<u32, Song> = value
+            .songs
+            .into_iter()
+            .enumerate()
+            .map(|(id, se)| (id as u32, se.into()))
+            .collect();
+        let mut placed = 0;
+        for song_id in &value.song_order {
+            if let Some(from) = songs.get_index_of(song_id)
+                && from >= placed
+            {
+                songs.move_index(from, placed);
+                placed += 1;
+            }
+        }
         Self {
             id: value.id,
             name: value.name,
-            songs: value
-                .songs
-                .into_iter()
-                .enumerate()
-                .map(|(id, se)| (id as u32, se.into()))
-                .collect(),
+            songs,
         }
     }
 }
 
 impl From<Playlist> for PlaylistEntity {
     fn from(value: Playlist) -> Self {
+        let song_order: Vec<u32> = value.songs.keys().copied().collect();
+        let mut songs: Vec<_> = value.songs.into_iter().collect();
+        songs.sort_by_key(|(id, _)| *id);
         Self {
             id: value.id,
             name: value.name,
-            songs: value.songs.into_values().map(Into::into).collect(),
+            songs: songs.into_iter().map(|(_, song)| song.into()).collect(),
+            // The insertion order needs no storage.
+            song_order: if song_order.is_sorted() {
+                Vec::new()
+            } else {
+                song_order
+            },
         }
     }
 }
@@ -272,11 +659,12 @@ impl From<TeamEntity> for (Uuid, Team) {
     fn from(value: TeamEntity) -> Self {
         let id = value.id;
         let team = Team {
-            buzzer_id: None,
+            buzzer_ids: value.buzzer_ids,
             name: value.name,
             score: value.score,
             color: value.color.into(),
             updated_at: value.updated_at,
+            created_in_session: None,
         };
         (id, team)
     }
@@ -287,6 +675,7 @@ impl From<(Uuid, Team)> for TeamEntity {
         Self {
             id,
             name: team.name,
+            buzzer_ids: team.buzzer_ids,
             score: team.score,
             color: team.color.into(),
             updated_at: team.updated_at,
@@ -323,20 +712,84 @@ impl From<TeamColor> for TeamColorEntity {
     }
 }
 
+impl From<FinishReasonEntity> for FinishReason {
+    fn from(value: FinishReasonEntity) -> Self {
+        match value {
+            FinishReasonEntity::PlaylistCompleted => Self::PlaylistCompleted,
+            FinishReasonEntity::ManualStop => Self::ManualStop,
+        }
+    }
+}
+
+impl From<FinishReason> for FinishReasonEntity {
+    fn from(value: FinishReason) -> Self {
+        match value {
+            FinishReason::PlaylistCompleted => Self::PlaylistCompleted,
+            FinishReason::ManualStop => Self::ManualStop,
+        }
+    }
+}
+
 impl From<(GameEntity, PlaylistEntity)> for GameSession {
     fn from((game, playlist): (GameEntity, PlaylistEntity)) -> Self {
+        let teams: IndexMap<Uuid, Team> = game.teams.into_iter().map(Into::into).collect();
         Self {
+            round_start_scores: scores_of(&teams),
+            buzzed_teams: HashSet::new(),
             id: game.id,
             name: game.name,
             created_at: game.created_at,
             updated_at: game.updated_at,
-            teams: game.teams.into_iter().map(Into::into).collect(),
+            teams,
             playlist: playlist.into(),
             playlist_song_order: game.playlist_song_order,
             current_song_index: game.current_song_index,
             current_song_found: game.current_song_found,
             found_point_fields: Vec::new(),
             found_bonus_fields: Vec::new(),
+            scored_point_fields: HashSet::new(),
+            scored_bonus_fields: HashSet::new(),
+            song_stats: SongRoundStats::default(),
+            enforce_required_fields: game.enforce_required_fields,
+            finished: game.finished,
+            finish_reason: game.finish_reason.map(Into::into),
+            shuffle_seed: None,
+        }
+    }
+}
+
+impl GameSession {
+    /// Build the persisted game document from a borrowed session.
+    ///
+    /// Only the playlist identifier is stored in the game document, so the playlist songs are
+    /// never cloned; this keeps debounced saves cheap for large playlists.
+    pub fn to_entity(&self) -> GameEntity {
+        GameEntity {
+            teams: self
+                .teams
+                .iter()
+                .map(|(id, team)| (*id, team.clone()).into())
+                .collect(),
+            ..self.to_entity_without_teams()
+        }
+    }
+
+    /// Build the persisted game document without its teams, for saves that only touch
+    /// game-level fields (teams are stored as separate documents).
+    pub fn to_entity_without_teams(&self) -> GameEntity {
+        GameEntity {
+            id: self.id,
+            name: self.name.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            teams: Vec::new(),
+            playlist_id: self.playlist.id,
+            playlist_song_order: self.playlist_song_order.clone(),
+            current_song_index: self.current_song_index,
+            current_song_found: self.current_song_found,
+            enforce_required_fields: self.enforce_required_fields,
+            finished: self.finished,
+            finish_reason: self.finish_reason.map(Into::into),
         }
     }
 }
@@ -353,6 +806,238 @@ impl From<GameSession> for GameEntity {
             playlist_song_order: value.playlist_song_order,
             current_song_index: value.current_song_index,
             current_song_found: value.current_song_found,
+            enforce_required_fields: value.enforce_required_fields,
+            finished: value.finished,
+            finish_reason: value.finish_reason.map(Into::into),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dao::models::PlaylistAnalyticsEntity;
+
+    #[test]
+    fn resolving_song_updates_analytics_counters() {
+        let mut stats = SongRoundStats::default();
+        stats.start_playing();
+        stats.record_buzz();
+        stats.record_validation(&AnswerValidation::Wrong);
+        stats.start_playing();
+        stats.record_buzz();
+        stats.record_validation(&AnswerValidation::Correct);
+
+        let mut analytics = PlaylistAnalyticsEntity::new(Uuid::new_v4());
+        analytics.record(stats.clone().into_analytics(3).unwrap());
+        analytics.record(stats.into_analytics(3).unwrap());
+
+        let song = &analytics.songs[0];
+        assert_eq!(analytics.songs.len(), 1);
+        assert_eq!(song.song_id, 3);
+        assert_eq!(song.plays, 2);
+        assert_eq!(song.buzzes, 4);
+        assert_eq!(song.correct_count, 2);
+        assert_eq!(song.wrong_count, 2);
+    }
+
+    #[test]
+    fn unplayed_song_yields_no_analytics() {
+        assert!(SongRoundStats::default().into_analytics(0).is_none());
+    }
+
+    #[test]
+    fn auto_created_teams_get_distinct_names() {
+        let config = crate::config::AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "names".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        game.add_team(
+            Uuid::new_v4(),
+            &config,
+            Some("Team 2".into()),
+            None,
+            None,
+            None,
+        );
+        let (_, first) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        let (_, second) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+
+        assert_eq!(first.name, "Team 3");
+        assert_eq!(second.name, "Team 4");
+    }
+
+    #[test]
+    fn round_deltas_follow_current_song() {
+        let config = crate::config::AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "rounds".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (red, _) = game.add_team(Uuid::new_v4(), &config, None, None, Some(10), None);
+        let (blue, _) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        game.snapshot_round_scores();
+
+        game.teams.get_mut(&red).unwrap().score += 3;
+        assert_eq!(game.round_deltas(), vec![(red, 3), (blue, 0)]);
+
+        game.snapshot_round_scores();
+        assert_eq!(game.round_deltas(), vec![(red, 0), (blue, 0)]);
+        game.teams.get_mut(&blue).unwrap().score -= 1;
+        assert_eq!(game.round_deltas(), vec![(red, 0), (blue, -1)]);
+    }
+
+    #[test]
+    fn each_team_buzzes_once_until_next_song() {
+        let config = crate::config::AppConfig::default();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "buzzes".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let (red, _) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        let (blue, _) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+
+        assert!(game.register_buzz(red));
+        assert!(!game.register_buzz(red));
+        assert!(game.register_buzz(blue));
+
+        game.enter_song(Some(1));
+        assert!(game.buzzed_teams.is_empty());
+        assert!(game.register_buzz(red));
+    }
+
+    #[test]
+    fn persisted_snapshot_borrows_session_without_copying_playlist() {
+        let config = crate::config::AppConfig::default();
+        let songs = (0..1_000)
+            .map(|id| {
+                let song = Song {
+                    starts_at_ms: 0,
+                    guess_duration_ms: 1_000,
+                    url: format!("https://example.com/{id}.mp3"),
+                    point_fields: Vec::new(),
+                    bonus_fields: Vec::new(),
+                    tags: Vec::new(),
+                };
+                (id, song)
+            })
+            .collect();
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "snapshot".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), songs),
+            None,
+        );
+        let (red, _) = game.add_team(Uuid::new_v4(), &config, None, None, Some(4), None);
+        let playlist_clones = || PLAYLIST_CLONES.with(|count| count.get());
+        let before = playlist_clones();
+
+        let without_teams = game.to_entity_without_teams();
+        assert!(without_teams.teams.is_empty());
+        assert_eq!(without_teams.playlist_id, game.playlist.id);
+        assert_eq!(without_teams.playlist_song_order, game.playlist_song_order);
+
+        let full = game.to_entity();
+        assert_eq!(full.teams.len(), 1);
+        assert_eq!(full.teams[0].id, red);
+        assert_eq!(full.teams[0].score, 4);
+        assert_eq!(playlist_clones(), before);
+
+        // Cloning the whole session, as snapshots used to, copies every song.
+        let _ = game.clone();
+        assert_eq!(playlist_clones(), before + 1);
+        assert_eq!(full, GameEntity::from(game));
+    }
+
+    #[test]
+    fn same_shuffle_seed_yields_the_same_order() {
+        let songs: IndexMap<u32, Song> = (0..50)
+            .map(|id| {
+                let song = Song {
+                    starts_at_ms: 0,
+                    guess_duration_ms: 1_000,
+                    url: format!("https://example.com/{id}.mp3"),
+                    point_fields: Vec::new(),
+                    bonus_fields: Vec::new(),
+                    tags: Vec::new(),
+                };
+                (id, song)
+            })
+            .collect();
+        let playlist = Playlist::new(Uuid::new_v4(), "playlist".into(), songs);
+        let shuffled = |seed| {
+            GameSession::new(
+                Uuid::new_v4(),
+                "seeded".into(),
+                IndexMap::new(),
+                playlist.clone(),
+                seed,
+            )
+        };
+
+        let first = shuffled(Some(42));
+        assert_eq!(first.shuffle_seed, Some(42));
+        assert_eq!(
+            first.playlist_song_order,
+            shuffled(Some(42)).playlist_song_order
+        );
+        assert_ne!(
+            first.playlist_song_order,
+            shuffled(Some(43)).playlist_song_order
+        );
+        assert_eq!(
+            shuffled(None).playlist_song_order,
+            (0..50).collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn index_stable_colors_ignore_deletions() {
+        let config = crate::config::AppConfig::from_json(
+            r#"{
+                "color_assignment": "by_index",
+                "colors": [
+                    {"hue": 0.0, "saturation": 1.0, "value": 1.0},
+                    {"hue": 120.0, "saturation": 1.0, "value": 1.0},
+                    {"hue": 240.0, "saturation": 1.0, "value": 1.0}
+                ]
+            }"#,
+        );
+        let palette = |h| TeamColor { h, s: 1.0, v: 1.0 };
+        let mut game = GameSession::new(
+            Uuid::new_v4(),
+            "colors".into(),
+            IndexMap::new(),
+            Playlist::new(Uuid::new_v4(), "playlist".into(), IndexMap::new()),
+            None,
+        );
+        let ids: Vec<Uuid> = (0..3)
+            .map(|_| {
+                game.add_team(Uuid::new_v4(), &config, None, None, None, None)
+                    .0
+            })
+            .collect();
+
+        assert_eq!(game.teams[&ids[1]].color, palette(120.0));
+
+        // The first color is free again, yet the team re-added third keeps the third color.
+        game.teams.shift_remove(&ids[0]);
+        let (_, third) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        assert_eq!(third.color, palette(240.0));
+
+        // A fourth team wraps around the colors set.
+        let (_, fourth) = game.add_team(Uuid::new_v4(), &config, None, None, None, None);
+        assert_eq!(fourth.color, palette(0.0));
+    }
+}
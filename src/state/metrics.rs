@@ -0,0 +1,137 @@
+//! Operational counters exported in the Prometheus text format.
+
+use std::{
+    fmt::Write,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use super::state_machine::GamePhase;
+
+/// Operational counter tracked by [`Metrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counter {
+    /// A buzzer identified itself on `/ws`.
+    BuzzerConnects,
+    /// An identified buzzer connection ended.
+    BuzzerDisconnects,
+    /// A buzz message was handled.
+    Buzzes,
+    /// A game or team document was written to the store.
+    PersistenceWrites,
+    /// A write held back by the persistence cooldown was flushed.
+    DebounceFlushes,
+    /// A game phase transition was applied.
+    Transitions,
+}
+
+impl Counter {
+    const ALL: [Counter; 6] = [
+        Counter::BuzzerConnects,
+        Counter::BuzzerDisconnects,
+        Counter::Buzzes,
+        Counter::PersistenceWrites,
+        Counter::DebounceFlushes,
+        Counter::Transitions,
+    ];
+
+    /// Metric name and help text in the Prometheus exposition.
+    fn describe(self) -> (&'static str, &'static str) {
+        match self {
+            Counter::BuzzerConnects => (
+                "neon_beat_buzzer_connects_total",
+                "Buzzer WebSocket connections identified.",
+            ),
+            Counter::BuzzerDisconnects => (
+                "neon_beat_buzzer_disconnects_total",
+                "Identified buzzer WebSocket connections closed.",
+            ),
+            Counter::Buzzes => ("neon_beat_buzzes_total", "Buzz messages handled."),
+            Counter::PersistenceWrites => (
+                "neon_beat_persistence_writes_total",
+                "Game and team documents written to the store.",
+            ),
+            Counter::DebounceFlushes => (
+                "neon_beat_debounce_flushes_total",
+                "Writes held back by the persistence cooldown and flushed later.",
+            ),
+            Counter::Transitions => (
+                "neon_beat_phase_transitions_total",
+                "Game phase transitions applied.",
+            ),
+        }
+    }
+}
+
+/// Lock-free counters exposed by `GET /metrics` (`metrics` feature) in the Prometheus text
+/// format.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    counters: [AtomicU64; Counter::ALL.len()],
+}
+
+impl Metrics {
+    /// Add one to `counter`.
+    pub fn increment(&self, counter: Counter) {
+        self.counters[counter as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Current value of `counter`.
+    pub fn get(&self, counter: Counter) -> u64 {
+        self.counters[counter as usize].load(Ordering::Relaxed)
+    }
+
+    /// Render every counter, and the current `phase` as a gauge labelled with each
+    /// [`GamePhase`] variant, in the Prometheus text exposition format.
+    pub fn render(&self, phase: &GamePhase) -> String {
+        let mut out = String::new();
+        for counter in Counter::ALL {
+            let (name, help) = counter.describe();
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} counter");
+            let _ = writeln!(out, "{name} {}", self.get(counter));
+        }
+
+        let current = phase_label(phase);
+        let _ = writeln!(out, "# HELP neon_beat_phase Current game phase.");
+        let _ = writeln!(out, "# TYPE neon_beat_phase gauge");
+        for label in ["Idle", "GameRunning", "ShowScores"] {
+            let _ = writeln!(
+                out,
+                "neon_beat_phase{{phase=\"{label}\"}} {}",
+                u8::from(label == current)
+            );
+        }
+        out
+    }
+}
+
+fn phase_label(phase: &GamePhase) -> &'static str {
+    match phase {
+        GamePhase::Idle => "Idle",
+        GamePhase::GameRunning(_) => "GameRunning",
+        GamePhase::ShowScores => "ShowScores",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::state_machine::{GameRunningPhase, PrepStatus};
+
+    #[test]
+    fn renders_counters_and_the_current_phase() {
+        let metrics = Metrics::default();
+        metrics.increment(Counter::Buzzes);
+        metrics.increment(Counter::Buzzes);
+        metrics.increment(Counter::PersistenceWrites);
+
+        let text = metrics.render(&GamePhase::GameRunning(GameRunningPhase::Prep(
+            PrepStatus::Ready,
+        )));
+        assert!(text.contains("# TYPE neon_beat_buzzes_total counter\nneon_beat_buzzes_total 2\n"));
+        assert!(text.contains("neon_beat_persistence_writes_total 1\n"));
+        assert!(text.contains("neon_beat_debounce_flushes_total 0\n"));
+        assert!(text.contains("neon_beat_phase{phase=\"GameRunning\"} 1\n"));
+        assert!(text.contains("neon_beat_phase{phase=\"Idle\"} 0\n"));
+    }
+}
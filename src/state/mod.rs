@@ -43,14 +43,15 @@
 //!
 //! ### Tradeoffs
 //!
-//! - **Slight Delay**: Updates may take up to `COOLDOWN` ms to persist (default: 200ms)
+//! - **Slight Delay**: Updates may take up to the cooldown to persist (default: 200ms)
 //! - **Memory Overhead**: Pending updates are held in memory until flushed
 //! - **Complexity**: More complex than simple throttling
 //!
 //! ### Configuration
 //!
-//! Cooldown duration is currently hardcoded at 200ms but can be made configurable
-//! via `AppConfig` if different environments require different values.
+//! The cooldown defaults to 200ms and is set through the `persist_cooldown_ms` key of
+//! `AppConfig`, so high-latency backends can batch more while local ones write sooner.
+//! `storage_max_concurrent_writes` bounds how many game and team writes reach the store at once.
 //!
 //! ## Graceful Shutdown
 //!
@@ -59,36 +60,55 @@
 
 /// Game session data structures and conversions.
 pub mod game;
+/// Identifier generation for new games, teams and playlists.
+pub mod ids;
+/// Operational counters exposed to Prometheus.
+pub mod metrics;
+/// Bounded cache of the last pattern sent to each buzzer.
+pub mod patterns;
 /// Server-Sent Events hub and state management.
 mod sse;
 /// State machine for game phase transitions.
 pub mod state_machine;
+/// Game-scoped timers that can be frozen while paused.
+pub mod timers;
 /// State machine transition implementations.
 pub mod transitions;
 
 use std::{
-    sync::Arc,
+    collections::{HashSet, VecDeque},
+    net::IpAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
     time::{Duration, Instant},
 };
 
 use crate::{
-    config::{AppConfig, BuzzerPatternPreset},
-    dao::{game_store::GameStore, models::TeamEntity},
+    config::{AppConfig, resolve_config_path},
+    dao::{
+        game_store::GameStore,
+        models::{GameEntity, TeamEntity},
+    },
     dto::{
         common::{GamePhaseSnapshot, SongSnapshot},
-        game::TeamSummary,
+        game::{TeamSummary, rank_teams},
         phase::VisibleGamePhase,
     },
     error::ServiceError,
     state::{
         game::{GameSession, Team},
+        ids::IdGenerator,
+        metrics::{Counter, Metrics},
         state_machine::{GamePhase, GameRunningPhase, PairingSession, PauseKind, PrepStatus},
+        timers::GameTimers,
     },
 };
 use axum::extract::ws::Message;
-use dashmap::DashMap;
+use dashmap::{DashMap, mapref::entry::Entry};
 use indexmap::IndexMap;
-use tokio::sync::{Mutex, RwLock, mpsc, watch};
+use tokio::sync::{Mutex, RwLock, Semaphore, SemaphorePermit, mpsc, watch};
 use tokio::time::timeout;
 use tracing::{info, warn};
 use uuid::Uuid;
@@ -96,14 +116,17 @@ use uuid::Uuid;
 pub use self::sse::SseHub;
 pub use self::state_machine::{AbortError, ApplyError, Plan, PlanError, PlanId, Snapshot};
 use self::{
+    patterns::BuzzerPatternCache,
     sse::SseState,
     state_machine::{GameEvent, GameStateMachine},
 };
 
 /// Shared reference to application state, safe to clone across tasks.
 pub type SharedState = Arc<AppState>;
-/// Default timeout for state machine transitions.
-pub const DEFAULT_TRANSITION_TIMEOUT: Duration = Duration::from_secs(5);
+/// Number of spectator clients tracked by the reaction rate limiter before idle ones are pruned.
+const MAX_TRACKED_REACTION_CLIENTS: usize = 1024;
+/// Number of score adjustments remembered per team for undo.
+const SCORE_UNDO_DEPTH: usize = 10;
 
 /// Handle used to push messages to a connected buzzer.
 #[derive(Clone)]
@@ -141,17 +164,22 @@ pub struct BuzzerConnection {
 ///
 /// Call `flush_all_pending()` before shutdown to ensure all pending updates are saved.
 struct PersistenceCoordinator {
+    /// Minimum delay between two saves of the same document; later updates are debounced.
+    cooldown: Duration,
     /// Mutex used to serialize full game persistent saves to avoid concurrent PUTs.
     game_lock: Mutex<()>,
     /// Timestamp of last successful game persist, used for throttling.
     game_last_persist: RwLock<Option<Instant>>,
     /// Pending full game save that should be flushed after cooldown expires.
-    pending_game: RwLock<Option<GameSession>>,
+    pending_game: RwLock<Option<GameEntity>>,
     /// Flag indicating whether a flush task is already scheduled for the game.
     game_flush_scheduled: RwLock<bool>,
     /// Per-team persistence metadata (lock + throttle timestamp + pending update).
     /// Keyed by team_id only since only one game is active at a time.
     team_metadata: DashMap<Uuid, TeamPersistMetadata>,
+    /// Bounds the game and team writes issued to the store at the same time. A permit is always
+    /// taken after the game or team lock, never before, so the two cannot deadlock.
+    write_permits: Semaphore,
 }
 
 /// Metadata for coordinating team persistence operations.
@@ -169,16 +197,26 @@ struct TeamPersistMetadata {
 }
 
 impl PersistenceCoordinator {
-    fn new() -> Self {
+    fn new(cooldown: Duration, max_concurrent_writes: usize) -> Self {
         Self {
+            cooldown,
             game_lock: Mutex::new(()),
             game_last_persist: RwLock::new(None),
             pending_game: RwLock::new(None),
             game_flush_scheduled: RwLock::new(false),
             team_metadata: DashMap::new(),
+            write_permits: Semaphore::new(max_concurrent_writes.max(1)),
         }
     }
 
+    /// Wait for a free store write slot; hold the permit for the duration of the write.
+    async fn write_permit(&self) -> SemaphorePermit<'_> {
+        self.write_permits
+            .acquire()
+            .await
+            .expect("the write semaphore is never closed")
+    }
+
     /// Clear all persistence state in preparation for a new game session.
     ///
     /// This ensures that throttling, pending updates, and flush scheduling from the
@@ -197,23 +235,53 @@ impl PersistenceCoordinator {
     }
 }
 
+/// Number of pending writes persisted or failed by [`AppState::flush_all_pending`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FlushReport {
+    /// Pending writes successfully persisted.
+    pub flushed: usize,
+    /// Pending writes that could not be persisted and remain pending.
+    pub failed: usize,
+}
+
 /// Central application state storing persistent connections and database handles.
 pub struct AppState {
-    config: Arc<AppConfig>,
+    /// Current configuration, swapped as a whole when it is reloaded.
+    config: std::sync::RwLock<Arc<AppConfig>>,
     game_store: RwLock<Option<Arc<dyn GameStore>>>,
     sse: SseState,
     buzzers: DashMap<String, BuzzerConnection>,
     /// Last known pattern for each buzzer. This is updated on every successful pattern send
     /// and used to restore buzzer state when they reconnect.
-    /// Tracks the desired state for each buzzer regardless of connection status.
-    buzzer_last_patterns: DashMap<String, BuzzerPatternPreset>,
+    /// Tracks the desired state for each buzzer regardless of connection status, up to the
+    /// configured number of buzzers.
+    buzzer_last_patterns: BuzzerPatternCache,
+    /// When each buzzer last disconnected, to tolerate short drops.
+    buzzer_disconnects: DashMap<String, Instant>,
+    /// Last battery level (percent) reported by each buzzer.
+    buzzer_battery_levels: DashMap<String, u8>,
     game: RwLock<GameStateMachine>,
     current_game: RwLock<Option<GameSession>>,
     degraded_flag: RwLock<bool>,
     degraded_tx: watch::Sender<bool>,
+    /// Whether the degraded flag was last set by an admin drill rather than the storage
+    /// supervisor.
+    degraded_override: AtomicBool,
     transition_gate: Mutex<()>,
     transition_timeout: Option<Duration>,
     persistence: PersistenceCoordinator,
+    timers: GameTimers,
+    scores_visible: AtomicBool,
+    /// Whether the current game changed since the last autosave.
+    game_dirty: AtomicBool,
+    last_song_advance: std::sync::Mutex<Option<Instant>>,
+    last_reactions: DashMap<IpAddr, Instant>,
+    /// Last score adjustments of each team in the current game, most recent last.
+    score_history: DashMap<Uuid, VecDeque<i32>>,
+    /// Buzzers that buzzed while another team was answering, in press order.
+    buzz_queue: std::sync::Mutex<Vec<String>>,
+    ids: std::sync::RwLock<IdGenerator>,
+    metrics: Metrics,
 }
 
 impl AppState {
@@ -221,20 +289,41 @@ impl AppState {
     ///
     /// The application starts in degraded mode until a storage backend is installed.
     pub fn new() -> SharedState {
+        Self::with_config(AppConfig::load())
+    }
+
+    /// Construct a new [`AppState`] from an already resolved configuration.
+    pub fn with_config(config: AppConfig) -> SharedState {
         let (degraded_tx, _rx) = watch::channel(true);
+        let transition_timeout = config.transition_timeout();
         Arc::new(Self {
-            config: Arc::new(AppConfig::load()),
+            persistence: PersistenceCoordinator::new(
+                config.persist_cooldown(),
+                config.storage_max_concurrent_writes(),
+            ),
+            buzzer_last_patterns: BuzzerPatternCache::new(config.buzzer_pattern_cache_size()),
+            config: std::sync::RwLock::new(Arc::new(config)),
             game_store: RwLock::new(None),
             sse: SseState::new(16, 16),
             buzzers: DashMap::new(),
-            buzzer_last_patterns: DashMap::new(),
+            buzzer_disconnects: DashMap::new(),
+            buzzer_battery_levels: DashMap::new(),
             game: RwLock::new(GameStateMachine::new()),
             current_game: RwLock::new(None),
             degraded_flag: RwLock::new(true),
             degraded_tx,
+            degraded_override: AtomicBool::new(false),
             transition_gate: Mutex::new(()),
-            transition_timeout: Some(DEFAULT_TRANSITION_TIMEOUT),
-            persistence: PersistenceCoordinator::new(),
+            transition_timeout,
+            timers: GameTimers::default(),
+            scores_visible: AtomicBool::new(true),
+            game_dirty: AtomicBool::new(false),
+            last_song_advance: std::sync::Mutex::new(None),
+            last_reactions: DashMap::new(),
+            score_history: DashMap::new(),
+            buzz_queue: std::sync::Mutex::new(Vec::new()),
+            ids: std::sync::RwLock::new(IdGenerator::default()),
+            metrics: Metrics::default(),
         })
     }
 
@@ -250,7 +339,7 @@ impl AppState {
     ///
     /// - **Immediate persist**: If no recent persist occurred, saves immediately
     /// - **Debounced persist**: If within cooldown window, stores as pending and schedules flush
-    /// - **Cooldown**: 200ms (prevents more than 5 writes/second per entity)
+    /// - **Cooldown**: `persist_cooldown_ms`, 200ms by default (at most 5 writes/second per entity)
     ///
     /// ## Debouncing Details
     ///
@@ -267,13 +356,17 @@ impl AppState {
     ///
     /// ## Parameters
     ///
+    /// - `capture`: Builds the game document to save from the current session. Snapshots are
+    ///   built from a borrowed session so the playlist is never cloned; the pending snapshot
+    ///   always includes the teams since the flush performs a full save.
     /// - `persist_fn`: Closure that performs the actual storage operation
     async fn persist_with_throttle<F, Fut>(
         self: &Arc<Self>,
+        capture: fn(&GameSession) -> GameEntity,
         persist_fn: F,
     ) -> Result<(), ServiceError>
     where
-        F: FnOnce(Arc<dyn GameStore>, GameSession) -> Fut,
+        F: FnOnce(Arc<dyn GameStore>, GameEntity) -> Fut,
         Fut: std::future::Future<Output = Result<(), crate::dao::storage::StorageError>>,
     {
         // Serialize persistent saves so we don't issue concurrent PUTs to CouchDB which would
@@ -281,55 +374,55 @@ impl AppState {
         // occurred recently, skip another save.
         let _lock = self.persistence.game_lock.lock().await;
 
-        // Throttle window (tunable).
-        const PERSIST_COOLDOWN: Duration = Duration::from_millis(200);
-
-        if let Some(last) = *self.persistence.game_last_persist.read().await {
-            if last.elapsed() < PERSIST_COOLDOWN {
-                // Recent persist occurred; store as pending
-                let remaining = PERSIST_COOLDOWN - last.elapsed();
-
-                let snapshot = {
-                    let guard = self.current_game.read().await;
-                    guard
-                        .as_ref()
-                        .cloned()
-                        .ok_or_else(|| ServiceError::InvalidState("no active game".into()))?
-                };
+        // Throttle window, configured through `persist_cooldown_ms`.
+        let cooldown = self.persistence.cooldown;
 
-                // Only spawn flush task if one isn't already scheduled
-                let should_spawn = {
-                    let mut scheduled = self.persistence.game_flush_scheduled.write().await;
-                    let currently_scheduled = *scheduled;
-                    if !currently_scheduled {
-                        *scheduled = true;
-                    }
-                    !currently_scheduled
-                };
+        if let Some(last) = *self.persistence.game_last_persist.read().await
+            && last.elapsed() < cooldown
+        {
+            // Recent persist occurred; store as pending
+            let remaining = cooldown - last.elapsed();
+
+            let snapshot = {
+                let guard = self.current_game.read().await;
+                guard
+                    .as_ref()
+                    .map(GameSession::to_entity)
+                    .ok_or_else(|| ServiceError::InvalidState("no active game".into()))?
+            };
 
-                {
-                    let mut pending = self.persistence.pending_game.write().await;
-                    *pending = Some(snapshot);
+            // Only spawn flush task if one isn't already scheduled
+            let should_spawn = {
+                let mut scheduled = self.persistence.game_flush_scheduled.write().await;
+                let currently_scheduled = *scheduled;
+                if !currently_scheduled {
+                    *scheduled = true;
                 }
+                !currently_scheduled
+            };
 
-                drop(_lock);
+            {
+                let mut pending = self.persistence.pending_game.write().await;
+                *pending = Some(snapshot);
+            }
 
-                if should_spawn {
-                    // Spawn task to flush pending update after cooldown
-                    let state = Arc::clone(self);
-                    tokio::spawn(async move {
-                        tokio::time::sleep(remaining).await;
-                        if let Err(e) = state.flush_pending_game().await {
-                            warn!(
-                                error = ?e,
-                                "failed to flush pending game update"
-                            );
-                        }
-                    });
-                }
+            drop(_lock);
 
-                return Ok(());
+            if should_spawn {
+                // Spawn task to flush pending update after cooldown
+                let state = Arc::clone(self);
+                tokio::spawn(async move {
+                    tokio::time::sleep(remaining).await;
+                    if let Err(e) = state.flush_pending_game().await {
+                        warn!(
+                            error = ?e,
+                            "failed to flush pending game update"
+                        );
+                    }
+                });
             }
+
+            return Ok(());
         }
 
         let store = self.require_game_store().await?;
@@ -337,11 +430,13 @@ impl AppState {
             let guard = self.current_game.read().await;
             guard
                 .as_ref()
-                .cloned()
+                .map(capture)
                 .ok_or_else(|| ServiceError::InvalidState("no active game".into()))?
         };
 
+        let _permit = self.persistence.write_permit().await;
         persist_fn(store, snapshot).await?;
+        self.record_write(false);
 
         *self.persistence.game_last_persist.write().await = Some(Instant::now());
         Ok(())
@@ -349,8 +444,8 @@ impl AppState {
 
     /// Persist the current in-memory game back into the configured store.
     pub async fn persist_current_game(self: &Arc<Self>) -> Result<(), ServiceError> {
-        self.persist_with_throttle(|store, snapshot| async move {
-            store.save_game(snapshot.into()).await
+        self.persist_with_throttle(GameSession::to_entity, |store, snapshot| async move {
+            store.save_game(snapshot).await
         })
         .await
     }
@@ -358,11 +453,12 @@ impl AppState {
     /// Persist only game document (without team documents) for efficient partial updates.
     /// Use this when only game-level fields have changed (e.g., current_song_index,
     /// current_song_found, playlist_song_order, found fields).
-    /// The `teams` field in the snapshot is ignored by the storage layer.
+    /// The snapshot is captured without teams, which the storage layer ignores anyway.
     pub async fn persist_current_game_without_teams(self: &Arc<Self>) -> Result<(), ServiceError> {
-        self.persist_with_throttle(|store, snapshot| async move {
-            store.save_game_without_teams(snapshot.into()).await
-        })
+        self.persist_with_throttle(
+            GameSession::to_entity_without_teams,
+            |store, snapshot| async move { store.save_game_without_teams(snapshot).await },
+        )
         .await
     }
 
@@ -384,7 +480,7 @@ impl AppState {
     ///
     /// Rapid-fire updates (e.g., score spam via REST API) are debounced:
     /// - First update: persists immediately
-    /// - Updates during cooldown (200ms): stored as pending
+    /// - Updates during cooldown (`persist_cooldown_ms`): stored as pending
     /// - After cooldown: flush task persists the final state
     ///
     /// Example timeline for team A:
@@ -407,7 +503,7 @@ impl AppState {
         team_id: Uuid,
         team: game::Team,
     ) -> Result<(), ServiceError> {
-        const TEAM_PERSIST_COOLDOWN: Duration = Duration::from_millis(200);
+        let cooldown = self.persistence.cooldown;
 
         // Get or create metadata for this specific team
         let mut metadata = self
@@ -422,23 +518,68 @@ impl AppState {
             });
 
         // Check throttle without holding the lock (fast path)
-        if let Some(last) = metadata.last_persist {
-            if last.elapsed() < TEAM_PERSIST_COOLDOWN {
-                // Recent persist for this team; store as pending
-                let remaining = TEAM_PERSIST_COOLDOWN - last.elapsed();
+        if let Some(last) = metadata.last_persist
+            && last.elapsed() < cooldown
+        {
+            // Recent persist for this team; store as pending
+            let remaining = cooldown - last.elapsed();
+            metadata.pending = Some(team);
+
+            // Only spawn flush task if one isn't already scheduled
+            let should_spawn = !metadata.flush_scheduled;
+            if should_spawn {
+                metadata.flush_scheduled = true;
+            }
+            drop(metadata);
+
+            if should_spawn {
+                // Spawn task to flush pending update after cooldown
+                let state = Arc::clone(self);
+                let task = async move {
+                    tokio::time::sleep(remaining).await;
+                    if let Err(e) = state.flush_pending_team(game_id, team_id).await {
+                        warn!(
+                            game_id = %game_id,
+                            team_id = %team_id,
+                            error = ?e,
+                            "failed to flush pending team update"
+                        );
+                    }
+                };
+                tokio::spawn(task);
+            }
+
+            return Ok(());
+        }
+
+        // Clone the lock to release the DashMap entry before awaiting
+        let team_lock = metadata.lock.clone();
+        drop(metadata);
+
+        // Lock only this specific team, allowing other teams to persist concurrently
+        let _lock = team_lock.lock().await;
+
+        // Double-check throttle after acquiring lock (race condition mitigation)
+        if let Some(metadata) = self.persistence.team_metadata.get(&team_id)
+            && let Some(last) = metadata.last_persist
+            && last.elapsed() < cooldown
+        {
+            // Another task persisted while we were waiting for the lock
+            // Store as pending for the next flush cycle
+            drop(metadata);
+            if let Some(mut metadata) = self.persistence.team_metadata.get_mut(&team_id) {
+                let remaining = cooldown - last.elapsed();
                 metadata.pending = Some(team);
 
                 // Only spawn flush task if one isn't already scheduled
                 let should_spawn = !metadata.flush_scheduled;
                 if should_spawn {
                     metadata.flush_scheduled = true;
-                }
-                drop(metadata);
+                    drop(metadata);
 
-                if should_spawn {
-                    // Spawn task to flush pending update after cooldown
+                    // Spawn task to flush this pending update
                     let state = Arc::clone(self);
-                    let task = async move {
+                    tokio::spawn(async move {
                         tokio::time::sleep(remaining).await;
                         if let Err(e) = state.flush_pending_team(game_id, team_id).await {
                             warn!(
@@ -448,61 +589,17 @@ impl AppState {
                                 "failed to flush pending team update"
                             );
                         }
-                    };
-                    tokio::spawn(task);
-                }
-
-                return Ok(());
-            }
-        }
-
-        // Clone the lock to release the DashMap entry before awaiting
-        let team_lock = metadata.lock.clone();
-        drop(metadata);
-
-        // Lock only this specific team, allowing other teams to persist concurrently
-        let _lock = team_lock.lock().await;
-
-        // Double-check throttle after acquiring lock (race condition mitigation)
-        if let Some(metadata) = self.persistence.team_metadata.get(&team_id) {
-            if let Some(last) = metadata.last_persist {
-                if last.elapsed() < TEAM_PERSIST_COOLDOWN {
-                    // Another task persisted while we were waiting for the lock
-                    // Store as pending for the next flush cycle
-                    drop(metadata);
-                    if let Some(mut metadata) = self.persistence.team_metadata.get_mut(&team_id) {
-                        let remaining = TEAM_PERSIST_COOLDOWN - last.elapsed();
-                        metadata.pending = Some(team);
-
-                        // Only spawn flush task if one isn't already scheduled
-                        let should_spawn = !metadata.flush_scheduled;
-                        if should_spawn {
-                            metadata.flush_scheduled = true;
-                            drop(metadata);
-
-                            // Spawn task to flush this pending update
-                            let state = Arc::clone(self);
-                            tokio::spawn(async move {
-                                tokio::time::sleep(remaining).await;
-                                if let Err(e) = state.flush_pending_team(game_id, team_id).await {
-                                    warn!(
-                                        game_id = %game_id,
-                                        team_id = %team_id,
-                                        error = ?e,
-                                        "failed to flush pending team update"
-                                    );
-                                }
-                            });
-                        }
-                    }
-                    return Ok(());
+                    });
                 }
             }
+            return Ok(());
         }
 
         let store = self.require_game_store().await?;
         let team_entity: TeamEntity = (team_id, team).into();
+        let _permit = self.persistence.write_permit().await;
         store.save_team(game_id, team_entity).await?;
+        self.record_write(false);
 
         // Update the per-team throttle timestamp
         if let Some(mut metadata) = self.persistence.team_metadata.get_mut(&team_id) {
@@ -533,6 +630,7 @@ impl AppState {
         let _lock = team_lock.lock().await;
 
         let store = self.require_game_store().await?;
+        let _permit = self.persistence.write_permit().await;
         store.delete_team(game_id, team_id).await?;
 
         // Clean up the metadata entry for this deleted team
@@ -547,12 +645,33 @@ impl AppState {
             let mut guard = self.game_store.write().await;
             *guard = Some(store);
         }
+        self.end_degraded_override();
         self.update_degraded(false).await;
     }
 
-    /// Access the immutable application configuration.
+    /// Access the current application configuration.
+    ///
+    /// The returned snapshot stays consistent even if the configuration is reloaded meanwhile;
+    /// call this again to observe a reload.
     pub fn config(&self) -> Arc<AppConfig> {
-        Arc::clone(&self.config)
+        Arc::clone(&self.config.read().expect("config lock poisoned"))
+    }
+
+    /// Read the configuration file again and swap it in, keeping the current configuration when
+    /// the file cannot be read or parsed.
+    ///
+    /// Settings read when the state is built (persistence cooldown and write permits, transition
+    /// timeout, pattern cache size) keep their startup values, as do the autosave and event
+    /// recording tasks spawned at startup.
+    pub fn reload_config(&self) -> Result<(), String> {
+        self.reload_config_from(&resolve_config_path())
+    }
+
+    /// Same as [`Self::reload_config`], reading the configuration file at `path`.
+    pub fn reload_config_from(&self, path: &std::path::Path) -> Result<(), String> {
+        let config = AppConfig::reload(path)?;
+        *self.config.write().expect("config lock poisoned") = Arc::new(config);
+        Ok(())
     }
 
     /// Current degraded flag.
@@ -560,6 +679,31 @@ impl AppState {
         *self.degraded_flag.read().await
     }
 
+    /// Force the degraded flag for an operational drill, whatever the storage health.
+    ///
+    /// The storage supervisor leaves the flag alone until the next real health transition,
+    /// which ends the override.
+    pub async fn override_degraded(&self, degraded: bool) {
+        self.degraded_override.store(true, Ordering::SeqCst);
+        warn!(
+            degraded,
+            "degraded flag manually overridden until the next storage health transition"
+        );
+        self.update_degraded(degraded).await;
+    }
+
+    /// Whether the degraded flag currently comes from [`Self::override_degraded`].
+    pub fn degraded_overridden(&self) -> bool {
+        self.degraded_override.load(Ordering::SeqCst)
+    }
+
+    /// End a manual degraded override, called on real storage health transitions.
+    pub fn end_degraded_override(&self) {
+        if self.degraded_override.swap(false, Ordering::SeqCst) {
+            info!("storage health changed; manual degraded override ended");
+        }
+    }
+
     /// Subscribe to degraded mode updates.
     pub fn degraded_watcher(&self) -> watch::Receiver<bool> {
         self.degraded_tx.subscribe()
@@ -575,9 +719,25 @@ impl AppState {
         self.sse.admin().hub()
     }
 
-    /// Token guard that ensures a single admin SSE subscriber at a time.
-    pub fn admin_token(&self) -> &Mutex<Option<String>> {
-        self.sse.admin().token()
+    /// SSE sub-state, including the spectator count of the public streams.
+    pub fn sse(&self) -> &SseState {
+        &self.sse
+    }
+
+    /// Tokens of the connected admin SSE subscribers, capped by `admin_max_subscribers`.
+    pub fn admin_tokens(&self) -> &Mutex<HashSet<String>> {
+        self.sse.admin().tokens()
+    }
+
+    /// Identifier of the current admin SSE session (spanning while any admin stream is
+    /// connected), used to attribute state changes triggered outside the admin API.
+    pub fn admin_session(&self) -> Option<Uuid> {
+        self.sse.admin().session()
+    }
+
+    /// Record (or clear) the current admin SSE session.
+    pub fn set_admin_session(&self, session: Option<Uuid>) {
+        self.sse.admin().set_session(session);
     }
 
     /// Registry of active buzzer sockets keyed by their identifier.
@@ -585,12 +745,186 @@ impl AppState {
         &self.buzzers
     }
 
+    /// Register a freshly identified buzzer connection.
+    pub fn connect_buzzer(&self, connection: BuzzerConnection) {
+        self.buzzer_disconnects.remove(&connection.id);
+        self.buzzers.insert(connection.id.clone(), connection);
+    }
+
+    /// Drop a buzzer connection, remembering when it went away while the reconnect grace lasts.
+    pub fn disconnect_buzzer(&self, buzzer_id: &str) {
+        if self.buzzers.remove(buzzer_id).is_none() {
+            return;
+        }
+        let grace = self.config().buzzer_reconnect_grace();
+        // Forget buzzers whose grace is over so ids that never come back do not pile up.
+        self.buzzer_disconnects.retain(|_, at| at.elapsed() < grace);
+        if !grace.is_zero() {
+            self.buzzer_disconnects
+                .insert(buzzer_id.to_string(), Instant::now());
+        }
+    }
+
+    /// Close a buzzer connection on behalf of the admin and forget its last pattern, so none is
+    /// restored if it reconnects. Returns whether the buzzer was connected.
+    pub fn kick_buzzer(&self, buzzer_id: &str) -> bool {
+        let Some((_, connection)) = self.buzzers.remove(buzzer_id) else {
+            return false;
+        };
+        let _ = connection.tx.send(Message::Close(None));
+        self.buzzer_last_patterns.remove(buzzer_id);
+        true
+    }
+
+    /// Whether a buzzer is connected or disconnected less than the configured grace ago.
+    pub fn buzzer_present(&self, buzzer_id: &str) -> bool {
+        self.buzzers.contains_key(buzzer_id)
+            || self
+                .buzzer_disconnects
+                .get(buzzer_id)
+                .is_some_and(|at| at.elapsed() < self.config().buzzer_reconnect_grace())
+    }
+
+    /// Record the battery level reported by a buzzer, returning the previously reported one.
+    pub fn record_battery_level(&self, buzzer_id: &str, level: u8) -> Option<u8> {
+        self.buzzer_battery_levels
+            .insert(buzzer_id.to_string(), level)
+    }
+
     /// Registry of last known patterns for all buzzers.
     /// This is updated on every successful pattern send and used to restore buzzer state on reconnection.
-    pub fn buzzer_last_patterns(&self) -> &DashMap<String, BuzzerPatternPreset> {
+    pub fn buzzer_last_patterns(&self) -> &BuzzerPatternCache {
         &self.buzzer_last_patterns
     }
 
+    /// Timers attached to the current game (auto-reveal, answer countdown).
+    pub fn timers(&self) -> &GameTimers {
+        &self.timers
+    }
+
+    /// Operational counters rendered by `GET /metrics`.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Count a document written to the store, `debounced` when it was held back by the cooldown.
+    fn record_write(&self, debounced: bool) {
+        self.metrics.increment(Counter::PersistenceWrites);
+        if debounced {
+            self.metrics.increment(Counter::DebounceFlushes);
+        }
+    }
+
+    /// Whether team scores are currently shown to public clients.
+    pub fn scores_visible(&self) -> bool {
+        self.scores_visible.load(Ordering::Relaxed)
+    }
+
+    /// Generator minting the ids of new games, teams and playlists.
+    pub fn ids(&self) -> IdGenerator {
+        self.ids.read().expect("id generator lock poisoned").clone()
+    }
+
+    /// Replace the id generator, e.g. with [`IdGenerator::sequential`] in tests.
+    pub fn set_id_generator(&self, ids: IdGenerator) {
+        *self.ids.write().expect("id generator lock poisoned") = ids;
+    }
+
+    /// Reject a song advance when the previous one happened less than `min_gap` ago.
+    ///
+    /// On rejection, returns the time left before another advance is allowed.
+    pub fn check_song_advance(&self, min_gap: Duration) -> Result<(), Duration> {
+        let last = self
+            .last_song_advance
+            .lock()
+            .expect("song advance lock poisoned");
+        match last.map(|at| at.elapsed()) {
+            Some(elapsed) if elapsed < min_gap => Err(min_gap - elapsed),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record that the game just moved to another song, starting the gap before the next advance.
+    pub fn record_song_advance(&self) {
+        *self
+            .last_song_advance
+            .lock()
+            .expect("song advance lock poisoned") = Some(Instant::now());
+    }
+
+    /// Record a spectator reaction from `client` unless its previous one was less than
+    /// `cooldown` ago.
+    ///
+    /// On rejection, returns the time left before the client may react again.
+    pub fn claim_reaction(&self, client: IpAddr, cooldown: Duration) -> Result<(), Duration> {
+        // Forget idle clients so the table stays bounded by recent activity.
+        if self.last_reactions.len() > MAX_TRACKED_REACTION_CLIENTS {
+            self.last_reactions.retain(|_, at| at.elapsed() < cooldown);
+        }
+        match self.last_reactions.entry(client) {
+            Entry::Occupied(mut last) => {
+                let elapsed = last.get().elapsed();
+                if elapsed < cooldown {
+                    return Err(cooldown - elapsed);
+                }
+                last.insert(Instant::now());
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(Instant::now());
+            }
+        }
+        Ok(())
+    }
+
+    /// Remember a score adjustment of `team_id` so it can be undone, keeping the last
+    /// [`SCORE_UNDO_DEPTH`] ones.
+    pub fn record_score_adjustment(&self, team_id: Uuid, delta: i32) {
+        let mut history = self.score_history.entry(team_id).or_default();
+        if history.len() == SCORE_UNDO_DEPTH {
+            history.pop_front();
+        }
+        history.push_back(delta);
+    }
+
+    /// Take the most recent score adjustment of `team_id`, if any is left to undo.
+    pub fn take_last_score_adjustment(&self, team_id: Uuid) -> Option<i32> {
+        self.score_history.get_mut(&team_id)?.pop_back()
+    }
+
+    /// Buzzers waiting for their turn during the current buzz pause, in press order.
+    pub fn buzz_queue(&self) -> Vec<String> {
+        self.buzz_queue
+            .lock()
+            .expect("buzz queue lock poisoned")
+            .clone()
+    }
+
+    /// Append a buzzer to the buzz queue.
+    pub fn enqueue_buzz(&self, buzzer_id: &str) {
+        self.buzz_queue
+            .lock()
+            .expect("buzz queue lock poisoned")
+            .push(buzzer_id.to_string());
+    }
+
+    /// Take the buzzer first in the buzz queue, if any.
+    pub fn pop_queued_buzz(&self) -> Option<String> {
+        let mut queue = self.buzz_queue.lock().expect("buzz queue lock poisoned");
+        (!queue.is_empty()).then(|| queue.remove(0))
+    }
+
+    fn clear_buzz_queue(&self) {
+        self.buzz_queue
+            .lock()
+            .expect("buzz queue lock poisoned")
+            .clear();
+    }
+
+    /// Show or hide team scores from public clients. Admin clients always see them.
+    pub fn set_scores_visible(&self, visible: bool) {
+        self.scores_visible.store(visible, Ordering::Relaxed);
+    }
+
     /// Snapshot the current pairing session if one is active.
     pub async fn pairing_session(&self) -> Option<PairingSession> {
         let sm = self.game.read().await;
@@ -614,13 +948,11 @@ impl AppState {
         }
     }
 
-    /// Check whether every team in `teams` has an active buzzer connection registered.
+    /// Check whether every team in `teams` has a buzzer present, see [`Self::buzzer_present`].
     pub fn all_teams_paired(&self, teams: &IndexMap<Uuid, Team>) -> bool {
-        teams.iter().all(|(_, team)| {
-            team.buzzer_id
-                .as_ref()
-                .is_some_and(|id| self.buzzers.contains_key(id))
-        })
+        teams
+            .iter()
+            .all(|(_, team)| team.buzzer_ids.iter().any(|id| self.buzzer_present(id)))
     }
 
     /// Snapshot the current phase of the shared game state machine.
@@ -673,9 +1005,20 @@ impl AppState {
         F: FnOnce(&mut Option<GameSession>) -> R,
     {
         let mut guard = self.current_game.write().await;
+        self.mark_game_dirty();
         f(&mut guard)
     }
 
+    /// Flag the current game as changed since the last autosave.
+    pub fn mark_game_dirty(&self) {
+        self.game_dirty.store(true, Ordering::Relaxed);
+    }
+
+    /// Clear the autosave dirty flag, returning whether the game changed since the last call.
+    pub fn take_game_dirty(&self) -> bool {
+        self.game_dirty.swap(false, Ordering::Relaxed)
+    }
+
     /// Clear all game-scoped state in preparation for a new game session.
     ///
     /// This clears:
@@ -690,6 +1033,13 @@ impl AppState {
 
         // Clear buzzer pattern cache
         self.buzzer_last_patterns.clear();
+
+        // Score adjustments of the previous game can no longer be undone
+        self.score_history.clear();
+        self.clear_buzz_queue();
+
+        // Drop timers scheduled for the previous game
+        self.timers.cancel_all();
     }
 
     /// Flush any pending team update for the given team_id.
@@ -723,7 +1073,9 @@ impl AppState {
 
             let store = self.require_game_store().await?;
             let team_entity: TeamEntity = (team_id, team).into();
+            let _permit = self.persistence.write_permit().await;
             store.save_team(game_id, team_entity).await?;
+            self.record_write(true);
 
             // Update timestamp
             if let Some(mut metadata) = self.persistence.team_metadata.get_mut(&team_id) {
@@ -756,7 +1108,9 @@ impl AppState {
             let _lock = self.persistence.game_lock.lock().await;
 
             let store = self.require_game_store().await?;
-            store.save_game(game.into()).await?;
+            let _permit = self.persistence.write_permit().await;
+            store.save_game(game).await?;
+            self.record_write(true);
 
             *self.persistence.game_last_persist.write().await = Some(Instant::now());
         }
@@ -783,11 +1137,13 @@ impl AppState {
             }
             _ => None,
         };
+        let buzz_queue = paused_buzzer.as_ref().map(|_| self.buzz_queue());
 
         let mut song = None;
         let mut scoreboard = None;
         let mut found_point_fields = None;
         let mut found_bonus_fields = None;
+        let mut outstanding_required_fields = None;
 
         let need_song = matches!(
             phase,
@@ -798,8 +1154,14 @@ impl AppState {
         let need_scoreboard = matches!(phase, GamePhase::ShowScores);
 
         if need_song || need_found_fields || need_scoreboard {
-            let (session_song, session_scoreboard, session_point_fields, session_bonus_fields) =
-                self.read_current_game(|maybe| {
+            let (
+                session_song,
+                session_scoreboard,
+                session_point_fields,
+                session_bonus_fields,
+                session_outstanding_fields,
+            ) = self
+                .read_current_game(|maybe| {
                     if let Some(game) = maybe {
                         (
                             if need_song {
@@ -808,7 +1170,7 @@ impl AppState {
                                 None
                             },
                             if need_scoreboard {
-                                Some(teams_to_summaries(&game.teams))
+                                Some(rank_teams(teams_to_summaries(&game.teams)))
                             } else {
                                 None
                             },
@@ -822,9 +1184,14 @@ impl AppState {
                             } else {
                                 None
                             },
+                            if need_found_fields && game.enforce_required_fields {
+                                Some(game.outstanding_required_fields())
+                            } else {
+                                None
+                            },
                         )
                     } else {
-                        (None, None, None, None)
+                        (None, None, None, None, None)
                     }
                 })
                 .await;
@@ -833,6 +1200,7 @@ impl AppState {
             scoreboard = session_scoreboard;
             found_point_fields = session_point_fields;
             found_bonus_fields = session_bonus_fields;
+            outstanding_required_fields = session_outstanding_fields;
         }
 
         GamePhaseSnapshot {
@@ -841,10 +1209,13 @@ impl AppState {
             degraded,
             pairing_team_id,
             paused_buzzer,
+            buzz_queue,
             song,
             scoreboard,
             found_point_fields,
             found_bonus_fields,
+            outstanding_required_fields,
+            allowed_actions: None,
         }
     }
 
@@ -890,20 +1261,54 @@ impl AppState {
     pub async fn shutdown(self: &Arc<Self>) -> Result<(), ServiceError> {
         info!("Starting graceful shutdown of persistence layer");
 
-        let mut error_count = 0;
-        let mut success_count = 0;
+        let report = self.flush_all_pending().await;
+
+        if report.failed > 0 {
+            warn!(
+                success = report.flushed,
+                errors = report.failed,
+                "Graceful shutdown completed with errors"
+            );
+        } else if report.flushed > 0 {
+            info!(
+                success = report.flushed,
+                "Graceful shutdown completed successfully"
+            );
+        } else {
+            info!("Graceful shutdown completed (no pending updates)");
+        }
+
+        Ok(())
+    }
+
+    /// Immediately persist every pending debounced write: the full game snapshot and the
+    /// pending team updates.
+    ///
+    /// Writes go through the same locks as the debounced flush tasks, so this is safe to call
+    /// while regular writes are in flight; a flush task firing afterwards finds nothing left to
+    /// write. A write that fails is put back as pending unless a newer update replaced it.
+    pub async fn flush_all_pending(self: &Arc<Self>) -> FlushReport {
+        let mut report = FlushReport::default();
 
         // Flush pending game save
-        if let Some(game) = self.persistence.pending_game.read().await.clone() {
-            info!("Flushing pending game save during shutdown");
-            match self.flush_game_immediate(game).await {
+        let pending_game = self.persistence.pending_game.write().await.take();
+        if let Some(game) = pending_game {
+            info!("Flushing pending game save");
+            match self.flush_game_immediate(game.clone()).await {
                 Ok(_) => {
-                    success_count += 1;
+                    report.flushed += 1;
+                    self.record_write(true);
+                    *self.persistence.game_last_persist.write().await = Some(Instant::now());
                     info!("Successfully flushed pending game save");
                 }
                 Err(e) => {
-                    error_count += 1;
-                    warn!(error = ?e, "Failed to flush pending game save during shutdown");
+                    report.failed += 1;
+                    self.persistence
+                        .pending_game
+                        .write()
+                        .await
+                        .get_or_insert(game);
+                    warn!(error = ?e, "Failed to flush pending game save");
                 }
             }
         }
@@ -923,10 +1328,7 @@ impl AppState {
             .collect();
 
         if !team_ids.is_empty() {
-            info!(
-                count = team_ids.len(),
-                "Flushing pending team updates during shutdown"
-            );
+            info!(count = team_ids.len(), "Flushing pending team updates");
         }
 
         for team_id in team_ids {
@@ -935,7 +1337,7 @@ impl AppState {
                 Some(id) => id,
                 None => {
                     warn!(team_id = %team_id, "Cannot flush team: no active game");
-                    error_count += 1;
+                    report.failed += 1;
                     continue;
                 }
             };
@@ -955,52 +1357,48 @@ impl AppState {
                 // Lock and persist immediately
                 let _lock = team_lock.lock().await;
 
-                match self.require_game_store().await {
+                let result = match self.require_game_store().await {
                     Ok(store) => {
-                        let team_entity: TeamEntity = (team_id, team).into();
-                        match store.save_team(game_id, team_entity).await {
-                            Ok(_) => {
-                                success_count += 1;
-                                info!(team_id = %team_id, "Successfully flushed pending team update");
-                            }
-                            Err(e) => {
-                                error_count += 1;
-                                warn!(team_id = %team_id, error = ?e, "Failed to flush pending team update during shutdown");
-                            }
+                        let _permit = self.persistence.write_permit().await;
+                        store
+                            .save_team(game_id, (team_id, team.clone()).into())
+                            .await
+                            .map_err(ServiceError::from)
+                    }
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(_) => {
+                        report.flushed += 1;
+                        self.record_write(true);
+                        if let Some(mut metadata) = self.persistence.team_metadata.get_mut(&team_id)
+                        {
+                            metadata.last_persist = Some(Instant::now());
                         }
+                        info!(team_id = %team_id, "Successfully flushed pending team update");
                     }
                     Err(e) => {
-                        error_count += 1;
-                        warn!(team_id = %team_id, error = ?e, "Failed to get game store during shutdown");
+                        report.failed += 1;
+                        if let Some(mut metadata) = self.persistence.team_metadata.get_mut(&team_id)
+                        {
+                            metadata.pending.get_or_insert(team);
+                        }
+                        warn!(team_id = %team_id, error = ?e, "Failed to flush pending team update");
                     }
                 }
             }
         }
 
-        if error_count > 0 {
-            warn!(
-                success = success_count,
-                errors = error_count,
-                "Graceful shutdown completed with errors"
-            );
-        } else if success_count > 0 {
-            info!(
-                success = success_count,
-                "Graceful shutdown completed successfully"
-            );
-        } else {
-            info!("Graceful shutdown completed (no pending updates)");
-        }
-
-        Ok(())
+        report
     }
 
     /// Immediately flush a game snapshot, bypassing cooldown checks.
-    /// Used during graceful shutdown.
-    async fn flush_game_immediate(&self, game: GameSession) -> Result<(), ServiceError> {
+    /// Used by [`Self::flush_all_pending`].
+    async fn flush_game_immediate(&self, game: GameEntity) -> Result<(), ServiceError> {
         let _lock = self.persistence.game_lock.lock().await;
         let store = self.require_game_store().await?;
-        store.save_game(game.into()).await?;
+        let _permit = self.persistence.write_permit().await;
+        store.save_game(game).await?;
         Ok(())
     }
 
@@ -1066,6 +1464,14 @@ impl AppState {
         match outcome {
             Ok(value) => {
                 let next = self.apply_planned_transition(plan_id).await?;
+                self.metrics.increment(Counter::Transitions);
+                // The queue only lives as long as the buzz pause it was gathered in.
+                if !matches!(
+                    next,
+                    GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz { .. }))
+                ) {
+                    self.clear_buzz_queue();
+                }
                 drop(gate);
                 Ok((value, next))
             }
@@ -1095,3 +1501,111 @@ fn current_song_snapshot(game: &GameSession) -> Option<SongSnapshot> {
     let song = game.playlist.songs.get(&song_id)?;
     Some(SongSnapshot::from_game_song(song_id, song))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::{
+        dao::game_store::testing::RecordingStore,
+        state::game::{Team, TeamColor},
+    };
+
+    #[test]
+    fn disconnects_are_forgotten_once_their_grace_is_over() {
+        let state =
+            AppState::with_config(AppConfig::from_json(r#"{"buzzer_reconnect_grace_ms": 20}"#));
+        let reconnect_then_drop = |id: &str| {
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+            state.connect_buzzer(BuzzerConnection { id: id.into(), tx });
+            state.disconnect_buzzer(id);
+        };
+
+        reconnect_then_drop("deadbeef0001");
+        assert!(state.buzzer_present("deadbeef0001"));
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(!state.buzzer_present("deadbeef0001"));
+
+        reconnect_then_drop("deadbeef0002");
+        assert!(!state.buzzer_disconnects.contains_key("deadbeef0001"));
+        assert!(state.buzzer_disconnects.contains_key("deadbeef0002"));
+
+        let state =
+            AppState::with_config(AppConfig::from_json(r#"{"buzzer_reconnect_grace_ms": 0}"#));
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        state.connect_buzzer(BuzzerConnection {
+            id: "deadbeef0003".into(),
+            tx,
+        });
+        state.disconnect_buzzer("deadbeef0003");
+        assert!(state.buzzer_disconnects.is_empty());
+    }
+
+    #[test]
+    fn failed_config_reload_keeps_the_previous_config() {
+        let path = std::env::temp_dir().join(format!("neon-beat-config-{}.json", Uuid::new_v4()));
+        let state = AppState::with_config(AppConfig::from_json(r#"{"applause_ms": 30}"#));
+
+        std::fs::write(&path, r#"{"applause_ms": 50}"#).unwrap();
+        state.reload_config_from(&path).unwrap();
+        assert_eq!(
+            state.config().applause_duration(),
+            Some(Duration::from_millis(50))
+        );
+
+        std::fs::write(&path, r#"{"applause_ms": "#).unwrap();
+        assert!(state.reload_config_from(&path).is_err());
+        assert_eq!(
+            state.config().applause_duration(),
+            Some(Duration::from_millis(50))
+        );
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(state.reload_config_from(&path).is_err());
+        assert_eq!(
+            state.config().applause_duration(),
+            Some(Duration::from_millis(50))
+        );
+    }
+
+    async fn peak_concurrent_team_saves(max_concurrent_writes: usize) -> usize {
+        let state = AppState::with_config(AppConfig::from_json(&format!(
+            r#"{{"storage_max_concurrent_writes": {max_concurrent_writes}}}"#
+        )));
+        let store = Arc::new(RecordingStore::with_team_save_delay(Duration::from_millis(
+            20,
+        )));
+        state.set_game_store(store.clone()).await;
+
+        let game_id = Uuid::new_v4();
+        let writes = (0..4).map(|index| {
+            let state = state.clone();
+            let team = Team {
+                buzzer_ids: Vec::new(),
+                name: format!("Team {index}"),
+                score: 0,
+                color: TeamColor {
+                    h: 0.0,
+                    s: 1.0,
+                    v: 1.0,
+                },
+                updated_at: std::time::SystemTime::now(),
+                created_in_session: None,
+            };
+            tokio::spawn(async move { state.persist_team(game_id, Uuid::new_v4(), team).await })
+        });
+        for write in futures::future::join_all(writes).await {
+            write.unwrap().unwrap();
+        }
+
+        assert_eq!(store.teams.lock().unwrap().len(), 4);
+        store.peak_team_saves.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn store_writes_are_bounded_by_the_permit_count() {
+        assert_eq!(peak_concurrent_team_saves(1).await, 1);
+        assert!(peak_concurrent_team_saves(4).await > 1);
+    }
+}
@@ -0,0 +1,120 @@
+//! Bounded cache of the last pattern sent to each buzzer.
+//!
+//! Patterns are kept for disconnected buzzers too, so they can be restored on reconnection. On a
+//! long-running instance transient buzzer ids would pile up forever, so the cache holds at most
+//! `buzzer_pattern_cache_size` entries and evicts the least recently used one beyond that.
+
+use std::time::Instant;
+
+use dashmap::DashMap;
+use tracing::debug;
+
+use crate::config::BuzzerPatternPreset;
+
+#[derive(Debug)]
+struct CachedPattern {
+    preset: BuzzerPatternPreset,
+    last_seen: Instant,
+}
+
+/// Last known pattern of each buzzer, with a last-seen instant used for LRU eviction.
+#[derive(Debug)]
+pub struct BuzzerPatternCache {
+    entries: DashMap<String, CachedPattern>,
+    capacity: usize,
+}
+
+impl BuzzerPatternCache {
+    /// Create an empty cache holding at most `capacity` buzzers (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Last pattern stored for `buzzer_id`, marking the buzzer as recently seen.
+    pub fn get(&self, buzzer_id: &str) -> Option<BuzzerPatternPreset> {
+        let mut entry = self.entries.get_mut(buzzer_id)?;
+        entry.last_seen = Instant::now();
+        Some(entry.preset.clone())
+    }
+
+    /// Last pattern stored for `buzzer_id`, without marking the buzzer as recently seen.
+    pub fn peek(&self, buzzer_id: &str) -> Option<BuzzerPatternPreset> {
+        self.entries
+            .get(buzzer_id)
+            .map(|entry| entry.preset.clone())
+    }
+
+    /// Store the pattern of `buzzer_id`, evicting the least recently seen buzzer when the cache
+    /// grows beyond its capacity.
+    pub fn insert(&self, buzzer_id: impl Into<String>, preset: BuzzerPatternPreset) {
+        let buzzer_id = buzzer_id.into();
+        self.entries.insert(
+            buzzer_id.clone(),
+            CachedPattern {
+                preset,
+                last_seen: Instant::now(),
+            },
+        );
+        while self.entries.len() > self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|entry| *entry.key() != buzzer_id)
+                .min_by_key(|entry| entry.last_seen)
+                .map(|entry| entry.key().clone());
+            let Some(oldest) = oldest else { break };
+            self.entries.remove(&oldest);
+            debug!(buzzer_id = %oldest, "evicted least recently seen buzzer pattern");
+        }
+    }
+
+    /// Forget the pattern stored for `buzzer_id`.
+    pub fn remove(&self, buzzer_id: &str) {
+        self.entries.remove(buzzer_id);
+    }
+
+    /// Number of buzzers with a stored pattern.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no pattern is stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget every stored pattern.
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_recently_seen_buzzer_is_evicted_beyond_the_cap() {
+        let cache = BuzzerPatternCache::new(2);
+        cache.insert("a", BuzzerPatternPreset::Waiting);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        cache.insert("b", BuzzerPatternPreset::Waiting);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        // Reading "a" makes "b" the least recently seen buzzer.
+        assert!(cache.get("a").is_some());
+        std::thread::sleep(std::time::Duration::from_millis(2));
+
+        cache.insert("c", BuzzerPatternPreset::WaitingForPairing);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("a").is_some());
+        assert!(matches!(
+            cache.get("c"),
+            Some(BuzzerPatternPreset::WaitingForPairing)
+        ));
+    }
+}
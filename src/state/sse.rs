@@ -1,4 +1,10 @@
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
 use tokio::sync::{Mutex, broadcast};
+use uuid::Uuid;
 
 use crate::dto::sse::ServerEvent;
 
@@ -6,6 +12,10 @@ use crate::dto::sse::ServerEvent;
 pub struct SseState {
     public: SseHub,
     admin: AdminSseState,
+    /// Number of connected public subscribers (SSE and WebSocket).
+    spectators: AtomicUsize,
+    /// Whether a debounced `spectators` broadcast is already scheduled.
+    spectators_broadcast_pending: AtomicBool,
 }
 
 impl SseState {
@@ -14,6 +24,8 @@ impl SseState {
         Self {
             public: SseHub::new(public_capacity),
             admin: AdminSseState::new(admin_capacity),
+            spectators: AtomicUsize::new(0),
+            spectators_broadcast_pending: AtomicBool::new(false),
         }
     }
 
@@ -22,24 +34,62 @@ impl SseState {
         &self.public
     }
 
-    /// Access the admin SSE state bundle containing both hub and token.
+    /// Access the admin SSE state bundle containing both hub and tokens.
     pub fn admin(&self) -> &AdminSseState {
         &self.admin
     }
+
+    /// Number of connected public subscribers.
+    pub fn spectators(&self) -> usize {
+        self.spectators.load(Ordering::Relaxed)
+    }
+
+    /// Account for a new public subscriber, returning the updated count.
+    pub fn add_spectator(&self) -> usize {
+        self.spectators.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Account for a departed public subscriber, returning the updated count.
+    pub fn remove_spectator(&self) -> usize {
+        let previous = self
+            .spectators
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+                Some(count.saturating_sub(1))
+            })
+            .unwrap_or_default();
+        previous.saturating_sub(1)
+    }
+
+    /// Mark a `spectators` broadcast as scheduled, returning `false` when one already is.
+    pub fn schedule_spectators_broadcast(&self) -> bool {
+        !self
+            .spectators_broadcast_pending
+            .swap(true, Ordering::Relaxed)
+    }
+
+    /// Clear the scheduled `spectators` broadcast flag, right before broadcasting.
+    pub fn clear_spectators_broadcast(&self) {
+        self.spectators_broadcast_pending
+            .store(false, Ordering::Relaxed);
+    }
 }
 
-/// State bundle holding the admin SSE hub and its coordinating token.
+/// State bundle holding the admin SSE hub and the tokens of its connections.
 pub struct AdminSseState {
     hub: SseHub,
-    token: Mutex<Option<String>>,
+    tokens: Mutex<HashSet<String>>,
+    /// Non-secret identifier of the admin session, spanning as long as an admin stream stays
+    /// connected, for auditing.
+    session: std::sync::RwLock<Option<Uuid>>,
 }
 
 impl AdminSseState {
-    /// Create the admin SSE manager backed by a broadcast channel and token lock.
+    /// Create the admin SSE manager backed by a broadcast channel and token set.
     fn new(capacity: usize) -> Self {
         Self {
             hub: SseHub::new(capacity),
-            token: Mutex::new(None),
+            tokens: Mutex::new(HashSet::new()),
+            session: std::sync::RwLock::new(None),
         }
     }
 
@@ -48,9 +98,19 @@ impl AdminSseState {
         &self.hub
     }
 
-    /// Borrow the token mutex that coordinates the single admin connection.
-    pub fn token(&self) -> &Mutex<Option<String>> {
-        &self.token
+    /// Borrow the tokens issued to the connected admin streams, one per connection.
+    pub fn tokens(&self) -> &Mutex<HashSet<String>> {
+        &self.tokens
+    }
+
+    /// Identifier of the currently connected admin session, if any.
+    pub fn session(&self) -> Option<Uuid> {
+        *self.session.read().expect("admin session lock poisoned")
+    }
+
+    /// Record (or clear) the identifier of the connected admin session.
+    pub fn set_session(&self, session: Option<Uuid>) {
+        *self.session.write().expect("admin session lock poisoned") = session;
     }
 }
 
@@ -17,6 +17,91 @@ pub enum GamePhase {
     ShowScores,
 }
 
+impl GamePhase {
+    /// Events that can currently be applied, with placeholder payloads for the events carrying
+    /// data (pairing session, pause kind, finish reason).
+    pub fn available_events(&self) -> Vec<GameEvent> {
+        let placeholder_session = PairingSession {
+            pairing_team_id: Uuid::nil(),
+            snapshot: IndexMap::new(),
+        };
+        [
+            GameEvent::StartGame,
+            GameEvent::ReloadFinished,
+            GameEvent::PairingStarted(placeholder_session),
+            GameEvent::PairingFinished,
+            GameEvent::GameConfigured,
+            GameEvent::Pause(PauseKind::Manual),
+            GameEvent::ContinuePlaying,
+            GameEvent::Reveal,
+            GameEvent::NextSong,
+            GameEvent::SkipSong,
+            GameEvent::PreviousSong,
+            GameEvent::Finish(FinishReason::ManualStop),
+            GameEvent::EndGame,
+        ]
+        .into_iter()
+        .filter(|event| self.next(event.clone()).is_ok())
+        .collect()
+    }
+
+    /// Compute the phase reached by applying `event`, if the transition is valid.
+    #[allow(clippy::result_large_err)]
+    fn next(&self, event: GameEvent) -> Result<GamePhase, InvalidTransition> {
+        let next = match (self.clone(), event) {
+            (GamePhase::Idle, GameEvent::StartGame) => {
+                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready))
+            }
+            (
+                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready)),
+                GameEvent::PairingStarted(session),
+            ) => GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Pairing(session))),
+            (
+                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Pairing(_))),
+                GameEvent::PairingFinished,
+            ) => GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready)),
+            (
+                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready)),
+                GameEvent::GameConfigured,
+            ) => GamePhase::GameRunning(GameRunningPhase::Playing),
+            (GamePhase::GameRunning(GameRunningPhase::Playing), GameEvent::Pause(kind)) => {
+                GamePhase::GameRunning(GameRunningPhase::Paused(kind))
+            }
+            // The turn passes to the next queued buzzer.
+            (
+                GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz { .. })),
+                GameEvent::Pause(PauseKind::Buzz { id }),
+            ) => GamePhase::GameRunning(GameRunningPhase::Paused(PauseKind::Buzz { id })),
+            (GamePhase::GameRunning(GameRunningPhase::Playing), GameEvent::Reveal) => {
+                GamePhase::GameRunning(GameRunningPhase::Reveal)
+            }
+            (GamePhase::GameRunning(GameRunningPhase::Paused(..)), GameEvent::ContinuePlaying) => {
+                GamePhase::GameRunning(GameRunningPhase::Playing)
+            }
+            (GamePhase::GameRunning(GameRunningPhase::Paused(..)), GameEvent::Reveal) => {
+                GamePhase::GameRunning(GameRunningPhase::Reveal)
+            }
+            (GamePhase::GameRunning(GameRunningPhase::Reveal), GameEvent::NextSong) => {
+                GamePhase::GameRunning(GameRunningPhase::Playing)
+            }
+            (
+                GamePhase::GameRunning(GameRunningPhase::Playing | GameRunningPhase::Paused(..)),
+                GameEvent::SkipSong,
+            ) => GamePhase::GameRunning(GameRunningPhase::Playing),
+            (
+                GamePhase::GameRunning(GameRunningPhase::Playing | GameRunningPhase::Reveal),
+                GameEvent::PreviousSong,
+            ) => GamePhase::GameRunning(GameRunningPhase::Playing),
+            (GamePhase::GameRunning(_), GameEvent::Finish(..)) => GamePhase::ShowScores,
+            (GamePhase::ShowScores, GameEvent::EndGame) => GamePhase::Idle,
+            (GamePhase::Idle, GameEvent::ReloadFinished) => GamePhase::ShowScores,
+            (from, event) => return Err(InvalidTransition { from, event }),
+        };
+
+        Ok(next)
+    }
+}
+
 /// Fine-grained phase while the game is running.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GameRunningPhase {
@@ -88,10 +173,16 @@ pub enum GameEvent {
     Reveal,
     /// Move to the next song after a reveal.
     NextSong,
+    /// Move to the next song without revealing the current one.
+    SkipSong,
+    /// Go back to the previous song, e.g. after advancing by mistake.
+    PreviousSong,
     /// Transition to the final scoreboard view.
     Finish(FinishReason),
     /// Completely end the game and return to idle.
     EndGame,
+    /// Load a finished game straight into its final scoreboard.
+    ReloadFinished,
 }
 
 /// Error returned when attempting to apply an invalid transition.
@@ -246,6 +337,7 @@ impl GameStateMachine {
 
     /// Plan a transition by validating that the event can be applied from the current phase.
     /// Returns a Plan that can later be applied or aborted.
+    #[allow(clippy::result_large_err)]
     pub fn plan(&mut self, event: GameEvent) -> Result<Plan, PlanError> {
         if self.pending.is_some() {
             return Err(PlanError::AlreadyPending);
@@ -271,6 +363,7 @@ impl GameStateMachine {
 
     /// Apply a planned transition, moving the state machine to the next phase.
     /// Returns the new phase after the transition.
+    #[allow(clippy::result_large_err)]
     pub fn apply(&mut self, plan_id: PlanId) -> Result<GamePhase, ApplyError> {
         let plan = self.pending.take().ok_or(ApplyError::NoPending)?;
 
@@ -320,44 +413,9 @@ impl GameStateMachine {
     }
 
     /// Compute a transition from an event if the transition is valid.
+    #[allow(clippy::result_large_err)]
     fn compute_transition(&self, event: GameEvent) -> Result<GamePhase, InvalidTransition> {
-        let next = match (self.phase.clone(), event) {
-            (GamePhase::Idle, GameEvent::StartGame) => {
-                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready))
-            }
-            (
-                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready)),
-                GameEvent::PairingStarted(session),
-            ) => GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Pairing(session))),
-            (
-                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Pairing(_))),
-                GameEvent::PairingFinished,
-            ) => GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready)),
-            (
-                GamePhase::GameRunning(GameRunningPhase::Prep(PrepStatus::Ready)),
-                GameEvent::GameConfigured,
-            ) => GamePhase::GameRunning(GameRunningPhase::Playing),
-            (GamePhase::GameRunning(GameRunningPhase::Playing), GameEvent::Pause(kind)) => {
-                GamePhase::GameRunning(GameRunningPhase::Paused(kind))
-            }
-            (GamePhase::GameRunning(GameRunningPhase::Playing), GameEvent::Reveal) => {
-                GamePhase::GameRunning(GameRunningPhase::Reveal)
-            }
-            (GamePhase::GameRunning(GameRunningPhase::Paused(..)), GameEvent::ContinuePlaying) => {
-                GamePhase::GameRunning(GameRunningPhase::Playing)
-            }
-            (GamePhase::GameRunning(GameRunningPhase::Paused(..)), GameEvent::Reveal) => {
-                GamePhase::GameRunning(GameRunningPhase::Reveal)
-            }
-            (GamePhase::GameRunning(GameRunningPhase::Reveal), GameEvent::NextSong) => {
-                GamePhase::GameRunning(GameRunningPhase::Playing)
-            }
-            (GamePhase::GameRunning(_), GameEvent::Finish(..)) => GamePhase::ShowScores,
-            (GamePhase::ShowScores, GameEvent::EndGame) => GamePhase::Idle,
-            (from, event) => return Err(InvalidTransition { from, event }),
-        };
-
-        Ok(next)
+        self.phase.next(event)
     }
 }
 
@@ -522,4 +580,42 @@ mod tests {
         sm.abort(plan.id).unwrap();
         assert!(sm.pending.is_none());
     }
+
+    #[test]
+    fn skipping_moves_straight_to_the_next_song() {
+        let mut sm = GameStateMachine::new();
+        apply(&mut sm, GameEvent::StartGame);
+        apply(&mut sm, GameEvent::GameConfigured);
+        apply(&mut sm, GameEvent::Pause(PauseKind::Manual));
+
+        assert_eq!(
+            apply(&mut sm, GameEvent::SkipSong),
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+        assert_eq!(
+            apply(&mut sm, GameEvent::SkipSong),
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+        apply(&mut sm, GameEvent::Reveal);
+        assert!(sm.plan(GameEvent::SkipSong).is_err());
+    }
+
+    #[test]
+    fn previous_song_is_allowed_from_playing_and_reveal() {
+        let mut sm = GameStateMachine::new();
+        apply(&mut sm, GameEvent::StartGame);
+        apply(&mut sm, GameEvent::GameConfigured);
+        apply(&mut sm, GameEvent::Reveal);
+
+        assert_eq!(
+            apply(&mut sm, GameEvent::PreviousSong),
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+        assert_eq!(
+            apply(&mut sm, GameEvent::PreviousSong),
+            GamePhase::GameRunning(GameRunningPhase::Playing)
+        );
+        apply(&mut sm, GameEvent::Pause(PauseKind::Manual));
+        assert!(sm.plan(GameEvent::PreviousSong).is_err());
+    }
 }
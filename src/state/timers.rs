@@ -0,0 +1,214 @@
+//! Game-scoped timers (auto-reveal, answer countdown) that can be frozen while the game is paused.
+//!
+//! Each timer kind has at most one instance. Scheduling a kind again replaces the previous
+//! instance. Suspending a timer records its remaining duration and cancels the task, so a later
+//! resume reschedules it for exactly the time that was left.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures::future::BoxFuture;
+use tokio::{task::JoinHandle, time::Instant};
+
+/// Identifies the purpose of a game timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimerKind {
+    /// Reveals the current song once its guess time is over.
+    AutoReveal,
+    /// Ends the time given to a buzzing team to answer.
+    AnswerCountdown,
+}
+
+/// Callback executed when a timer fires.
+pub type TimerAction = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+enum TimerStatus {
+    Running {
+        deadline: Instant,
+        handle: JoinHandle<()>,
+    },
+    Suspended {
+        remaining: Duration,
+    },
+}
+
+struct ScheduledTimer {
+    generation: u64,
+    action: TimerAction,
+    status: TimerStatus,
+}
+
+#[derive(Default)]
+struct TimersInner {
+    next_generation: u64,
+    timers: HashMap<TimerKind, ScheduledTimer>,
+}
+
+/// Registry of the timers attached to the current game.
+#[derive(Clone, Default)]
+pub struct GameTimers {
+    inner: Arc<Mutex<TimersInner>>,
+}
+
+impl GameTimers {
+    /// Schedule `action` to run after `delay`, replacing any timer of the same kind.
+    pub fn schedule(&self, kind: TimerKind, delay: Duration, action: TimerAction) {
+        let mut inner = self.inner.lock().expect("timers lock poisoned");
+        inner.next_generation += 1;
+        let generation = inner.next_generation;
+        let status = self.spawn(kind, generation, delay, Arc::clone(&action));
+        if let Some(previous) = inner.timers.insert(
+            kind,
+            ScheduledTimer {
+                generation,
+                action,
+                status,
+            },
+        ) {
+            previous.abort();
+        }
+    }
+
+    /// Cancel the timer of the given kind, if any.
+    pub fn cancel(&self, kind: TimerKind) {
+        let mut inner = self.inner.lock().expect("timers lock poisoned");
+        if let Some(timer) = inner.timers.remove(&kind) {
+            timer.abort();
+        }
+    }
+
+    /// Cancel every timer, e.g. when the game ends or another game is loaded.
+    pub fn cancel_all(&self) {
+        let mut inner = self.inner.lock().expect("timers lock poisoned");
+        for (_, timer) in inner.timers.drain() {
+            timer.abort();
+        }
+    }
+
+    /// Freeze the timer of the given kind, if running, remembering how much time was left.
+    pub fn suspend(&self, kind: TimerKind) {
+        let mut inner = self.inner.lock().expect("timers lock poisoned");
+        if let Some(timer) = inner.timers.get_mut(&kind) {
+            timer.suspend();
+        }
+    }
+
+    /// Freeze every running timer, remembering how much time was left.
+    pub fn suspend_all(&self) {
+        let mut inner = self.inner.lock().expect("timers lock poisoned");
+        for timer in inner.timers.values_mut() {
+            timer.suspend();
+        }
+    }
+
+    /// Reschedule every suspended timer for the time it had left when suspended.
+    pub fn resume_all(&self) {
+        let mut inner = self.inner.lock().expect("timers lock poisoned");
+        for (kind, timer) in inner.timers.iter_mut() {
+            if let TimerStatus::Suspended { remaining } = timer.status {
+                timer.status = self.spawn(
+                    *kind,
+                    timer.generation,
+                    remaining,
+                    Arc::clone(&timer.action),
+                );
+            }
+        }
+    }
+
+    /// Time left before the timer of the given kind fires, if it is scheduled.
+    pub fn remaining(&self, kind: TimerKind) -> Option<Duration> {
+        let inner = self.inner.lock().expect("timers lock poisoned");
+        inner.timers.get(&kind).map(|timer| match &timer.status {
+            TimerStatus::Running { deadline, .. } => {
+                deadline.saturating_duration_since(Instant::now())
+            }
+            TimerStatus::Suspended { remaining } => *remaining,
+        })
+    }
+
+    fn spawn(
+        &self,
+        kind: TimerKind,
+        generation: u64,
+        delay: Duration,
+        action: TimerAction,
+    ) -> TimerStatus {
+        let deadline = Instant::now() + delay;
+        let inner = Arc::clone(&self.inner);
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep_until(deadline).await;
+            {
+                let mut inner = inner.lock().expect("timers lock poisoned");
+                // A newer timer of the same kind may have replaced this one meanwhile.
+                match inner.timers.get(&kind) {
+                    Some(timer) if timer.generation == generation => {
+                        inner.timers.remove(&kind);
+                    }
+                    _ => return,
+                }
+            }
+            action().await;
+        });
+        TimerStatus::Running { deadline, handle }
+    }
+}
+
+impl ScheduledTimer {
+    fn suspend(&mut self) {
+        if let TimerStatus::Running { deadline, handle } = &self.status {
+            handle.abort();
+            self.status = TimerStatus::Suspended {
+                remaining: deadline.saturating_duration_since(Instant::now()),
+            };
+        }
+    }
+
+    fn abort(self) {
+        if let TimerStatus::Running { handle, .. } = self.status {
+            handle.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn flag_action(flag: &Arc<AtomicBool>) -> TimerAction {
+        let flag = Arc::clone(flag);
+        Arc::new(move || {
+            let flag = Arc::clone(&flag);
+            Box::pin(async move { flag.store(true, Ordering::SeqCst) })
+        })
+    }
+
+    #[tokio::test]
+    async fn suspended_timer_does_not_fire_until_resumed() {
+        let timers = GameTimers::default();
+        let fired = Arc::new(AtomicBool::new(false));
+        timers.schedule(
+            TimerKind::AutoReveal,
+            Duration::from_millis(100),
+            flag_action(&fired),
+        );
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        timers.suspend_all();
+        let remaining = timers.remaining(TimerKind::AutoReveal).unwrap();
+        assert!(remaining <= Duration::from_millis(60));
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(!fired.load(Ordering::SeqCst));
+        assert_eq!(timers.remaining(TimerKind::AutoReveal), Some(remaining));
+
+        timers.resume_all();
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(fired.load(Ordering::SeqCst));
+        assert_eq!(timers.remaining(TimerKind::AutoReveal), None);
+    }
+}